@@ -0,0 +1,81 @@
+//! Benchmarks shared by the day crates, which like [`crate::testing`] run every registered solution
+//! so that new variants are measured without writing benchmarks for them.
+//!
+//! Groups are named `day_XX::<solution>` after the module of the solution, which saved criterion
+//! baselines go by.
+
+use criterion::{BenchmarkId, Criterion};
+
+use crate::SolutionEntry;
+
+/// Every benchmark below, which is all most days need.
+pub fn all(
+    c: &mut Criterion,
+    solutions: &[SolutionEntry],
+    input: &str,
+    sizes: &[usize],
+    generate: impl Fn(usize, u64) -> String,
+) {
+    run(c, solutions, input);
+    phases(c, solutions, input);
+    generated(c, solutions, sizes, generate);
+}
+
+/// Each solution on `input`, from parsing to the answer.
+pub fn run(c: &mut Criterion, solutions: &[SolutionEntry], input: &str) {
+    for solution in solutions {
+        let mut group = c.benchmark_group(group_name(solution, ""));
+        group.bench_with_input(format!("part{}", solution.part), input, |b, input| {
+            b.iter(|| (solution.run)(input))
+        });
+        group.finish();
+    }
+}
+
+/// Parsing and solving `input` on their own, in `::parse` and `::solve` groups.
+pub fn phases(c: &mut Criterion, solutions: &[SolutionEntry], input: &str) {
+    for solution in solutions {
+        let mut group = c.benchmark_group(group_name(solution, "::parse"));
+        group.bench_with_input(format!("part{}", solution.part), input, |b, input| {
+            b.iter(|| (solution.parse)(input))
+        });
+        group.finish();
+
+        // Every iteration parses again but only the time spent solving is measured.
+        let mut group = c.benchmark_group(group_name(solution, "::solve"));
+        group.bench_with_input(format!("part{}", solution.part), input, |b, input| {
+            b.iter_custom(|iters| solution.solve_time(input, iters))
+        });
+        group.finish();
+    }
+}
+
+/// Each solution on the input `generate` makes of every size in `sizes`, in `::generated` groups
+/// that criterion plots against the size.
+pub fn generated(
+    c: &mut Criterion,
+    solutions: &[SolutionEntry],
+    sizes: &[usize],
+    generate: impl Fn(usize, u64) -> String,
+) {
+    let inputs = sizes
+        .iter()
+        .map(|&size| (size, generate(size, 0)))
+        .collect::<Vec<_>>();
+
+    for solution in solutions {
+        let mut group = c.benchmark_group(group_name(solution, "::generated"));
+        // The largest inputs take a while, so the sweep settles for fewer samples.
+        group.sample_size(10);
+        for (size, input) in &inputs {
+            group.bench_with_input(BenchmarkId::from_parameter(size), &**input, |b, input| {
+                b.iter(|| (solution.run)(input))
+            });
+        }
+        group.finish();
+    }
+}
+
+fn group_name(solution: &SolutionEntry, suffix: &str) -> String {
+    format!("day_{:02}::{}{}", solution.day, solution.name(), suffix)
+}
@@ -0,0 +1,101 @@
+use std::{
+    borrow::Cow,
+    env,
+    error::Error,
+    fmt::{self, Display},
+    fs,
+    io::{self, Read},
+    path::PathBuf,
+    process,
+};
+
+/// Environment variable consulted when no input is given on the command line.
+pub const INPUT_VAR: &str = "AOC_INPUT";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    Embedded,
+    Stdin,
+    File(PathBuf),
+}
+
+impl InputSource {
+    pub fn from_arg(arg: &str) -> Self {
+        match arg {
+            "-" => InputSource::Stdin,
+            path => InputSource::File(path.into()),
+        }
+    }
+
+    /// Picks the first command line argument, falling back to [`InputSource::from_env`].
+    pub fn from_args() -> Self {
+        match env::args().nth(1) {
+            Some(arg) => InputSource::from_arg(&arg),
+            None => InputSource::from_env(),
+        }
+    }
+
+    /// Picks `AOC_INPUT` if it is set, falling back to the embedded input.
+    pub fn from_env() -> Self {
+        match env::var(INPUT_VAR) {
+            Ok(arg) if !arg.is_empty() => InputSource::from_arg(&arg),
+            _ => InputSource::Embedded,
+        }
+    }
+
+    pub fn read(&self, embedded: &'static str) -> Result<Cow<'static, str>, InputError> {
+        let result = match self {
+            InputSource::Embedded => return Ok(Cow::Borrowed(embedded)),
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input).map(|_| input)
+            }
+            InputSource::File(path) => fs::read_to_string(path),
+        };
+
+        result.map(Cow::Owned).map_err(|error| InputError {
+            source: self.clone(),
+            error,
+        })
+    }
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputSource::Embedded => write!(f, "embedded input"),
+            InputSource::Stdin => write!(f, "stdin"),
+            InputSource::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct InputError {
+    source: InputSource,
+    error: io::Error,
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "could not read puzzle input from {}: {}",
+            self.source, self.error
+        )
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// Reads the input from `source`, exiting the process with a message if it cannot be read.
+pub fn load(source: InputSource, embedded: &'static str) -> Cow<'static, str> {
+    source.read(embedded).unwrap_or_else(|err| {
+        eprintln!("{}", err);
+        process::exit(1);
+    })
+}
@@ -0,0 +1,17 @@
+#[cfg(feature = "bench")]
+pub mod bench;
+pub mod input;
+pub mod parse;
+pub mod progress;
+pub mod solution;
+pub mod testing;
+#[cfg(feature = "trace")]
+pub mod trace;
+
+pub use input::InputSource;
+pub use parse::ParseError;
+pub use progress::{Cancelled, Progress};
+pub use solution::{
+    print_answer, print_solution, variant_main, Solution, SolutionEntry, Timings, DEFAULT_VARIANT,
+    TIMINGS_VAR,
+};
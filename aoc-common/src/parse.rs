@@ -0,0 +1,135 @@
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    ops::Range,
+    str::FromStr,
+};
+
+/// Points at the text of the puzzle input that could not be parsed, with `kind` saying why.
+///
+/// `line` and `column` are 1-based so they can be shown as-is. The alternate format (`{:#}`) also
+/// prints the offending line with the text underlined.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError<K> {
+    pub kind: K,
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    source_line: String,
+}
+
+impl<K> ParseError<K> {
+    /// `text` has to be a slice of `input`, which is what its position is worked out from.
+    pub fn new(input: &str, text: &str, kind: K) -> Self {
+        let offset = (text.as_ptr() as usize).wrapping_sub(input.as_ptr() as usize);
+        assert!(
+            offset <= input.len(),
+            "{:?} is not part of the parsed input",
+            text
+        );
+        Self::at_offset(input, offset..offset + text.len(), kind)
+    }
+
+    /// Same as [`ParseError::new`], but for parsers that report byte ranges instead of slices.
+    pub fn at_offset(input: &str, span: Range<usize>, kind: K) -> Self {
+        let line_start = input[..span.start].rfind('\n').map_or(0, |idx| idx + 1);
+        let line_end = input[span.start..]
+            .find('\n')
+            .map_or(input.len(), |idx| span.start + idx);
+
+        Self {
+            kind,
+            line: input[..span.start].matches('\n').count() + 1,
+            column: input[line_start..span.start].chars().count() + 1,
+            text: input[span].to_string(),
+            source_line: input[line_start..line_end]
+                .trim_end_matches('\r')
+                .to_string(),
+        }
+    }
+
+    /// Parses `text` with [`str::parse`], pointing at it if that fails.
+    pub fn try_parse<T: FromStr>(
+        input: &str,
+        text: &str,
+        kind: impl FnOnce(T::Err) -> K,
+    ) -> Result<T, Self> {
+        text.parse()
+            .map_err(|err| Self::new(input, text, kind(err)))
+    }
+
+    /// Swaps the kind while keeping the position, e.g. to wrap the error of a shared parser.
+    pub fn map_kind<L>(self, f: impl FnOnce(K) -> L) -> ParseError<L> {
+        ParseError {
+            kind: f(self.kind),
+            line: self.line,
+            column: self.column,
+            text: self.text,
+            source_line: self.source_line,
+        }
+    }
+}
+
+impl<K: Display> Display for ParseError<K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} at line {}, column {}",
+            self.kind, self.line, self.column
+        )?;
+        if !self.text.is_empty() {
+            write!(f, ": {:?}", self.text)?;
+        }
+
+        if f.alternate() {
+            let gutter = " ".repeat(self.line.to_string().len());
+            let underline = self
+                .text
+                .lines()
+                .next()
+                .map_or(0, |text| text.chars().count())
+                .max(1);
+            write!(
+                f,
+                "\n{gutter} |\n{} | {}\n{gutter} | {}{}",
+                self.line,
+                self.source_line,
+                " ".repeat(self.column - 1),
+                "^".repeat(underline)
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl<K: fmt::Debug + Display> Error for ParseError<K> {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_position_of_slice() {
+        let input = "a: 1\nb: x\n";
+        let err = ParseError::new(input, &input[8..9], "not a number");
+
+        assert_eq!((err.line, err.column), (2, 4));
+        assert_eq!(err.text, "x");
+        assert_eq!(
+            format!("{:#}", err),
+            "not a number at line 2, column 4: \"x\"
+  |
+2 | b: x
+  |    ^"
+        );
+    }
+
+    #[test]
+    fn test_position_of_missing_text() {
+        let input = "a: 1\nb:";
+        let err = ParseError::new(input, &input[input.len()..], "missing value");
+
+        assert_eq!((err.line, err.column), (2, 3));
+        assert_eq!(err.to_string(), "missing value at line 2, column 3");
+    }
+}
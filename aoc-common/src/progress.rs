@@ -0,0 +1,263 @@
+use std::{
+    borrow::Cow,
+    env,
+    error::Error,
+    fmt::{self, Display},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant},
+};
+
+use indicatif::{ProgressBar, ProgressDrawTarget, ProgressStyle};
+
+/// Environment variable with the number of seconds after which the binaries give up.
+pub const TIMEOUT_VAR: &str = "AOC_TIMEOUT";
+
+/// Progress reporting and cooperative cancellation for solutions that can run for a long time.
+///
+/// Solutions count the steps of their main loop with [`Progress::inc`] or
+/// [`Progress::set_position`], describe how far they got with [`Progress::set_message`] and return
+/// [`Cancelled`] from [`Progress::check`] once they should stop. Clones share the same bar and
+/// token, so the runner can cancel from another thread.
+#[derive(Debug, Clone)]
+pub struct Progress {
+    bar: ProgressBar,
+    cancelled: Arc<AtomicBool>,
+    started: Instant,
+    deadline: Option<Instant>,
+}
+
+impl Progress {
+    /// Draws nothing and is never cancelled, for the plain `partN` functions.
+    pub fn hidden() -> Self {
+        Self::with_bar(ProgressBar::hidden())
+    }
+
+    /// Spinner on stderr, only drawn when stderr is a terminal.
+    pub fn stderr() -> Self {
+        let bar = ProgressBar::with_draw_target(None, ProgressDrawTarget::stderr());
+        bar.set_style(
+            ProgressStyle::with_template("{spinner} {pos} steps [{elapsed_precise}] {wide_msg}")
+                .unwrap(),
+        );
+        Self::with_bar(bar)
+    }
+
+    fn with_bar(bar: ProgressBar) -> Self {
+        Self {
+            bar,
+            cancelled: Arc::new(AtomicBool::new(false)),
+            started: Instant::now(),
+            deadline: None,
+        }
+    }
+
+    /// Cancels once `timeout` has passed since the progress was created.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.deadline = Some(self.started + timeout);
+        self
+    }
+
+    /// Reads the timeout from `AOC_TIMEOUT`, if it is set.
+    pub fn timeout_from_env() -> Result<Option<Duration>, String> {
+        match env::var(TIMEOUT_VAR) {
+            Ok(secs) if !secs.is_empty() => parse_timeout(&secs).map(Some),
+            _ => Ok(None),
+        }
+    }
+
+    /// Turns the spinner into a bar, for solutions that know how many steps they will take.
+    pub fn set_length(&self, len: u64) {
+        self.bar.set_length(len);
+        self.bar.set_style(
+            ProgressStyle::with_template(
+                "{bar:40} {pos}/{len} [{elapsed_precise}<{eta_precise}] {wide_msg}",
+            )
+            .unwrap(),
+        );
+    }
+
+    pub fn inc(&self, steps: u64) {
+        self.bar.inc(steps);
+    }
+
+    pub fn set_position(&self, steps: u64) {
+        self.bar.set_position(steps);
+    }
+
+    /// Partial state worth reporting if the solution gets cancelled.
+    pub fn set_message(&self, message: impl Into<Cow<'static, str>>) {
+        self.bar.set_message(message);
+    }
+
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        if self.cancelled.load(Ordering::Relaxed) {
+            return true;
+        }
+        if self
+            .deadline
+            .is_some_and(|deadline| Instant::now() >= deadline)
+        {
+            self.cancel();
+            return true;
+        }
+        false
+    }
+
+    /// [`Cancelled`] once [`Progress::is_cancelled`], for solutions to `?` in their main loop.
+    pub fn check(&self) -> Result<(), Cancelled> {
+        if self.is_cancelled() {
+            Err(Cancelled)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Removes the bar so that the answer is not printed over it, returning how far the solution
+    /// got to report in place of the answer if it was cancelled.
+    pub fn finish(&self) -> Status {
+        let status = Status {
+            elapsed: self.started.elapsed(),
+            steps: self.bar.position(),
+            message: self.bar.message(),
+        };
+        self.bar.finish_and_clear();
+        status
+    }
+}
+
+/// Returned by solutions in place of an answer once their [`Progress`] got cancelled.
+///
+/// Days wrap it in their own error type, returning it as the [`Error::source`] so that runners
+/// can tell it apart from the input being wrong with [`Cancelled::caused`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cancelled;
+
+impl Cancelled {
+    /// Whether `err`, or any error it was caused by, is [`Cancelled`].
+    pub fn caused(err: &(dyn Error + 'static)) -> bool {
+        let mut err = Some(err);
+        while let Some(current) = err {
+            if current.is::<Cancelled>() {
+                return true;
+            }
+            err = current.source();
+        }
+        false
+    }
+}
+
+impl Display for Cancelled {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "cancelled before finding an answer")
+    }
+}
+
+impl Error for Cancelled {}
+
+/// Snapshot of a [`Progress`]. Displays as e.g. `cancelled after 5.00s and 1200 steps: ...`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Status {
+    pub elapsed: Duration,
+    pub steps: u64,
+    pub message: String,
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "cancelled after {:.2?} and {} steps",
+            self.elapsed, self.steps
+        )?;
+        if !self.message.is_empty() {
+            write!(f, ": {}", self.message)?;
+        }
+        Ok(())
+    }
+}
+
+/// Seconds, possibly fractional.
+pub fn parse_timeout(secs: &str) -> Result<Duration, String> {
+    secs.parse::<f64>()
+        .ok()
+        .and_then(|secs| Duration::try_from_secs_f64(secs).ok())
+        .ok_or_else(|| format!("invalid timeout {:?}, expected a number of seconds", secs))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rstest::rstest;
+
+    #[test]
+    fn test_cancel() {
+        let progress = Progress::hidden();
+        let runner = progress.clone();
+        assert!(!progress.is_cancelled());
+
+        runner.cancel();
+        assert!(progress.is_cancelled());
+    }
+
+    #[test]
+    fn test_timeout() {
+        let progress = Progress::hidden().with_timeout(Duration::ZERO);
+        progress.inc(3);
+        progress.set_message("best so far 42");
+
+        assert!(progress.is_cancelled());
+        let status = progress.finish();
+        assert_eq!(
+            (status.steps, status.message.as_str()),
+            (3, "best so far 42")
+        );
+    }
+
+    #[test]
+    fn test_check() {
+        let progress = Progress::hidden();
+        assert_eq!(progress.check(), Ok(()));
+
+        progress.cancel();
+        assert_eq!(progress.check(), Err(Cancelled));
+    }
+
+    #[derive(Debug)]
+    struct Wrapper(Option<Cancelled>);
+
+    impl Display for Wrapper {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "wrapper")
+        }
+    }
+
+    impl Error for Wrapper {
+        fn source(&self) -> Option<&(dyn Error + 'static)> {
+            self.0.as_ref().map(|err| err as _)
+        }
+    }
+
+    #[rstest]
+    #[case(Box::new(Cancelled), true)]
+    #[case(Box::new(Wrapper(Some(Cancelled))), true)]
+    #[case(Box::new(Wrapper(None)), false)]
+    fn test_cancelled_caused(#[case] err: Box<dyn Error>, #[case] expected: bool) {
+        assert_eq!(Cancelled::caused(err.as_ref()), expected);
+    }
+
+    #[rstest]
+    #[case("5", Ok(Duration::from_secs(5)))]
+    #[case("0.25", Ok(Duration::from_millis(250)))]
+    #[case("-1", Err(()))]
+    #[case("soon", Err(()))]
+    fn test_parse_timeout(#[case] secs: &str, #[case] expected: Result<Duration, ()>) {
+        assert_eq!(parse_timeout(secs).map_err(|_| ()), expected);
+    }
+}
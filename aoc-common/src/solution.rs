@@ -0,0 +1,283 @@
+use std::{
+    env,
+    error::Error,
+    fmt::{self, Display},
+    process::ExitCode,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    input::{self, InputSource},
+    progress::{Cancelled, Progress},
+};
+
+pub const DEFAULT_VARIANT: &str = "default";
+
+/// Environment variable that makes the binaries print how long each phase took.
+pub const TIMINGS_VAR: &str = "AOC_TIMINGS";
+
+pub trait Solution {
+    const PART: u8;
+    const VARIANT: &'static str = DEFAULT_VARIANT;
+
+    type Model<'a>;
+    type Answer: Display;
+    type Error: Error + 'static;
+
+    fn parse(input: &str) -> Result<Self::Model<'_>, Self::Error>;
+
+    fn solve(model: Self::Model<'_>) -> Result<Self::Answer, Self::Error>;
+
+    fn run(input: &str) -> Result<Self::Answer, Self::Error> {
+        Self::parse(input).and_then(Self::solve)
+    }
+
+    /// Like [`Solution::solve`], for solutions that can run for a long time: they report on
+    /// `progress` and give up with [`Cancelled`] once it is cancelled. The others ignore it.
+    fn solve_with(
+        model: Self::Model<'_>,
+        _progress: &Progress,
+    ) -> Result<Self::Answer, Self::Error> {
+        Self::solve(model)
+    }
+
+    fn run_timed(input: &str) -> Result<(Self::Answer, Timings), Self::Error> {
+        timed(|| Self::parse(input), Self::solve)
+    }
+
+    fn run_with(input: &str, progress: &Progress) -> Result<(Self::Answer, Timings), Self::Error> {
+        timed(
+            || Self::parse(input),
+            |model| Self::solve_with(model, progress),
+        )
+    }
+}
+
+fn timed<M, A, E>(
+    parse: impl FnOnce() -> Result<M, E>,
+    solve: impl FnOnce(M) -> Result<A, E>,
+) -> Result<(A, Timings), E> {
+    let start = Instant::now();
+    let model = parse()?;
+    let parsed = Instant::now();
+    let answer = solve(model)?;
+
+    let timings = Timings {
+        parse: parsed - start,
+        solve: parsed.elapsed(),
+    };
+    Ok((answer, timings))
+}
+
+/// Time spent in [`Solution::parse`] and [`Solution::solve`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Timings {
+    pub parse: Duration,
+    pub solve: Duration,
+}
+
+impl Timings {
+    pub fn total(&self) -> Duration {
+        self.parse + self.solve
+    }
+}
+
+impl Display for Timings {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "parse {:.2?}, solve {:.2?}, total {:.2?}",
+            self.parse,
+            self.solve,
+            self.total()
+        )
+    }
+}
+
+/// Type-erased handle to a [`Solution`] so that harnesses can iterate over every part and variant
+/// of a day without naming each one.
+#[derive(Debug, Clone, Copy)]
+pub struct SolutionEntry {
+    pub day: u8,
+    pub part: u8,
+    pub variant: &'static str,
+    /// Version of the day crate, as solutions only change along with it.
+    pub version: &'static str,
+    pub run: ErasedFn<String>,
+    /// Only parses the input, dropping the model.
+    pub parse: ErasedFn<()>,
+    pub run_timed: ErasedFn<(String, Timings)>,
+    pub run_with: ErasedFnWith<(String, Timings)>,
+}
+
+type ErasedFn<T> = fn(&str) -> Result<T, Box<dyn Error>>;
+type ErasedFnWith<T> = fn(&str, &Progress) -> Result<T, Box<dyn Error>>;
+
+impl SolutionEntry {
+    pub const fn new<S: Solution>(day: u8, version: &'static str) -> Self {
+        Self {
+            day,
+            part: S::PART,
+            variant: S::VARIANT,
+            version,
+            run: run_erased::<S>,
+            parse: parse_erased::<S>,
+            run_timed: run_timed_erased::<S>,
+            run_with: run_with_erased::<S>,
+        }
+    }
+
+    /// Module name of the solution, e.g. `part1` or `part1_astar`.
+    pub fn name(&self) -> String {
+        if self.variant == DEFAULT_VARIANT {
+            format!("part{}", self.part)
+        } else {
+            format!("part{}_{}", self.part, self.variant)
+        }
+    }
+
+    /// Same as [`print_solution`] for this solution.
+    pub fn print(&self, input: &str) -> ExitCode {
+        print_run(self.part, self.variant, self.run_with, input)
+    }
+
+    /// Time spent solving over `iters` runs, leaving out parsing, for criterion's `iter_custom`.
+    pub fn solve_time(&self, input: &str, iters: u64) -> Duration {
+        (0..iters)
+            .map(|_| match (self.run_timed)(input) {
+                Ok((_, timings)) => timings.solve,
+                Err(err) => panic!("{} failed: {:#}", self.name(), err),
+            })
+            .sum()
+    }
+}
+
+fn run_erased<S: Solution>(input: &str) -> Result<String, Box<dyn Error>> {
+    Ok(S::run(input)?.to_string())
+}
+
+fn parse_erased<S: Solution>(input: &str) -> Result<(), Box<dyn Error>> {
+    S::parse(input)?;
+    Ok(())
+}
+
+fn run_timed_erased<S: Solution>(input: &str) -> Result<(String, Timings), Box<dyn Error>> {
+    let (answer, timings) = S::run_timed(input)?;
+    Ok((answer.to_string(), timings))
+}
+
+fn run_with_erased<S: Solution>(
+    input: &str,
+    progress: &Progress,
+) -> Result<(String, Timings), Box<dyn Error>> {
+    let (answer, timings) = S::run_with(input, progress)?;
+    Ok((answer.to_string(), timings))
+}
+
+/// Prints the answer, or the error along with the offending input, and turns it into the exit
+/// code of a binary.
+pub fn print_answer<A: Display, E: Display>(result: Result<A, E>) -> ExitCode {
+    match result {
+        Ok(answer) => {
+            println!("{}", answer);
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("error: {:#}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Runs a solution from its binary with a progress spinner, also printing the time each phase
+/// took to stderr when `AOC_TIMINGS` is set. Gives up after `AOC_TIMEOUT` seconds.
+pub fn print_solution<S: Solution>(input: &str) -> ExitCode {
+    print_run(S::PART, S::VARIANT, run_with_erased::<S>, input)
+}
+
+/// `main` of a binary that runs any of `solutions`, picked by the part and variant given as its
+/// first two arguments. A third argument is taken as the input like [`InputSource::from_args`]
+/// takes the first.
+pub fn variant_main(solutions: &[SolutionEntry], embedded: &'static str) -> ExitCode {
+    let args = env::args().skip(1).collect::<Vec<_>>();
+    let names = || {
+        solutions
+            .iter()
+            .map(|solution| format!("{} {}", solution.part, solution.variant))
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    let (Some(part), Some(variant)) = (args.first(), args.get(1)) else {
+        eprintln!(
+            "usage: variant <part> <variant> [input], with one of: {}",
+            names()
+        );
+        return ExitCode::FAILURE;
+    };
+    let Some(solution) = solutions
+        .iter()
+        .find(|solution| solution.part.to_string() == *part && solution.variant == variant)
+    else {
+        eprintln!(
+            "no part {} variant {}, expected one of: {}",
+            part,
+            variant,
+            names()
+        );
+        return ExitCode::FAILURE;
+    };
+
+    let source = args
+        .get(2)
+        .map_or_else(InputSource::from_env, |arg| InputSource::from_arg(arg));
+    let input = input::load(source, embedded);
+    solution.print(&input)
+}
+
+#[cfg_attr(not(feature = "trace"), allow(unused_variables))]
+fn print_run(
+    part: u8,
+    variant: &str,
+    run_with: ErasedFnWith<(String, Timings)>,
+    input: &str,
+) -> ExitCode {
+    #[cfg(feature = "trace")]
+    let _span = tracing::info_span!("solution", part, variant).entered();
+
+    let progress = match Progress::timeout_from_env() {
+        Ok(Some(timeout)) => Progress::stderr().with_timeout(timeout),
+        Ok(None) => Progress::stderr(),
+        Err(err) => {
+            eprintln!("{}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+    let result = run_with(input, &progress);
+    let status = progress.finish();
+
+    if let Err(err) = &result {
+        if Cancelled::caused(err.as_ref()) {
+            eprintln!("error: {}", status);
+            return ExitCode::FAILURE;
+        }
+    }
+    if let Ok((_, timings)) = &result {
+        if env::var_os(TIMINGS_VAR).is_some_and(|var| !var.is_empty()) {
+            eprintln!("{}", timings);
+        }
+    }
+    print_answer(result.map(|(answer, _)| answer))
+}
+
+/// Registers the solutions of a day crate as `pub static SOLUTIONS`.
+///
+/// ```ignore
+/// aoc_common::solutions!(17 => part1::Part1, part1_astar::Part1);
+/// ```
+#[macro_export]
+macro_rules! solutions {
+    ($day:literal => $($solution:ty),+ $(,)?) => {
+        pub static SOLUTIONS: &[$crate::SolutionEntry] =
+            &[$($crate::SolutionEntry::new::<$solution>($day, env!("CARGO_PKG_VERSION"))),+];
+    };
+}
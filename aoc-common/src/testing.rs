@@ -0,0 +1,177 @@
+//! Checks shared by the tests of the day crates, which run every registered solution so that new
+//! variants are covered without writing tests for them.
+
+use std::{fs, path::Path};
+
+use crate::{SolutionEntry, DEFAULT_VARIANT};
+
+/// Example input from a day's `examples` directory along with the answers the puzzle gives for it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub name: String,
+    pub input: String,
+    /// Indexed by part, left out for the parts the example has no answer for.
+    pub answers: [Option<String>; 2],
+}
+
+/// Reads every `<name>.txt` in `dir`, along with the answer to each part from `<name>.part1` and
+/// `<name>.part2` next to it.
+pub fn load_examples(dir: &Path) -> Vec<Example> {
+    let entries =
+        fs::read_dir(dir).unwrap_or_else(|err| panic!("could not read {}: {}", dir.display(), err));
+
+    let mut examples = entries
+        .map(|entry| entry.expect("could not read directory entry").path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "txt"))
+        .map(|path| {
+            let read = |path: &Path| {
+                fs::read_to_string(path)
+                    .unwrap_or_else(|err| panic!("could not read {}: {}", path.display(), err))
+            };
+            Example {
+                name: path.file_stem().unwrap().to_string_lossy().into_owned(),
+                input: read(&path),
+                answers: ["part1", "part2"].map(|part| {
+                    let path = path.with_extension(part);
+                    path.exists().then(|| read(&path).trim().to_string())
+                }),
+            }
+        })
+        .collect::<Vec<_>>();
+    examples.sort_by(|a, b| a.name.cmp(&b.name));
+    examples
+}
+
+/// Runs every solution on every example that has an answer for its part, panicking with all the
+/// wrong answers at once.
+pub fn check_examples(solutions: &[SolutionEntry], examples: &[Example]) {
+    let failures = examples
+        .iter()
+        .flat_map(|example| solutions.iter().map(move |solution| (example, solution)))
+        .filter_map(|(example, solution)| {
+            let expected = example.answers[solution.part as usize - 1].as_ref()?;
+            match (solution.run)(&example.input) {
+                Ok(answer) if answer == *expected => None,
+                Ok(answer) => Some(format!(
+                    "{} on {}: expected {}, got {}",
+                    solution.name(),
+                    example.name,
+                    expected,
+                    answer
+                )),
+                Err(err) => Some(format!(
+                    "{} on {}: expected {}, got error: {:#}",
+                    solution.name(),
+                    example.name,
+                    expected,
+                    err
+                )),
+            }
+        })
+        .collect::<Vec<_>>();
+
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
+
+/// Differential check of every variant against the default solution of the same part.
+pub fn assert_variants_agree(solutions: &[SolutionEntry], input: &str) {
+    for solution in solutions {
+        if solution.variant == DEFAULT_VARIANT {
+            continue;
+        }
+        let default = solutions
+            .iter()
+            .find(|default| default.part == solution.part && default.variant == DEFAULT_VARIANT)
+            .unwrap_or_else(|| panic!("{} has no default to compare with", solution.name()));
+
+        let answer = |solution: &SolutionEntry| {
+            (solution.run)(input)
+                .unwrap_or_else(|err| panic!("{} failed: {:#}", solution.name(), err))
+        };
+        assert_eq!(
+            answer(solution),
+            answer(default),
+            "{} disagrees with {}",
+            solution.name(),
+            default.name()
+        );
+    }
+}
+
+/// Runs every solution on the input `generate` makes for each `(size, seed, expected)` case,
+/// panicking with all the failures at once: solutions that return an error, variants that disagree
+/// with the default solution of their part, and answers that differ from `expected`, indexed by
+/// part. Parts past the end of `expected` are only checked for agreement, for inputs too large to
+/// work out the answer by hand.
+pub fn check_generated(
+    solutions: &[SolutionEntry],
+    generate: impl Fn(usize, u64) -> String,
+    cases: &[(usize, u64, &[&str])],
+) {
+    let failures = cases
+        .iter()
+        .flat_map(|&(size, seed, expected)| {
+            generated_failures(solutions, &generate(size, seed), expected)
+                .into_iter()
+                .map(move |failure| format!("size {} seed {}: {}", size, seed, failure))
+        })
+        .collect::<Vec<_>>();
+
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
+
+fn generated_failures(solutions: &[SolutionEntry], input: &str, expected: &[&str]) -> Vec<String> {
+    let answers = solutions
+        .iter()
+        .map(|solution| (solution, (solution.run)(input)))
+        .collect::<Vec<_>>();
+
+    let mut failures = vec![];
+    for (solution, answer) in &answers {
+        let answer = match answer {
+            Ok(answer) => answer,
+            Err(err) => {
+                failures.push(format!("{} failed: {:#}", solution.name(), err));
+                continue;
+            }
+        };
+        if let Some(expected) = expected.get(solution.part as usize - 1) {
+            if answer != expected {
+                failures.push(format!(
+                    "{}: expected {}, got {}",
+                    solution.name(),
+                    expected,
+                    answer
+                ));
+            }
+        }
+        let default = answers.iter().find(|(default, _)| {
+            default.part == solution.part && default.variant == DEFAULT_VARIANT
+        });
+        if let Some((default, Ok(default_answer))) = default {
+            if answer != default_answer {
+                failures.push(format!(
+                    "{} disagrees with {}: {} against {}",
+                    solution.name(),
+                    default.name(),
+                    answer,
+                    default_answer
+                ));
+            }
+        }
+    }
+
+    failures
+}
+
+/// Starts dhat in testing mode, for heap budgets checked with `dhat::assert!` until the profiler is
+/// dropped.
+///
+/// Budgets live in a `tests/heap.rs` test binary of their own, which sets [`dhat::Alloc`] as its
+/// global allocator, with a single test, since dhat counts the allocations of every thread,
+/// including those of tests running alongside. A failing budget writes the profile to
+/// `dhat-heap.json` in the crate directory, which `aoc dhat summary` can break down by allocation
+/// site.
+pub fn heap_profiler() -> dhat::Profiler {
+    dhat::Profiler::builder().testing().build()
+}
@@ -0,0 +1,29 @@
+use std::{env, fs::File, io, process, sync::Mutex};
+
+use tracing_subscriber::{fmt, EnvFilter};
+
+/// Environment variable naming a file to write the trace to as JSON lines. The trace goes to
+/// stderr when it is not set.
+pub const TRACE_VAR: &str = "AOC_TRACE";
+
+/// Installs a global subscriber for the spans and events of the `trace` feature, filtered by
+/// `RUST_LOG` (`debug` when it is not set). Exits the process if the trace file cannot be created.
+pub fn init() {
+    let filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("debug"));
+    let subscriber = fmt().with_env_filter(filter);
+
+    match env::var(TRACE_VAR) {
+        Ok(path) if !path.is_empty() => {
+            let file = File::create(&path).unwrap_or_else(|err| {
+                eprintln!("could not create trace file {}: {}", path, err);
+                process::exit(1);
+            });
+            subscriber
+                .json()
+                .with_span_list(true)
+                .with_writer(Mutex::new(file))
+                .init()
+        }
+        _ => subscriber.with_writer(io::stderr).init(),
+    }
+}
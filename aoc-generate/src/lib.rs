@@ -0,0 +1,61 @@
+pub mod outline;
+
+pub use outline::Outline;
+pub use rand;
+
+use rand::{rngs::StdRng, seq::index, Rng, SeedableRng};
+
+pub const LOWERCASE: &str = "abcdefghijklmnopqrstuvwxyz";
+
+/// Every generator draws from this so that the same seed always produces the same input.
+pub fn rng(seed: u64) -> StdRng {
+    StdRng::seed_from_u64(seed)
+}
+
+/// `count` distinct names of `len` chars picked from `alphabet`.
+pub fn unique_names(rng: &mut impl Rng, count: usize, len: usize, alphabet: &str) -> Vec<String> {
+    let alphabet = alphabet.chars().collect::<Vec<_>>();
+    let combinations = alphabet.len().pow(len as u32);
+    assert!(
+        count <= combinations,
+        "only {} names of length {} exist",
+        combinations,
+        len
+    );
+
+    index::sample(rng, combinations, count)
+        .into_iter()
+        .map(|mut idx| {
+            (0..len)
+                .map(|_| {
+                    let c = alphabet[idx % alphabet.len()];
+                    idx /= alphabet.len();
+                    c
+                })
+                .collect()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_unique_names() {
+        let names = unique_names(&mut rng(0), 26 * 26, 2, LOWERCASE);
+
+        assert_eq!(names.len(), 26 * 26);
+        assert!(names.iter().all(|name| name.len() == 2));
+        assert_eq!(names.iter().collect::<HashSet<_>>().len(), names.len());
+    }
+
+    #[test]
+    fn test_same_seed_same_names() {
+        assert_eq!(
+            unique_names(&mut rng(7), 10, 3, LOWERCASE),
+            unique_names(&mut rng(7), 10, 3, LOWERCASE)
+        );
+    }
+}
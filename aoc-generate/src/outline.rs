@@ -0,0 +1,181 @@
+use aoc_grid::{Direction, Point};
+use rand::Rng;
+use std::collections::{BTreeSet, HashMap};
+
+/// Closed, non self-intersecting path that only goes along rows and columns, e.g. a pipe loop or
+/// a dig plan.
+///
+/// The path walks clockwise around a random shape whose rows are each a single run of cells, with
+/// every row overlapping the next one so that the outline never touches itself.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Outline {
+    pub start: Point,
+    /// No two consecutive moves go in the same direction.
+    pub moves: Vec<(Direction, usize)>,
+}
+
+impl Outline {
+    /// Outline of a shape that fits in `width` x `height` cells, so its points range from
+    /// `(0, 0)` to `(height, width)`.
+    pub fn random(rng: &mut impl Rng, width: usize, height: usize) -> Self {
+        assert!(width > 0 && height > 0, "outline needs at least one cell");
+
+        let mut runs = Vec::with_capacity(height);
+        let start = rng.gen_range(0..width);
+        runs.push((start, rng.gen_range(start + 1..=width)));
+        for _ in 1..height {
+            let &(prev_start, prev_end) = runs.last().unwrap();
+            let start = rng.gen_range(0..prev_end);
+            let end = rng.gen_range(prev_start.max(start) + 1..=width);
+            runs.push((start, end));
+        }
+
+        let horizontal = |from: usize, to: usize| {
+            if from < to {
+                (Direction::East, to - from)
+            } else {
+                (Direction::West, from - to)
+            }
+        };
+        let mut moves = vec![(Direction::East, runs[0].1 - runs[0].0)];
+        for pair in runs.windows(2) {
+            moves.push((Direction::South, 1));
+            moves.push(horizontal(pair[0].1, pair[1].1));
+        }
+        moves.push((Direction::South, 1));
+        moves.push(horizontal(runs[height - 1].1, runs[height - 1].0));
+        for pair in runs.windows(2).rev() {
+            moves.push((Direction::North, 1));
+            moves.push(horizontal(pair[1].0, pair[0].0));
+        }
+        moves.push((Direction::North, 1));
+
+        Self {
+            start: (0, runs[0].0),
+            moves: merge(moves),
+        }
+    }
+
+    /// Every point the outline passes through, starting with `start` and not repeating it at the
+    /// end.
+    pub fn points(&self) -> Vec<Point> {
+        let mut pos = self.start;
+        let mut points = vec![];
+        for &(direction, len) in &self.moves {
+            for _ in 0..len {
+                points.push(pos);
+                pos = direction.checked_offset(pos, 1).unwrap();
+            }
+        }
+        points
+    }
+
+    /// Same shape with every distinct row and column moved apart by a random gap of up to
+    /// `max_gap`, so the moves get longer while keeping the outline from crossing itself.
+    pub fn stretch(&self, rng: &mut impl Rng, max_gap: usize) -> Self {
+        let corners = self.corners();
+        let rows = spread(rng, corners.iter().map(|pos| pos.0), max_gap);
+        let cols = spread(rng, corners.iter().map(|pos| pos.1), max_gap);
+
+        let corners = corners
+            .iter()
+            .map(|&(row, col)| (rows[&row], cols[&col]))
+            .collect::<Vec<_>>();
+        let moves = self
+            .moves
+            .iter()
+            .zip(corners.iter().zip(corners.iter().cycle().skip(1)))
+            .map(|(&(direction, _), (from, to))| {
+                (direction, from.0.abs_diff(to.0) + from.1.abs_diff(to.1))
+            })
+            .collect();
+
+        Self {
+            start: corners[0],
+            moves,
+        }
+    }
+
+    fn corners(&self) -> Vec<Point> {
+        let mut pos = self.start;
+        self.moves
+            .iter()
+            .map(|&(direction, len)| {
+                let corner = pos;
+                pos = direction.checked_offset(pos, len).unwrap();
+                corner
+            })
+            .collect()
+    }
+}
+
+/// Maps every distinct coordinate to a new one, keeping them in the same order.
+fn spread(
+    rng: &mut impl Rng,
+    coords: impl Iterator<Item = usize>,
+    max_gap: usize,
+) -> HashMap<usize, usize> {
+    let mut at = 0;
+    coords
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .map(|coord| {
+            at += rng.gen_range(1..=max_gap);
+            (coord, at)
+        })
+        .collect()
+}
+
+fn merge(moves: Vec<(Direction, usize)>) -> Vec<(Direction, usize)> {
+    moves.into_iter().filter(|&(_, len)| len > 0).fold(
+        vec![],
+        |mut merged: Vec<(Direction, usize)>, (direction, len)| {
+            match merged.last_mut() {
+                Some((last, last_len)) if *last == direction => *last_len += len,
+                _ => merged.push((direction, len)),
+            }
+            merged
+        },
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::rng;
+    use rstest::rstest;
+    use std::collections::HashSet;
+
+    #[rstest]
+    #[case(1, 1)]
+    #[case(5, 3)]
+    #[case(40, 40)]
+    fn test_outline_is_closed_and_simple(#[case] width: usize, #[case] height: usize) {
+        for seed in 0..20 {
+            let outline = Outline::random(&mut rng(seed), width, height);
+            let points = outline.points();
+
+            assert_eq!(points.iter().collect::<HashSet<_>>().len(), points.len());
+            assert!(points
+                .iter()
+                .all(|&(row, col)| row <= height && col <= width));
+            let last = *points.last().unwrap();
+            let (direction, _) = *outline.moves.last().unwrap();
+            assert_eq!(direction.checked_offset(last, 1), Some(outline.start));
+        }
+    }
+
+    #[test]
+    fn test_stretch_keeps_directions() {
+        let outline = Outline::random(&mut rng(1), 10, 10);
+        let stretched = outline.stretch(&mut rng(2), 1000);
+        let points = stretched.points();
+
+        assert!(outline
+            .moves
+            .iter()
+            .zip(&stretched.moves)
+            .all(|(a, b)| a.0 == b.0 && a.1 <= b.1));
+        assert_eq!(points.iter().collect::<HashSet<_>>().len(), points.len());
+    }
+}
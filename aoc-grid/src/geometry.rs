@@ -0,0 +1,249 @@
+/// Grid position as `(row, col)`.
+pub type Point = (usize, usize);
+
+/// Position as `(row, col)` that is allowed to leave the grid, e.g. while walking off an edge.
+pub type SignedPoint = (i64, i64);
+
+/// One of the 4 orthogonal directions, with north pointing towards row 0.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    North,
+    South,
+    East,
+    West,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::North,
+        Direction::South,
+        Direction::East,
+        Direction::West,
+    ];
+
+    /// Unit offset as `(row, col)`.
+    pub fn offset(self) -> SignedPoint {
+        match self {
+            Direction::North => (-1, 0),
+            Direction::South => (1, 0),
+            Direction::East => (0, 1),
+            Direction::West => (0, -1),
+        }
+    }
+
+    pub fn turn_left(self) -> Direction {
+        match self {
+            Direction::North => Direction::West,
+            Direction::West => Direction::South,
+            Direction::South => Direction::East,
+            Direction::East => Direction::North,
+        }
+    }
+
+    pub fn turn_right(self) -> Direction {
+        self.turn_left().reverse()
+    }
+
+    pub fn reverse(self) -> Direction {
+        match self {
+            Direction::North => Direction::South,
+            Direction::South => Direction::North,
+            Direction::East => Direction::West,
+            Direction::West => Direction::East,
+        }
+    }
+
+    pub fn offset_by(self, pos: SignedPoint, by: i64) -> SignedPoint {
+        offset_by(pos, self.offset(), by)
+    }
+
+    /// Moves `by` steps, returning `None` when that would go past row or column 0. The far edges
+    /// are not known here, so bounds checks against them are left to the caller.
+    pub fn checked_offset(self, pos: Point, by: usize) -> Option<Point> {
+        checked_offset(pos, self.offset(), by)
+    }
+
+    /// Moves `by` steps on a torus of `(height, width)`, wrapping around at every edge.
+    pub fn wrapping_offset(self, pos: Point, by: usize, bounds: (usize, usize)) -> Point {
+        wrapping_offset(pos, self.offset(), by, bounds)
+    }
+}
+
+/// One of the 8 orthogonal and diagonal directions, with north pointing towards row 0.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction8 {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction8 {
+    /// Clockwise, starting at north.
+    pub const ALL: [Direction8; 8] = [
+        Direction8::North,
+        Direction8::NorthEast,
+        Direction8::East,
+        Direction8::SouthEast,
+        Direction8::South,
+        Direction8::SouthWest,
+        Direction8::West,
+        Direction8::NorthWest,
+    ];
+
+    /// Unit offset as `(row, col)`.
+    pub fn offset(self) -> SignedPoint {
+        match self {
+            Direction8::North => (-1, 0),
+            Direction8::NorthEast => (-1, 1),
+            Direction8::East => (0, 1),
+            Direction8::SouthEast => (1, 1),
+            Direction8::South => (1, 0),
+            Direction8::SouthWest => (1, -1),
+            Direction8::West => (0, -1),
+            Direction8::NorthWest => (-1, -1),
+        }
+    }
+
+    /// Turns 45 degrees counter-clockwise.
+    pub fn turn_left(self) -> Direction8 {
+        Self::ALL[(self as usize + 7) % 8]
+    }
+
+    /// Turns 45 degrees clockwise.
+    pub fn turn_right(self) -> Direction8 {
+        Self::ALL[(self as usize + 1) % 8]
+    }
+
+    pub fn reverse(self) -> Direction8 {
+        Self::ALL[(self as usize + 4) % 8]
+    }
+
+    pub fn offset_by(self, pos: SignedPoint, by: i64) -> SignedPoint {
+        offset_by(pos, self.offset(), by)
+    }
+
+    /// See [`Direction::checked_offset`].
+    pub fn checked_offset(self, pos: Point, by: usize) -> Option<Point> {
+        checked_offset(pos, self.offset(), by)
+    }
+
+    /// See [`Direction::wrapping_offset`].
+    pub fn wrapping_offset(self, pos: Point, by: usize, bounds: (usize, usize)) -> Point {
+        wrapping_offset(pos, self.offset(), by, bounds)
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::North => Direction8::North,
+            Direction::South => Direction8::South,
+            Direction::East => Direction8::East,
+            Direction::West => Direction8::West,
+        }
+    }
+}
+
+fn offset_by(pos: SignedPoint, (row, col): SignedPoint, by: i64) -> SignedPoint {
+    (pos.0 + row * by, pos.1 + col * by)
+}
+
+fn checked_offset(pos: Point, (row, col): SignedPoint, by: usize) -> Option<Point> {
+    let step = |value: usize, delta: i64| match delta {
+        -1 => value.checked_sub(by),
+        0 => Some(value),
+        _ => value.checked_add(by),
+    };
+    Some((step(pos.0, row)?, step(pos.1, col)?))
+}
+
+fn wrapping_offset(
+    pos: Point,
+    (row, col): SignedPoint,
+    by: usize,
+    (height, width): (usize, usize),
+) -> Point {
+    let step = |value: usize, delta: i64, len: usize| match delta {
+        -1 => (value + len - by % len) % len,
+        0 => value,
+        _ => (value + by % len) % len,
+    };
+    (step(pos.0, row, height), step(pos.1, col, width))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(Direction::North, (0, 3), None)]
+    #[case(Direction::West, (3, 0), None)]
+    #[case(Direction::North, (1, 0), Some((0, 0)))]
+    #[case(Direction::West, (0, 1), Some((0, 0)))]
+    #[case(Direction::South, (0, 0), Some((1, 0)))]
+    #[case(Direction::East, (0, 0), Some((0, 1)))]
+    fn test_checked_offset_at_edge(
+        #[case] direction: Direction,
+        #[case] pos: Point,
+        #[case] expected: Option<Point>,
+    ) {
+        assert_eq!(direction.checked_offset(pos, 1), expected);
+    }
+
+    #[rstest]
+    #[case(Direction8::NorthEast, (0, 0), None)]
+    #[case(Direction8::SouthWest, (5, 0), None)]
+    #[case(Direction8::NorthWest, (1, 1), Some((0, 0)))]
+    #[case(Direction8::SouthEast, (0, 0), Some((1, 1)))]
+    fn test_checked_offset_diagonal_at_edge(
+        #[case] direction: Direction8,
+        #[case] pos: Point,
+        #[case] expected: Option<Point>,
+    ) {
+        assert_eq!(direction.checked_offset(pos, 1), expected);
+    }
+
+    #[test]
+    fn test_checked_offset_scaled() {
+        assert_eq!(Direction::North.checked_offset((2, 0), 3), None);
+        assert_eq!(Direction::North.checked_offset((3, 0), 3), Some((0, 0)));
+    }
+
+    #[rstest]
+    #[case(Direction::North, (0, 2), 1, (4, 2))]
+    #[case(Direction::West, (2, 0), 1, (2, 4))]
+    #[case(Direction::South, (4, 2), 1, (0, 2))]
+    #[case(Direction::East, (2, 4), 1, (2, 0))]
+    #[case(Direction::West, (0, 0), 12, (0, 3))]
+    fn test_wrapping_offset(
+        #[case] direction: Direction,
+        #[case] pos: Point,
+        #[case] by: usize,
+        #[case] expected: Point,
+    ) {
+        assert_eq!(direction.wrapping_offset(pos, by, (5, 5)), expected);
+    }
+
+    #[test]
+    fn test_turning() {
+        for direction in Direction::ALL {
+            assert_eq!(direction.turn_left().turn_right(), direction);
+            assert_eq!(direction.turn_right().turn_right(), direction.reverse());
+        }
+        assert_eq!(Direction::North.turn_left(), Direction::West);
+        assert_eq!(Direction::North.turn_right(), Direction::East);
+
+        for direction in Direction8::ALL {
+            assert_eq!(direction.turn_left().turn_right(), direction);
+            let (row, col) = direction.offset();
+            assert_eq!(direction.reverse().offset(), (-row, -col));
+        }
+        assert_eq!(Direction8::North.turn_left(), Direction8::NorthWest);
+    }
+}
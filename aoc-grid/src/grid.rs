@@ -0,0 +1,281 @@
+use crate::geometry::{Direction, Point, SignedPoint};
+use aoc_common::ParseError;
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    ops::{Index, IndexMut},
+};
+
+/// Dense, row-major 2D grid addressed by `(row, col)`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GridError {
+    Empty,
+    UnexpectedChar(char),
+    RaggedLine { expected: usize, found: usize },
+}
+
+impl Display for GridError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GridError::Empty => write!(f, "grid is empty"),
+            GridError::UnexpectedChar(found) => write!(f, "unexpected char {:?}", found),
+            GridError::RaggedLine { expected, found } => write!(
+                f,
+                "line has {} columns but {} were expected",
+                found, expected
+            ),
+        }
+    }
+}
+
+impl Error for GridError {}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(
+            cells.len(),
+            width * height,
+            "{}x{} grid needs {} cells",
+            width,
+            height,
+            width * height
+        );
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self::new(width, height, vec![value; width * height])
+    }
+
+    /// Parses one line per row, mapping every char with `f`. A `None` from `f` is reported as an
+    /// unexpected char pointing at that char.
+    pub fn parse(
+        input: &str,
+        mut f: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError<GridError>> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = vec![];
+
+        for line in input.lines() {
+            let start_len = cells.len();
+            for (col_idx, c) in line.char_indices() {
+                let cell = f(c).ok_or_else(|| {
+                    let found = &line[col_idx..col_idx + c.len_utf8()];
+                    ParseError::new(input, found, GridError::UnexpectedChar(c))
+                })?;
+                cells.push(cell);
+            }
+
+            let line_width = cells.len() - start_len;
+            match width {
+                None => width = Some(line_width),
+                Some(expected) if expected != line_width => {
+                    return Err(ParseError::new(
+                        input,
+                        line,
+                        GridError::RaggedLine {
+                            expected,
+                            found: line_width,
+                        },
+                    ))
+                }
+                Some(_) => {}
+            }
+            height += 1;
+        }
+
+        match width {
+            Some(width) if width > 0 => Ok(Self::new(width, height, cells)),
+            _ => Err(ParseError::new(input, input, GridError::Empty)),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (row, col): Point) -> bool {
+        row < self.height && col < self.width
+    }
+
+    pub fn get(&self, pos: Point) -> Option<&T> {
+        self.contains(pos).then(|| &self.cells[self.index_of(pos)])
+    }
+
+    pub fn get_mut(&mut self, pos: Point) -> Option<&mut T> {
+        if self.contains(pos) {
+            let idx = self.index_of(pos);
+            Some(&mut self.cells[idx])
+        } else {
+            None
+        }
+    }
+
+    /// Converts a signed position into a grid position if it lies within the grid.
+    pub fn checked_position(&self, (row, col): SignedPoint) -> Option<Point> {
+        let pos = (usize::try_from(row).ok()?, usize::try_from(col).ok()?);
+        self.contains(pos).then_some(pos)
+    }
+
+    /// Orthogonal neighbours of `pos` that lie within the grid.
+    pub fn neighbours(&self, pos: Point) -> impl Iterator<Item = Point> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| direction.checked_offset(pos, 1))
+            .filter(|&pos| self.contains(pos))
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn column(
+        &self,
+        col: usize,
+    ) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator<Item = &T> {
+        assert!(col < self.width, "column {} is out of bounds", col);
+        self.cells[col..].iter().step_by(self.width)
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width)
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |idx| (idx / width, idx % width))
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Point, &mut T)> {
+        let width = self.width;
+        self.cells
+            .iter_mut()
+            .enumerate()
+            .map(move |(idx, cell)| ((idx / width, idx % width), cell))
+    }
+
+    /// Position of the first cell, in row-major order, matching `predicate`.
+    pub fn position(&self, predicate: impl FnMut(&T) -> bool) -> Option<Point> {
+        self.cells
+            .iter()
+            .position(predicate)
+            .map(|idx| (idx / self.width, idx % self.width))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::new(self.width, self.height, self.cells.iter().map(f).collect())
+    }
+
+    fn index_of(&self, (row, col): Point) -> usize {
+        row * self.width + col
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Point) -> &Self::Output {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{:?} is outside of the grid", pos))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, pos: Point) -> &mut Self::Output {
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{:?} is outside of the grid", pos))
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (row_idx, row) in self.rows().enumerate() {
+            if row_idx > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rstest::rstest;
+
+    #[test]
+    fn test_parse_display_round_trip() {
+        let input = "#.#
+..#
+#..";
+        let grid = Grid::parse(input, Some).unwrap();
+
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 3);
+        assert_eq!(grid[(1, 2)], '#');
+        assert_eq!(grid.to_string(), input);
+    }
+
+    #[rstest]
+    #[case("", GridError::Empty, (1, 1))]
+    #[case("..\n.x", GridError::UnexpectedChar('x'), (2, 2))]
+    #[case("..\n...", GridError::RaggedLine { expected: 2, found: 3 }, (2, 1))]
+    fn test_parse_failure(
+        #[case] input: &str,
+        #[case] expected: GridError,
+        #[case] position: (usize, usize),
+    ) {
+        let err = Grid::parse(input, |c| matches!(c, '.' | '#').then_some(c)).unwrap_err();
+
+        assert_eq!(err.kind, expected);
+        assert_eq!((err.line, err.column), position);
+    }
+
+    #[rstest]
+    #[case((0, 0), vec![(1, 0), (0, 1)])]
+    #[case((1, 1), vec![(0, 1), (2, 1), (1, 2), (1, 0)])]
+    #[case((2, 3), vec![(1, 3), (2, 2)])]
+    fn test_neighbours(#[case] pos: Point, #[case] expected: Vec<(usize, usize)>) {
+        let grid = Grid::filled(4, 3, ());
+
+        assert_eq!(grid.neighbours(pos).collect::<Vec<_>>(), expected);
+    }
+
+    #[test]
+    fn test_column() {
+        let grid = Grid::parse("ab\ncd\nef", Some).unwrap();
+
+        assert_eq!(grid.column(1).collect::<String>(), "bdf");
+        assert_eq!(grid.column(0).rev().collect::<String>(), "eca");
+    }
+}
@@ -0,0 +1,5 @@
+pub mod geometry;
+pub mod grid;
+
+pub use geometry::{Direction, Direction8, Point, SignedPoint};
+pub use grid::{Grid, GridError};
@@ -0,0 +1,5 @@
+pub mod queue;
+pub mod search;
+
+pub use queue::{BucketPriority, BucketQueue, Frontier, HeapQueue};
+pub use search::{astar, bucket_astar, bucket_dijkstra, dijkstra, search, Cost, Path};
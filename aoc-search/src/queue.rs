@@ -0,0 +1,119 @@
+use std::{cmp::Reverse, collections::BinaryHeap};
+
+/// Min-priority queue holding the states that are still to be expanded.
+pub trait Frontier<P, T>: Default {
+    fn push(&mut self, priority: P, item: T);
+
+    /// Removes an item with the lowest priority.
+    fn pop(&mut self) -> Option<T>;
+}
+
+/// Binary heap frontier that works for any ordered priority.
+#[derive(Debug, Clone)]
+pub struct HeapQueue<P, T> {
+    heap: BinaryHeap<Reverse<(P, T)>>,
+}
+
+impl<P: Ord, T: Ord> Default for HeapQueue<P, T> {
+    fn default() -> Self {
+        Self {
+            heap: BinaryHeap::new(),
+        }
+    }
+}
+
+impl<P: Ord, T: Ord> Frontier<P, T> for HeapQueue<P, T> {
+    fn push(&mut self, priority: P, item: T) {
+        self.heap.push(Reverse((priority, item)));
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        self.heap.pop().map(|Reverse((_, item))| item)
+    }
+}
+
+/// Priority that can be used directly as a bucket index.
+pub trait BucketPriority: Copy {
+    fn bucket(self) -> usize;
+}
+
+macro_rules! impl_bucket_priority {
+    ($($ty:ty),+) => {
+        $(
+            impl BucketPriority for $ty {
+                fn bucket(self) -> usize {
+                    usize::try_from(self).expect("priority does not fit into a bucket index")
+                }
+            }
+        )+
+    };
+}
+
+impl_bucket_priority!(u8, u16, u32, u64, usize);
+
+/// Bucket queue (Dial's algorithm) with one bucket per priority. Popping is amortised O(1) as long
+/// as priorities never drop below the last popped one, which holds for Dijkstra and for A* with a
+/// consistent heuristic. It stays correct otherwise, only slower.
+#[derive(Debug, Clone)]
+pub struct BucketQueue<T> {
+    buckets: Vec<Vec<T>>,
+    current: usize,
+    len: usize,
+}
+
+impl<T> Default for BucketQueue<T> {
+    fn default() -> Self {
+        Self {
+            buckets: vec![],
+            current: 0,
+            len: 0,
+        }
+    }
+}
+
+impl<P: BucketPriority, T> Frontier<P, T> for BucketQueue<T> {
+    fn push(&mut self, priority: P, item: T) {
+        let bucket = priority.bucket();
+        if bucket >= self.buckets.len() {
+            self.buckets.resize_with(bucket + 1, Vec::new);
+        }
+        self.buckets[bucket].push(item);
+        self.current = self.current.min(bucket);
+        self.len += 1;
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+        while self.buckets[self.current].is_empty() {
+            self.current += 1;
+        }
+        self.len -= 1;
+        self.buckets[self.current].pop()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn drain<Q: Frontier<u32, char>>(mut queue: Q) -> String {
+        for (priority, item) in [(5, 'c'), (0, 'a'), (9, 'd'), (3, 'b')] {
+            queue.push(priority, item);
+        }
+        let mut popped = String::new();
+        popped.push(queue.pop().unwrap());
+        queue.push(1, 'e');
+        while let Some(item) = queue.pop() {
+            popped.push(item);
+        }
+        popped
+    }
+
+    #[test]
+    fn test_pops_lowest_priority_first() {
+        assert_eq!(drain(HeapQueue::default()), "aebcd");
+        assert_eq!(drain(BucketQueue::default()), "aebcd");
+    }
+}
@@ -0,0 +1,234 @@
+use crate::queue::{BucketPriority, BucketQueue, Frontier, HeapQueue};
+use std::{
+    collections::{hash_map::Entry, HashMap},
+    hash::Hash,
+    ops::Add,
+};
+
+/// Edge weight and path cost. `Default` is taken as the zero cost of the start state.
+pub trait Cost: Copy + Ord + Add<Output = Self> + Default {}
+
+impl<T: Copy + Ord + Add<Output = T> + Default> Cost for T {}
+
+/// Cheapest path found, with `states` running from the start state to the goal inclusive.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<S, C> {
+    pub states: Vec<S>,
+    pub cost: C,
+}
+
+pub fn dijkstra<S, C, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    search::<HeapQueue<C, (C, usize)>, _, _, _>(start, successors, |_| C::default(), is_goal)
+}
+
+/// `heuristic` must never overestimate the remaining cost, otherwise the path found may not be
+/// the cheapest one.
+pub fn astar<S, C, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    heuristic: impl FnMut(&S) -> C,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    search::<HeapQueue<C, (C, usize)>, _, _, _>(start, successors, heuristic, is_goal)
+}
+
+/// [`dijkstra`] over a [`BucketQueue`], for small integer weights.
+pub fn bucket_dijkstra<S, C, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Cost + BucketPriority,
+    I: IntoIterator<Item = (S, C)>,
+{
+    search::<BucketQueue<(C, usize)>, _, _, _>(start, successors, |_| C::default(), is_goal)
+}
+
+/// [`astar`] over a [`BucketQueue`], for small integer weights.
+pub fn bucket_astar<S, C, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    heuristic: impl FnMut(&S) -> C,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Cost + BucketPriority,
+    I: IntoIterator<Item = (S, C)>,
+{
+    search::<BucketQueue<(C, usize)>, _, _, _>(start, successors, heuristic, is_goal)
+}
+
+/// Best-first search from `start` until a state matching `is_goal` is popped. Every state is
+/// prioritised by its cost so far plus `heuristic`, so a heuristic of zero gives Dijkstra.
+///
+/// `successors` yields the neighbouring states along with the weight of the edge to them.
+#[cfg_attr(feature = "trace", tracing::instrument(level = "debug", skip_all))]
+pub fn search<Q, S, C, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    Q: Frontier<C, (C, usize)>,
+    S: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut queue = Q::default();
+    queue.push(heuristic(&start), (C::default(), 0));
+    let mut indices = HashMap::from([(start.clone(), 0)]);
+    let mut nodes = vec![Node {
+        state: start,
+        parent: None,
+        cost: C::default(),
+    }];
+
+    #[cfg(feature = "trace")]
+    let mut pops = 0_usize;
+    while let Some((cost, idx)) = queue.pop() {
+        #[cfg(feature = "trace")]
+        {
+            pops += 1;
+            tracing::trace!(node = idx, discovered = nodes.len(), "pop");
+        }
+        if cost > nodes[idx].cost {
+            // a cheaper way to this state has been found since it was queued
+            continue;
+        }
+        if is_goal(&nodes[idx].state) {
+            #[cfg(feature = "trace")]
+            tracing::debug!(pops, discovered = nodes.len(), "reached goal");
+            return Some(Path {
+                states: trace_back(&nodes, idx),
+                cost,
+            });
+        }
+
+        for (next, weight) in successors(&nodes[idx].state) {
+            let next_cost = cost + weight;
+            let next_idx = match indices.entry(next) {
+                Entry::Occupied(entry) => {
+                    let node = &mut nodes[*entry.get()];
+                    if node.cost <= next_cost {
+                        continue;
+                    }
+                    node.parent = Some(idx);
+                    node.cost = next_cost;
+                    *entry.get()
+                }
+                Entry::Vacant(entry) => {
+                    nodes.push(Node {
+                        state: entry.key().clone(),
+                        parent: Some(idx),
+                        cost: next_cost,
+                    });
+                    *entry.insert(nodes.len() - 1)
+                }
+            };
+            queue.push(
+                next_cost + heuristic(&nodes[next_idx].state),
+                (next_cost, next_idx),
+            );
+        }
+    }
+    #[cfg(feature = "trace")]
+    tracing::debug!(pops, discovered = nodes.len(), "exhausted queue");
+    None
+}
+
+struct Node<S, C> {
+    state: S,
+    parent: Option<usize>,
+    cost: C,
+}
+
+fn trace_back<S: Clone, C>(nodes: &[Node<S, C>], mut idx: usize) -> Vec<S> {
+    let mut states = vec![nodes[idx].state.clone()];
+    while let Some(parent) = nodes[idx].parent {
+        states.push(nodes[parent].state.clone());
+        idx = parent;
+    }
+    states.reverse();
+    states
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rstest::rstest;
+
+    type Search = fn(char, char) -> Option<Path<char, u32>>;
+
+    const EDGES: &[(char, char, u32)] = &[
+        ('a', 'b', 7),
+        ('a', 'c', 9),
+        ('a', 'f', 14),
+        ('b', 'c', 10),
+        ('b', 'd', 15),
+        ('c', 'd', 11),
+        ('c', 'f', 2),
+        ('d', 'e', 6),
+        ('e', 'f', 9),
+    ];
+
+    fn neighbours(state: &char) -> Vec<(char, u32)> {
+        EDGES
+            .iter()
+            .filter_map(|&(from, to, weight)| match *state {
+                s if s == from => Some((to, weight)),
+                s if s == to => Some((from, weight)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Never overestimates, as every edge on the way to 'e' weighs at least 2.
+    fn hops_to_e(state: &char) -> u32 {
+        match state {
+            'e' => 0,
+            'd' | 'f' => 2,
+            _ => 4,
+        }
+    }
+
+    #[rstest]
+    #[case::dijkstra(|start, goal| dijkstra(start, neighbours, |&s| s == goal))]
+    #[case::astar(|start, goal| astar(start, neighbours, hops_to_e, |&s| s == goal))]
+    #[case::bucket_dijkstra(|start, goal| bucket_dijkstra(start, neighbours, |&s| s == goal))]
+    #[case::bucket_astar(|start, goal| bucket_astar(start, neighbours, hops_to_e, |&s| s == goal))]
+    fn test_finds_cheapest_path(#[case] search: Search) {
+        assert_eq!(
+            search('a', 'e'),
+            Some(Path {
+                states: vec!['a', 'c', 'f', 'e'],
+                cost: 20,
+            })
+        );
+        assert_eq!(
+            search('a', 'a'),
+            Some(Path {
+                states: vec!['a'],
+                cost: 0,
+            })
+        );
+        assert_eq!(search('a', 'z'), None);
+    }
+}
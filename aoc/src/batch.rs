@@ -0,0 +1,270 @@
+use std::{
+    fs,
+    panic::{self, AssertUnwindSafe},
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+use aoc_common::{Cancelled, Progress, SolutionEntry};
+use indicatif::{ParallelProgressIterator, ProgressBar, ProgressStyle};
+use rayon::prelude::*;
+use serde::Serialize;
+
+use crate::{
+    cache::{Cache, Entry},
+    registry,
+};
+
+/// Outcome of one solution on one input, as written to the JSON report.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Run {
+    pub input: PathBuf,
+    pub day: u8,
+    pub part: u8,
+    pub variant: &'static str,
+    pub answer: Option<String>,
+    /// Parse or solve error, or the message of a panic.
+    pub error: Option<String>,
+    pub duration_ms: f64,
+    /// Only known when the solution succeeded.
+    pub parse_ms: Option<f64>,
+    pub solve_ms: Option<f64>,
+    /// Whether the answer and timings come from the cache rather than running the solution.
+    pub cached: bool,
+    /// Total time of the cached run, when there is one.
+    pub cached_ms: Option<f64>,
+}
+
+impl Run {
+    pub fn is_failure(&self) -> bool {
+        self.error.is_some()
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct Report {
+    pub runs: Vec<Run>,
+}
+
+/// Every `*.txt` file under a `dayNN` (or `day-NN`) directory of `dir`, sorted by day and path.
+pub fn find_inputs(dir: &Path) -> Result<Vec<(u8, PathBuf)>, String> {
+    let read_dir = |dir: &Path| {
+        fs::read_dir(dir).map_err(|err| format!("Could not read {}: {}", dir.display(), err))
+    };
+
+    let mut inputs = vec![];
+    for entry in read_dir(dir)? {
+        let path = entry.map_err(|err| err.to_string())?.path();
+        let Some(day) = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .and_then(|name| name.strip_prefix("day"))
+            .and_then(|day| day.trim_start_matches('-').parse::<u8>().ok())
+        else {
+            continue;
+        };
+        if !path.is_dir() {
+            continue;
+        }
+
+        for entry in read_dir(&path)? {
+            let input = entry.map_err(|err| err.to_string())?.path();
+            if input.extension().is_some_and(|ext| ext == "txt") {
+                inputs.push((day, input));
+            }
+        }
+    }
+
+    inputs.sort();
+    Ok(inputs)
+}
+
+/// Runs every part of the day in `variant` on each input in parallel, showing a progress bar.
+/// Inputs for days without such a solution are left out of the report, and runs that take longer
+/// than `timeout` are given up on. Answers in `cache` are reused unless `no_cache` is set, and
+/// fresh ones are added to it.
+pub fn run(
+    inputs: &[(u8, PathBuf)],
+    variant: &str,
+    timeout: Option<Duration>,
+    cache: Option<&Cache>,
+    no_cache: bool,
+) -> Report {
+    let jobs = inputs
+        .iter()
+        .flat_map(|(day, input)| {
+            registry::for_day(*day)
+                .filter(|solution| solution.variant == variant)
+                .map(move |solution| (input.as_path(), solution))
+        })
+        .collect::<Vec<_>>();
+
+    let bar = ProgressBar::new(jobs.len() as u64).with_style(
+        ProgressStyle::with_template(
+            "{bar:40} {pos}/{len} [{elapsed_precise}<{eta_precise}] {msg}",
+        )
+        .unwrap(),
+    );
+    // Panics are reported as failed runs, so the default hook would only garble the progress bar.
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let runs = jobs
+        .par_iter()
+        .progress_with(bar.clone())
+        .map(|(input, solution)| {
+            bar.set_message(format!(
+                "day {:02} part {} {}",
+                solution.day,
+                solution.part,
+                input.display()
+            ));
+            run_one(input, solution, timeout, cache, no_cache)
+        })
+        .collect();
+    panic::set_hook(hook);
+    bar.finish_and_clear();
+
+    Report { runs }
+}
+
+fn run_one(
+    input: &Path,
+    solution: &SolutionEntry,
+    timeout: Option<Duration>,
+    cache: Option<&Cache>,
+    no_cache: bool,
+) -> Run {
+    let mut run = Run {
+        input: input.to_path_buf(),
+        day: solution.day,
+        part: solution.part,
+        variant: solution.variant,
+        answer: None,
+        error: None,
+        duration_ms: 0.0,
+        parse_ms: None,
+        solve_ms: None,
+        cached: false,
+        cached_ms: None,
+    };
+    let contents = match fs::read_to_string(input) {
+        Ok(contents) => contents,
+        Err(err) => {
+            run.error = Some(format!("could not read {}: {}", input.display(), err));
+            return run;
+        }
+    };
+
+    let entry = cache.and_then(|cache| cache.get(solution, &contents));
+    run.cached_ms = entry.as_ref().map(|entry| millis(entry.timings.total()));
+    if let Some(entry) = entry.filter(|_| !no_cache) {
+        run.answer = Some(entry.answer);
+        run.duration_ms = millis(entry.timings.total());
+        run.parse_ms = Some(millis(entry.timings.parse));
+        run.solve_ms = Some(millis(entry.timings.solve));
+        run.cached = true;
+        return run;
+    }
+
+    let progress = match timeout {
+        Some(timeout) => Progress::hidden().with_timeout(timeout),
+        None => Progress::hidden(),
+    };
+    let start = Instant::now();
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        (solution.run_with)(&contents, &progress)
+    }));
+    run.duration_ms = millis(start.elapsed());
+    let status = progress.finish();
+
+    match result {
+        Ok(Ok((answer, timings))) => {
+            if let Some(cache) = cache {
+                let entry = Entry {
+                    answer: answer.clone(),
+                    timings,
+                };
+                if let Err(err) = cache.insert(solution, &contents, &entry) {
+                    run.error = Some(err);
+                }
+            }
+            run.answer = Some(answer);
+            run.parse_ms = Some(millis(timings.parse));
+            run.solve_ms = Some(millis(timings.solve));
+        }
+        Ok(Err(err)) if Cancelled::caused(err.as_ref()) => run.error = Some(status.to_string()),
+        Ok(Err(err)) => run.error = Some(err.to_string()),
+        Err(payload) => {
+            let message = payload
+                .downcast_ref::<&str>()
+                .map(|message| message.to_string())
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_default();
+            run.error = Some(format!("panicked: {}", message));
+        }
+    }
+    run
+}
+
+fn millis(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_batch() {
+        let tmp = tempfile::tempdir().unwrap();
+        let dir = tmp.path();
+        fs::create_dir_all(dir.join("day02")).unwrap();
+        fs::create_dir_all(dir.join("day-06")).unwrap();
+        fs::create_dir_all(dir.join("notes")).unwrap();
+        fs::write(
+            dir.join("day02/alice.txt"),
+            "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green",
+        )
+        .unwrap();
+        fs::write(dir.join("day02/bob.txt"), "Game 1: 3 purple").unwrap();
+        fs::write(dir.join("day02/README.md"), "not an input").unwrap();
+        fs::write(
+            dir.join("day-06/carol.txt"),
+            "Time:      7  15   30\nDistance:  9  40  200",
+        )
+        .unwrap();
+        fs::write(dir.join("notes/dave.txt"), "not a day").unwrap();
+
+        let inputs = find_inputs(dir).unwrap();
+        assert_eq!(
+            inputs,
+            vec![
+                (2, dir.join("day02/alice.txt")),
+                (2, dir.join("day02/bob.txt")),
+                (6, dir.join("day-06/carol.txt")),
+            ]
+        );
+
+        let report = run(&inputs, "default", None, None, false);
+        let outcomes = report
+            .runs
+            .iter()
+            .map(|run| (run.day, run.part, run.answer.as_deref(), run.is_failure()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            outcomes,
+            vec![
+                (2, 1, Some("1"), false),
+                (2, 2, Some("48"), false),
+                (2, 1, None, true),
+                (2, 2, None, true),
+                (6, 1, Some("288"), false),
+                (6, 2, Some("71503"), false),
+            ]
+        );
+        assert_eq!(
+            report.runs[2].error.as_deref(),
+            Some("unknown cube color at line 1, column 11: \"purple\"")
+        );
+    }
+}
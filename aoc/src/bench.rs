@@ -0,0 +1,384 @@
+use std::{
+    cmp::Ordering,
+    fmt::{self, Write},
+    fs,
+    path::{Path, PathBuf},
+};
+
+use clap::ValueEnum;
+use serde::Deserialize;
+
+/// Criterion's summary of one benchmark run, in nanoseconds.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Estimate {
+    pub mean: f64,
+    pub lower: f64,
+    pub upper: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Input {
+    Puzzle(Phase),
+    /// Input from the day's `generate` module of the given size.
+    Generated(usize),
+}
+
+/// Part of a solution a benchmark measures.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Phase {
+    Both,
+    Parse,
+    Solve,
+}
+
+impl fmt::Display for Input {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Input::Puzzle(Phase::Both) => write!(f, "puzzle"),
+            Input::Puzzle(Phase::Parse) => write!(f, "puzzle (parse)"),
+            Input::Puzzle(Phase::Solve) => write!(f, "puzzle (solve)"),
+            Input::Generated(size) => write!(f, "generated({})", size),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Row {
+    pub day: u8,
+    pub part: u8,
+    pub variant: String,
+    pub input: Input,
+    pub estimate: Estimate,
+    pub baseline: Option<Estimate>,
+}
+
+impl Row {
+    /// Relative change of the mean against the baseline, e.g. `0.1` for 10% slower.
+    pub fn delta(&self) -> Option<f64> {
+        self.baseline
+            .map(|baseline| self.estimate.mean / baseline.mean - 1.0)
+    }
+
+    /// Only counts as a change when the confidence intervals do not overlap, so that noise does
+    /// not show up as a regression.
+    pub fn change(&self) -> Option<Ordering> {
+        let baseline = self.baseline?;
+        if self.estimate.lower > baseline.upper {
+            Some(Ordering::Greater)
+        } else if self.estimate.upper < baseline.lower {
+            Some(Ordering::Less)
+        } else {
+            Some(Ordering::Equal)
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    Markdown,
+    Csv,
+}
+
+#[derive(Deserialize)]
+struct Benchmark {
+    group_id: String,
+    function_id: Option<String>,
+    value_str: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct Estimates {
+    mean: Statistic,
+}
+
+#[derive(Deserialize)]
+struct Statistic {
+    point_estimate: f64,
+    confidence_interval: ConfidenceInterval,
+}
+
+#[derive(Deserialize)]
+struct ConfidenceInterval {
+    lower_bound: f64,
+    upper_bound: f64,
+}
+
+/// Reads the latest run of every day benchmark under `criterion_dir`, along with the run saved as
+/// `baseline` (see criterion's `--save-baseline`) if there is one.
+///
+/// Benchmarks whose group is not named `day_NN::partN[_variant][::parse|::solve|::generated]` are
+/// skipped.
+pub fn collect(criterion_dir: &Path, baseline: Option<&str>) -> Result<Vec<Row>, String> {
+    let mut benchmarks = vec![];
+    find_benchmarks(criterion_dir, &mut benchmarks)
+        .map_err(|err| format!("Could not read {}: {}", criterion_dir.display(), err))?;
+
+    let mut rows = vec![];
+    for dir in benchmarks {
+        let benchmark: Benchmark = read_json(&dir.join("new").join("benchmark.json"))?;
+        let Some((day, part, variant, input)) = parse_id(&benchmark) else {
+            continue;
+        };
+        let estimate = read_estimate(&dir.join("new"))?;
+        let baseline = match baseline {
+            Some(baseline) if dir.join(baseline).is_dir() => {
+                Some(read_estimate(&dir.join(baseline))?)
+            }
+            _ => None,
+        };
+
+        rows.push(Row {
+            day,
+            part,
+            variant,
+            input,
+            estimate,
+            baseline,
+        });
+    }
+
+    rows.sort_by(|a, b| {
+        (a.day, a.part, &a.variant, a.input).cmp(&(b.day, b.part, &b.variant, b.input))
+    });
+    Ok(rows)
+}
+
+/// Every directory holding a `new` run, which criterion nests one or two levels down depending on
+/// whether the benchmark has a parameter.
+fn find_benchmarks(dir: &Path, benchmarks: &mut Vec<PathBuf>) -> std::io::Result<()> {
+    if dir.join("new").join("benchmark.json").is_file() {
+        benchmarks.push(dir.to_path_buf());
+        return Ok(());
+    }
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() && path.file_name().is_some_and(|name| name != "report") {
+            find_benchmarks(&path, benchmarks)?;
+        }
+    }
+    Ok(())
+}
+
+fn read_json<T: for<'de> Deserialize<'de>>(path: &Path) -> Result<T, String> {
+    let json = fs::read_to_string(path)
+        .map_err(|err| format!("Could not read {}: {}", path.display(), err))?;
+    serde_json::from_str(&json)
+        .map_err(|err| format!("Could not parse {}: {}", path.display(), err))
+}
+
+fn read_estimate(run_dir: &Path) -> Result<Estimate, String> {
+    let estimates: Estimates = read_json(&run_dir.join("estimates.json"))?;
+    Ok(Estimate {
+        mean: estimates.mean.point_estimate,
+        lower: estimates.mean.confidence_interval.lower_bound,
+        upper: estimates.mean.confidence_interval.upper_bound,
+    })
+}
+
+/// Splits the ids the day benches use, `day_17::part1_astar` with the function id `part1`, the same
+/// with a `::parse` or `::solve` suffix, or `day_17::part1_astar::generated` with the size as
+/// parameter.
+fn parse_id(benchmark: &Benchmark) -> Option<(u8, u8, String, Input)> {
+    let mut segments = benchmark.group_id.split("::");
+    let day = segments.next()?.strip_prefix("day_")?.parse().ok()?;
+    let name = segments.next()?.strip_prefix("part")?;
+    let (part, variant) = match name.split_once('_') {
+        Some((part, variant)) => (part, variant),
+        None => (name, aoc_common::DEFAULT_VARIANT),
+    };
+    let part = part.parse().ok()?;
+    let input = match (segments.next(), &benchmark.value_str) {
+        (None, _) if benchmark.function_id.is_some() => Input::Puzzle(Phase::Both),
+        (Some("parse"), _) if benchmark.function_id.is_some() => Input::Puzzle(Phase::Parse),
+        (Some("solve"), _) if benchmark.function_id.is_some() => Input::Puzzle(Phase::Solve),
+        (Some("generated"), Some(size)) => Input::Generated(size.parse().ok()?),
+        _ => return None,
+    };
+    if segments.next().is_some() {
+        return None;
+    }
+
+    Some((day, part, variant.to_string(), input))
+}
+
+/// Formats nanoseconds the way criterion prints them.
+fn format_time(ns: f64) -> String {
+    match ns {
+        ns if ns < 1e3 => format!("{:.2} ns", ns),
+        ns if ns < 1e6 => format!("{:.2} µs", ns / 1e3),
+        ns if ns < 1e9 => format!("{:.2} ms", ns / 1e6),
+        ns => format!("{:.2} s", ns / 1e9),
+    }
+}
+
+fn format_delta(row: &Row) -> String {
+    let Some(delta) = row.delta() else {
+        return String::new();
+    };
+    let change = match row.change() {
+        Some(Ordering::Greater) => " regressed",
+        Some(Ordering::Less) => " improved",
+        _ => "",
+    };
+    format!("{:+.2}%{}", delta * 100.0, change)
+}
+
+/// Table meant to be pasted into a README. The baseline columns are left out when no row has a
+/// baseline.
+pub fn markdown(rows: &[Row]) -> String {
+    let with_baseline = rows.iter().any(|row| row.baseline.is_some());
+    let mut table = String::new();
+
+    table.push_str("| day | part | variant | input | mean | 95% CI |");
+    if with_baseline {
+        table.push_str(" baseline | delta |");
+    }
+    table.push_str("\n|---:|---:|---|---|---:|---:|");
+    if with_baseline {
+        table.push_str("---:|---:|");
+    }
+    table.push('\n');
+
+    for row in rows {
+        write!(
+            table,
+            "| {:02} | {} | {} | {} | {} | {} – {} |",
+            row.day,
+            row.part,
+            row.variant,
+            row.input,
+            format_time(row.estimate.mean),
+            format_time(row.estimate.lower),
+            format_time(row.estimate.upper),
+        )
+        .unwrap();
+        if with_baseline {
+            let baseline = row
+                .baseline
+                .map_or(String::new(), |baseline| format_time(baseline.mean));
+            write!(table, " {} | {} |", baseline, format_delta(row)).unwrap();
+        }
+        table.push('\n');
+    }
+    table
+}
+
+/// Same columns as [`markdown`] but with raw nanoseconds and the delta as a ratio, for
+/// spreadsheets and scripts.
+pub fn csv(rows: &[Row]) -> String {
+    let mut table = String::from(
+        "day,part,variant,input,mean_ns,lower_ns,upper_ns,baseline_mean_ns,delta,change\n",
+    );
+    for row in rows {
+        let change = match row.change() {
+            Some(Ordering::Greater) => "regressed",
+            Some(Ordering::Less) => "improved",
+            Some(Ordering::Equal) => "unchanged",
+            None => "",
+        };
+        writeln!(
+            table,
+            "{},{},{},{},{},{},{},{},{},{}",
+            row.day,
+            row.part,
+            row.variant,
+            row.input,
+            row.estimate.mean,
+            row.estimate.lower,
+            row.estimate.upper,
+            row.baseline
+                .map_or(String::new(), |baseline| baseline.mean.to_string()),
+            row.delta().map_or(String::new(), |delta| delta.to_string()),
+            change,
+        )
+        .unwrap();
+    }
+    table
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rstest::rstest;
+
+    fn benchmark(group_id: &str, function_id: Option<&str>, value_str: Option<&str>) -> Benchmark {
+        Benchmark {
+            group_id: group_id.to_string(),
+            function_id: function_id.map(str::to_string),
+            value_str: value_str.map(str::to_string),
+        }
+    }
+
+    #[rstest]
+    #[case(
+        benchmark("day_02::part1", Some("part1"), None),
+        Some((2, 1, "default", Input::Puzzle(Phase::Both)))
+    )]
+    #[case(
+        benchmark("day_05::part2::solve", Some("part2"), None),
+        Some((5, 2, "default", Input::Puzzle(Phase::Solve)))
+    )]
+    #[case(
+        benchmark("day_17::part2_astar_with_skips", Some("part2"), None),
+        Some((17, 2, "astar_with_skips", Input::Puzzle(Phase::Both)))
+    )]
+    #[case(
+        benchmark("day_06::part1::generated", None, Some("3")),
+        Some((6, 1, "default", Input::Generated(3)))
+    )]
+    #[case(benchmark("day_06::part1::generated", None, None), None)]
+    #[case(benchmark("other::part1", Some("part1"), None), None)]
+    fn test_parse_id(
+        #[case] benchmark: Benchmark,
+        #[case] expected: Option<(u8, u8, &str, Input)>,
+    ) {
+        assert_eq!(
+            parse_id(&benchmark),
+            expected.map(|(day, part, variant, input)| (day, part, variant.to_string(), input))
+        );
+    }
+
+    #[test]
+    fn test_collect_and_render() {
+        let tmp = tempfile::tempdir().unwrap();
+        let dir = tmp.path();
+        let write_run = |path: &str, benchmark: &str, mean: f64| {
+            let run = dir.join(path);
+            fs::create_dir_all(&run).unwrap();
+            fs::write(run.join("benchmark.json"), benchmark).unwrap();
+            fs::write(
+                run.join("estimates.json"),
+                format!(
+                    r#"{{"mean":{{"confidence_interval":{{"confidence_level":0.95,"lower_bound":{},"upper_bound":{}}},"point_estimate":{},"standard_error":1.0}}}}"#,
+                    mean - 10.0,
+                    mean + 10.0,
+                    mean
+                ),
+            )
+            .unwrap();
+        };
+        let part1 = r#"{"group_id":"day_06::part1","function_id":"part1","value_str":null}"#;
+        let generated =
+            r#"{"group_id":"day_06::part1::generated","function_id":null,"value_str":"3"}"#;
+        write_run("day_06__part1/part1/new", part1, 2000.0);
+        write_run("day_06__part1/part1/main", part1, 1000.0);
+        write_run("day_06__part1__generated/3/new", generated, 500.0);
+        fs::create_dir_all(dir.join("report")).unwrap();
+
+        let rows = collect(dir, Some("main")).unwrap();
+
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].input, Input::Puzzle(Phase::Both));
+        assert_eq!(rows[0].delta(), Some(1.0));
+        assert_eq!(rows[0].change(), Some(Ordering::Greater));
+        assert_eq!(rows[1].baseline, None);
+        assert_eq!(
+            markdown(&rows),
+            "| day | part | variant | input | mean | 95% CI | baseline | delta |
+|---:|---:|---|---|---:|---:|---:|---:|
+| 06 | 1 | default | puzzle | 2.00 µs | 1.99 µs – 2.01 µs | 1.00 µs | +100.00% regressed |
+| 06 | 1 | default | generated(3) | 500.00 ns | 490.00 ns – 510.00 ns |  |  |
+"
+        );
+    }
+}
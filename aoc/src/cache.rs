@@ -0,0 +1,190 @@
+use std::{
+    env, fmt, fs,
+    path::{Path, PathBuf},
+    time::{Duration, UNIX_EPOCH},
+};
+
+use aoc_common::{SolutionEntry, Timings};
+use serde::{Deserialize, Serialize};
+
+/// Environment variable naming the directory answers are cached in. Nothing is cached without it.
+pub const CACHE_VAR: &str = "AOC_CACHE";
+
+/// Answers of earlier runs on disk, one TOML file per day, part, variant, crate version, build and
+/// input.
+///
+/// The build is told apart by the modification time of the running executable, which cargo relinks
+/// whenever a solution changes, so edits made without bumping the crate version are not answered
+/// from stale entries.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cache {
+    dir: PathBuf,
+    build: u64,
+}
+
+/// A cached answer along with how long it took to find.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    pub answer: String,
+    pub timings: Timings,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Stored {
+    answer: String,
+    parse_ns: u64,
+    solve_ns: u64,
+}
+
+impl Cache {
+    pub fn new(dir: impl Into<PathBuf>, build: u64) -> Self {
+        Self {
+            dir: dir.into(),
+            build,
+        }
+    }
+
+    /// Nothing is cached either when the build of the running executable cannot be told.
+    pub fn from_env() -> Option<Self> {
+        let dir = env::var_os(CACHE_VAR).filter(|dir| !dir.is_empty())?;
+        Some(Self::new(dir, build()?))
+    }
+
+    /// Unreadable or outdated entries count as missing.
+    pub fn get(&self, solution: &SolutionEntry, input: &str) -> Option<Entry> {
+        let stored = fs::read_to_string(self.path(solution, input)).ok()?;
+        let stored: Stored = toml::from_str(&stored).ok()?;
+        Some(Entry {
+            answer: stored.answer,
+            timings: Timings {
+                parse: Duration::from_nanos(stored.parse_ns),
+                solve: Duration::from_nanos(stored.solve_ns),
+            },
+        })
+    }
+
+    pub fn insert(
+        &self,
+        solution: &SolutionEntry,
+        input: &str,
+        entry: &Entry,
+    ) -> Result<(), String> {
+        let path = self.path(solution, input);
+        let stored = Stored {
+            answer: entry.answer.clone(),
+            parse_ns: entry.timings.parse.as_nanos() as u64,
+            solve_ns: entry.timings.solve.as_nanos() as u64,
+        };
+        let stored = toml::to_string(&stored).expect("entry is serializable");
+
+        fs::create_dir_all(path.parent().unwrap_or(Path::new("")))
+            .and_then(|()| fs::write(&path, stored))
+            .map_err(|err| format!("Could not write {}: {}", path.display(), err))
+    }
+
+    fn path(&self, solution: &SolutionEntry, input: &str) -> PathBuf {
+        self.dir
+            .join(format!("day-{:02}", solution.day))
+            .join(format!(
+                "{}-{}-{:016x}-{:016x}.toml",
+                solution.name(),
+                solution.version,
+                self.build,
+                hash(input)
+            ))
+    }
+}
+
+impl Entry {
+    /// How long `timings` took next to the cached run.
+    pub fn compare(&self, timings: &Timings) -> Comparison {
+        Comparison {
+            fresh: timings.total(),
+            cached: self.timings.total(),
+        }
+    }
+}
+
+pub struct Comparison {
+    pub fresh: Duration,
+    pub cached: Duration,
+}
+
+impl fmt::Display for Comparison {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let delta = self.fresh.as_secs_f64() / self.cached.as_secs_f64() - 1.0;
+        write!(
+            f,
+            "{:.2?} against {:.2?} cached, {:+.2}%",
+            self.fresh,
+            self.cached,
+            delta * 100.0
+        )
+    }
+}
+
+/// 64-bit FNV-1a, which unlike the hasher of the standard library is the same across releases so
+/// that entries outlive the toolchain.
+fn hash(input: &str) -> u64 {
+    input.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+/// Nanoseconds between the epoch and the last modification of the running executable.
+fn build() -> Option<u64> {
+    let modified = env::current_exe()
+        .and_then(fs::metadata)
+        .and_then(|metadata| metadata.modified())
+        .ok()?;
+    Some(modified.duration_since(UNIX_EPOCH).ok()?.as_nanos() as u64)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::registry;
+
+    #[test]
+    fn test_hash() {
+        assert_eq!(hash(""), 0xcbf2_9ce4_8422_2325);
+        assert_eq!(hash("a"), 0xaf63_dc4c_8601_ec8c);
+    }
+
+    #[test]
+    fn test_cache() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = Cache::new(dir.path(), 1);
+        let rebuilt = Cache::new(dir.path(), 2);
+        let part1 = registry::find(6, 1, "default").unwrap();
+        let part2 = registry::find(6, 2, "default").unwrap();
+        let entry = Entry {
+            answer: "288".to_string(),
+            timings: Timings {
+                parse: Duration::from_nanos(1234),
+                solve: Duration::from_micros(56),
+            },
+        };
+
+        assert_eq!(cache.get(part1, "Time: 7\nDistance: 9"), None);
+        cache.insert(part1, "Time: 7\nDistance: 9", &entry).unwrap();
+
+        assert_eq!(cache.get(part1, "Time: 7\nDistance: 9"), Some(entry));
+        assert_eq!(cache.get(part1, "Time: 7\nDistance: 10"), None);
+        assert_eq!(cache.get(part2, "Time: 7\nDistance: 9"), None);
+        assert_eq!(rebuilt.get(part1, "Time: 7\nDistance: 9"), None);
+    }
+
+    #[test]
+    fn test_comparison() {
+        let comparison = Comparison {
+            fresh: Duration::from_millis(150),
+            cached: Duration::from_millis(200),
+        };
+
+        assert_eq!(
+            comparison.to_string(),
+            "150.00ms against 200.00ms cached, -25.00%"
+        );
+    }
+}
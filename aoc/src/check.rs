@@ -0,0 +1,273 @@
+use std::{
+    fmt, fs, io,
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+use aoc_common::SolutionEntry;
+use serde::Deserialize;
+
+use crate::registry;
+
+/// Expected answers read from a TOML file made of `[[answer]]` tables.
+#[derive(Debug, Deserialize, PartialEq, Eq)]
+pub struct Manifest {
+    #[serde(default, rename = "answer")]
+    pub answers: Vec<Answer>,
+}
+
+/// The answer to one part for a given input. Every variant of the part is checked against it
+/// unless `variant` narrows it down to one.
+#[derive(Debug, Deserialize, PartialEq, Eq)]
+pub struct Answer {
+    pub day: u8,
+    pub part: u8,
+    pub variant: Option<String>,
+    /// Relative paths are resolved against the directory of the manifest.
+    pub input: PathBuf,
+    pub answer: String,
+}
+
+impl Manifest {
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let manifest = fs::read_to_string(path)
+            .map_err(|err| format!("Could not read {}: {}", path.display(), err))?;
+        let mut manifest: Self = toml::from_str(&manifest)
+            .map_err(|err| format!("Could not parse {}: {}", path.display(), err))?;
+
+        let base = path.parent().unwrap_or(Path::new(""));
+        for answer in &mut manifest.answers {
+            answer.input = base.join(&answer.input);
+        }
+        Ok(manifest)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Mismatch(String),
+    Error(String),
+    MissingInput,
+    Unregistered,
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Status::Pass => write!(f, "ok"),
+            Status::Mismatch(actual) => write!(f, "MISMATCH got {}", actual),
+            Status::Error(err) => write!(f, "ERROR {}", err),
+            Status::MissingInput => write!(f, "skipped (input missing)"),
+            Status::Unregistered => write!(f, "skipped (no such solution)"),
+        }
+    }
+}
+
+pub struct Outcome<'a> {
+    pub answer: &'a Answer,
+    pub variant: String,
+    pub status: Status,
+    pub elapsed: Option<Duration>,
+}
+
+impl Outcome<'_> {
+    pub fn is_failure(&self) -> bool {
+        matches!(self.status, Status::Mismatch(_) | Status::Error(_))
+    }
+}
+
+/// Runs every solution the manifest has an answer for.
+pub fn check(manifest: &Manifest) -> Vec<Outcome<'_>> {
+    manifest
+        .answers
+        .iter()
+        .flat_map(|answer| {
+            let solutions = registry::for_day(answer.day)
+                .filter(|solution| {
+                    solution.part == answer.part
+                        && answer
+                            .variant
+                            .as_deref()
+                            .is_none_or(|variant| solution.variant == variant)
+                })
+                .collect::<Vec<_>>();
+            check_answer(answer, &solutions)
+        })
+        .collect()
+}
+
+fn check_answer<'a>(answer: &'a Answer, solutions: &[&SolutionEntry]) -> Vec<Outcome<'a>> {
+    let skipped = |status: Status| {
+        let variant = answer.variant.clone().unwrap_or_else(|| "*".to_string());
+        vec![Outcome {
+            answer,
+            variant,
+            status,
+            elapsed: None,
+        }]
+    };
+
+    if solutions.is_empty() {
+        return skipped(Status::Unregistered);
+    }
+    let input = match fs::read_to_string(&answer.input) {
+        Ok(input) => input,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return skipped(Status::MissingInput),
+        Err(err) => {
+            return skipped(Status::Error(format!(
+                "could not read {}: {}",
+                answer.input.display(),
+                err
+            )))
+        }
+    };
+
+    solutions
+        .iter()
+        .map(|solution| {
+            let start = Instant::now();
+            let result = (solution.run)(&input);
+            let elapsed = start.elapsed();
+
+            let status = match result {
+                Ok(actual) if actual == answer.answer => Status::Pass,
+                Ok(actual) => Status::Mismatch(actual),
+                Err(err) => Status::Error(err.to_string()),
+            };
+            Outcome {
+                answer,
+                variant: solution.variant.to_string(),
+                status,
+                elapsed: Some(elapsed),
+            }
+        })
+        .collect()
+}
+
+pub fn print_table(outcomes: &[Outcome]) {
+    let variant_width = outcomes
+        .iter()
+        .map(|outcome| outcome.variant.len())
+        .chain(["variant".len()])
+        .max()
+        .unwrap_or_default();
+    let expected_width = outcomes
+        .iter()
+        .map(|outcome| outcome.answer.answer.len())
+        .chain(["expected".len()])
+        .max()
+        .unwrap_or_default();
+
+    println!(
+        "day part {:variant_width$} {:>expected_width$} {:>10}  status",
+        "variant", "expected", "time"
+    );
+    for outcome in outcomes {
+        let elapsed = outcome
+            .elapsed
+            .map_or(String::new(), |elapsed| format!("{:.2?}", elapsed));
+        println!(
+            "{:02}  {:<4} {:variant_width$} {:>expected_width$} {:>10}  {}",
+            outcome.answer.day,
+            outcome.answer.part,
+            outcome.variant,
+            outcome.answer.answer,
+            elapsed,
+            outcome.status
+        );
+    }
+
+    let count =
+        |f: fn(&Status) -> bool| outcomes.iter().filter(|outcome| f(&outcome.status)).count();
+    println!(
+        "\n{} passed, {} failed, {} skipped",
+        count(|status| *status == Status::Pass),
+        count(|status| matches!(status, Status::Mismatch(_) | Status::Error(_))),
+        count(|status| matches!(status, Status::MissingInput | Status::Unregistered)),
+    );
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn answer(day: u8, part: u8, input: &str, expected: &str) -> Answer {
+        Answer {
+            day,
+            part,
+            variant: None,
+            input: PathBuf::from(input),
+            answer: expected.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_parse_manifest() {
+        let manifest: Manifest = toml::from_str(
+            r#"
+            [[answer]]
+            day = 2
+            part = 1
+            input = "day-02/src/input.txt"
+            answer = "2439"
+
+            [[answer]]
+            day = 17
+            part = 2
+            variant = "astar_with_skips"
+            input = "day-17/src/input.txt"
+            answer = "1227"
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(manifest.answers.len(), 2);
+        assert_eq!(
+            manifest.answers[0],
+            answer(2, 1, "day-02/src/input.txt", "2439")
+        );
+        assert_eq!(
+            manifest.answers[1].variant.as_deref(),
+            Some("astar_with_skips")
+        );
+    }
+
+    #[test]
+    fn test_check_every_variant() {
+        let dir = tempfile::tempdir().unwrap();
+        let input = dir.path().join("day-02.txt");
+        fs::write(
+            &input,
+            "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green",
+        )
+        .unwrap();
+        let manifest = Manifest {
+            answers: vec![
+                answer(2, 1, input.to_str().unwrap(), "8"),
+                answer(2, 2, input.to_str().unwrap(), "2287"),
+                answer(2, 1, "does/not/exist.txt", "8"),
+            ],
+        };
+
+        let statuses = check(&manifest)
+            .into_iter()
+            .map(|outcome| (outcome.variant, outcome.status))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            statuses,
+            vec![
+                ("default".to_string(), Status::Pass),
+                ("chumsky".to_string(), Status::Pass),
+                ("default".to_string(), Status::Mismatch("2286".to_string())),
+                ("chumsky".to_string(), Status::Mismatch("2286".to_string())),
+                ("*".to_string(), Status::MissingInput),
+            ]
+        );
+    }
+}
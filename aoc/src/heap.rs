@@ -0,0 +1,366 @@
+use std::{
+    cmp::Reverse,
+    collections::HashMap,
+    env, fs,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+use aoc_common::SolutionEntry;
+use serde::Deserialize;
+
+/// The parts of a `dhat-heap.json` profile that the summary needs.
+#[derive(Deserialize)]
+struct Profile {
+    mode: String,
+    cmd: String,
+    pps: Vec<ProgramPoint>,
+    ftbl: Vec<String>,
+}
+
+/// One allocation site. `t` is the total over the run, `g` what was live at the global peak and
+/// `e` what was still live at exit, in bytes (`b`) and blocks (`bk`).
+#[derive(Deserialize)]
+struct ProgramPoint {
+    tb: u64,
+    tbk: u64,
+    gb: u64,
+    gbk: u64,
+    eb: u64,
+    ebk: u64,
+    fs: Vec<usize>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Totals {
+    pub bytes: u64,
+    pub blocks: u64,
+    pub peak_bytes: u64,
+    pub peak_blocks: u64,
+    pub end_bytes: u64,
+    pub end_blocks: u64,
+}
+
+impl Totals {
+    fn add(&mut self, pp: &ProgramPoint) {
+        self.bytes += pp.tb;
+        self.blocks += pp.tbk;
+        self.peak_bytes += pp.gb;
+        self.peak_blocks += pp.gbk;
+        self.end_bytes += pp.eb;
+        self.end_blocks += pp.ebk;
+    }
+
+    fn rows(&self) -> [(&'static str, u64); 6] {
+        [
+            ("total bytes", self.bytes),
+            ("total blocks", self.blocks),
+            ("peak bytes", self.peak_bytes),
+            ("peak blocks", self.peak_blocks),
+            ("end bytes", self.end_bytes),
+            ("end blocks", self.end_blocks),
+        ]
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Site {
+    /// Innermost frame of our own code, e.g. `day_03::part2::part2 (src/part2.rs:105:10)`.
+    pub name: String,
+    pub totals: Totals,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Summary {
+    pub totals: Totals,
+    /// Sorted by total bytes, largest first.
+    pub sites: Vec<Site>,
+}
+
+impl Summary {
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let json = fs::read_to_string(path)
+            .map_err(|err| format!("Could not read {}: {}", path.display(), err))?;
+        Self::from_json(&json).map_err(|err| format!("Could not parse {}: {}", path.display(), err))
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        let profile: Profile = serde_json::from_str(json).map_err(|err| err.to_string())?;
+        if profile.mode != "rust-heap" {
+            return Err(format!(
+                "expected a heap profile, got mode {:?}",
+                profile.mode
+            ));
+        }
+        let binary = Path::new(&profile.cmd)
+            .file_name()
+            .and_then(|name| name.to_str())
+            .unwrap_or_default();
+
+        let mut totals = Totals::default();
+        let mut sites = HashMap::<String, Totals>::new();
+        for pp in &profile.pps {
+            totals.add(pp);
+            let frames = pp
+                .fs
+                .iter()
+                .filter_map(|&idx| profile.ftbl.get(idx).map(String::as_str));
+            sites.entry(site_name(frames, binary)).or_default().add(pp);
+        }
+
+        let mut sites = sites
+            .into_iter()
+            .map(|(name, totals)| Site { name, totals })
+            .collect::<Vec<_>>();
+        sites.sort_by(|a, b| {
+            b.totals
+                .bytes
+                .cmp(&a.totals.bytes)
+                .then_with(|| a.name.cmp(&b.name))
+        });
+        Ok(Self { totals, sites })
+    }
+}
+
+/// Picks the innermost frame from the binary or a workspace crate, since the frames above it are
+/// all allocator and collection internals. Falls back to the innermost frame outside of the
+/// standard library, and then to the innermost frame at all.
+fn site_name<'a>(frames: impl Iterator<Item = &'a str> + Clone, binary: &str) -> String {
+    let frames = frames.map(|frame| {
+        // Frames look like `0x563d92555818: part2::part2 (src/bin/part2.rs:105:10)`.
+        frame.split_once(": ").map_or(frame, |(_, frame)| frame)
+    });
+    let crate_name = |frame: &str| {
+        let path = frame.trim_start_matches('<');
+        path.split_once("::").map(|(name, _)| name.to_string())
+    };
+    let is_ours = |frame: &&str| {
+        crate_name(frame).is_some_and(|name| {
+            name == binary || name.starts_with("day_") || name.starts_with("aoc_")
+        })
+    };
+    let is_std = |frame: &&str| {
+        frame.starts_with('[')
+            || crate_name(frame).is_none_or(|name| {
+                name.contains(' ') || ["alloc", "core", "std", "hashbrown"].contains(&&*name)
+            })
+    };
+
+    frames
+        .clone()
+        .find(is_ours)
+        .or_else(|| frames.clone().find(|frame| !is_std(frame)))
+        .or_else(|| frames.clone().find(|frame| !frame.starts_with('[')))
+        .unwrap_or("[root]")
+        .to_string()
+}
+
+pub fn print_summary(summary: &Summary, top: usize) {
+    for (label, value) in summary.totals.rows() {
+        println!("{:12} {:>12}", label, value);
+    }
+
+    println!(
+        "\n{:>12} {:>8} {:>12}  site",
+        "bytes", "blocks", "peak bytes"
+    );
+    for site in summary.sites.iter().take(top) {
+        println!(
+            "{:>12} {:>8} {:>12}  {}",
+            site.totals.bytes, site.totals.blocks, site.totals.peak_bytes, site.name
+        );
+    }
+    if summary.sites.len() > top {
+        println!("... {} more sites", summary.sites.len() - top);
+    }
+}
+
+fn format_delta(before: u64, after: u64) -> String {
+    let delta = after as i128 - before as i128;
+    if before == 0 {
+        format!("{:+}", delta)
+    } else {
+        format!(
+            "{:+} ({:+.1}%)",
+            delta,
+            delta as f64 * 100.0 / before as f64
+        )
+    }
+}
+
+/// Totals side by side, then the sites that allocate the most in either profile. Sites are matched
+/// by name, so the same function in both profiles lines up even when the line numbers moved.
+pub fn print_diff(before: &Summary, after: &Summary, top: usize) {
+    println!("{:12} {:>12} {:>12}  delta", "", "before", "after");
+    let rows = before.totals.rows().into_iter().zip(after.totals.rows());
+    for ((label, before), (_, after)) in rows {
+        println!(
+            "{:12} {:>12} {:>12}  {}",
+            label,
+            before,
+            after,
+            format_delta(before, after)
+        );
+    }
+
+    let strip_location = |name: &str| {
+        name.split_once(" (")
+            .map_or(name, |(name, _)| name)
+            .to_string()
+    };
+    let mut sites = HashMap::<String, (u64, u64)>::new();
+    for site in &before.sites {
+        sites.entry(strip_location(&site.name)).or_default().0 += site.totals.bytes;
+    }
+    for site in &after.sites {
+        sites.entry(strip_location(&site.name)).or_default().1 += site.totals.bytes;
+    }
+    let mut sites = sites.into_iter().collect::<Vec<_>>();
+    sites.sort_by_key(|(name, (before, after))| (Reverse(*before.max(after)), name.clone()));
+
+    println!("\n{:>12} {:>12}  {:20}  site", "before", "after", "delta");
+    for (name, (before, after)) in sites.iter().take(top) {
+        println!(
+            "{:>12} {:>12}  {:20}  {}",
+            before,
+            after,
+            format_delta(*before, *after),
+            name
+        );
+    }
+    if sites.len() > top {
+        println!("... {} more sites", sites.len() - top);
+    }
+}
+
+/// Where the profile of a solution binary is kept, e.g. `day-02/dhat-results/part1-dhat-heap.json`.
+pub fn results_path(solution: &SolutionEntry) -> PathBuf {
+    PathBuf::from(format!("day-{:02}", solution.day))
+        .join("dhat-results")
+        .join(format!("{}-dhat-heap.json", solution.name()))
+}
+
+/// Runs the binary of a solution with the `dhat-heap` feature and moves the profile it writes into
+/// the day's results directory. Has to be run from the workspace root.
+pub fn record(solution: &SolutionEntry) -> Result<PathBuf, String> {
+    // dhat writes the profile to the working directory, so each run gets a fresh one to avoid
+    // picking up a stale profile.
+    let run_dir = env::temp_dir().join(format!("aoc-dhat-{}", std::process::id()));
+    fs::create_dir_all(&run_dir)
+        .map_err(|err| format!("Could not create {}: {}", run_dir.display(), err))?;
+    let manifest = env::current_dir()
+        .map_err(|err| err.to_string())?
+        .join("Cargo.toml");
+
+    let status = Command::new(env::var("CARGO").unwrap_or_else(|_| "cargo".to_string()))
+        .args([
+            "run",
+            "--quiet",
+            "--profile",
+            "dhat",
+            "--features",
+            "dhat-heap",
+        ])
+        .arg("--manifest-path")
+        .arg(&manifest)
+        .args(["--package", &format!("day-{:02}", solution.day)])
+        .args(["--bin", &solution.name()])
+        .current_dir(&run_dir)
+        .status()
+        .map_err(|err| format!("Could not run cargo: {}", err))?;
+    if !status.success() {
+        return Err(format!("cargo run exited with {}", status));
+    }
+
+    let path = results_path(solution);
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)
+            .map_err(|err| format!("Could not create {}: {}", dir.display(), err))?;
+    }
+    fs::copy(run_dir.join("dhat-heap.json"), &path)
+        .map_err(|err| format!("Could not write {}: {}", path.display(), err))?;
+    let _ = fs::remove_dir_all(&run_dir);
+    Ok(path)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rstest::rstest;
+
+    const PROFILE: &str = r#"{
+        "dhatFileVersion": 2, "mode": "rust-heap", "cmd": "target/dhat/part2", "tg": 5, "te": 9,
+        "pps": [
+            {"tb": 100, "tbk": 2, "tl": 1, "mb": 100, "mbk": 2, "gb": 100, "gbk": 2, "eb": 0, "ebk": 0,
+             "fs": [1, 2, 3]},
+            {"tb": 300, "tbk": 3, "tl": 1, "mb": 200, "mbk": 1, "gb": 0, "gbk": 0, "eb": 50, "ebk": 1,
+             "fs": [1, 4]},
+            {"tb": 20, "tbk": 1, "tl": 1, "mb": 20, "mbk": 1, "gb": 20, "gbk": 1, "eb": 0, "ebk": 0,
+             "fs": [1, 2, 3]}
+        ],
+        "ftbl": [
+            "[root]",
+            "0x1: <alloc::alloc::Global as core::alloc::Allocator>::allocate (alloc/src/alloc.rs:241:9)",
+            "0x2: part2::get_gear_ratio (src/bin/part2.rs:41:24)",
+            "0x2: part2::main (src/bin/part2.rs:14:20)",
+            "0x3: day_03::part2::part2 (src/part2.rs:105:10)"
+        ]
+    }"#;
+
+    #[test]
+    fn test_summary() {
+        let summary = Summary::from_json(PROFILE).unwrap();
+
+        assert_eq!(
+            summary.totals,
+            Totals {
+                bytes: 420,
+                blocks: 6,
+                peak_bytes: 120,
+                peak_blocks: 3,
+                end_bytes: 50,
+                end_blocks: 1,
+            }
+        );
+        assert_eq!(
+            summary
+                .sites
+                .iter()
+                .map(|site| (site.name.as_str(), site.totals.bytes))
+                .collect::<Vec<_>>(),
+            vec![
+                ("day_03::part2::part2 (src/part2.rs:105:10)", 300),
+                ("part2::get_gear_ratio (src/bin/part2.rs:41:24)", 120),
+            ]
+        );
+    }
+
+    #[rstest]
+    #[case(&["[root]"], "[root]")]
+    #[case(
+        &[
+            "0x1: <alloc::alloc::Global as core::alloc::Allocator>::allocate (alloc/src/alloc.rs:241:9)",
+            "0x1: <u64 as core::iter::traits::accum::Sum>::sum (iter/traits/accum.rs:149:1)",
+            "0x1: chumsky::primitive::just (src/primitive.rs:10:1)",
+            "0x1: day_02::part1_chumsky::parse (src/part1_chumsky.rs:20:5)",
+        ],
+        "day_02::part1_chumsky::parse (src/part1_chumsky.rs:20:5)"
+    )]
+    #[case(
+        &[
+            "0x1: alloc::raw_vec::RawVec<T,A>::allocate_in (alloc/src/raw_vec.rs:184:45)",
+            "0x1: chumsky::primitive::just (src/primitive.rs:10:1)",
+        ],
+        "chumsky::primitive::just (src/primitive.rs:10:1)"
+    )]
+    fn test_site_name(#[case] frames: &[&str], #[case] expected: &str) {
+        assert_eq!(site_name(frames.iter().copied(), "part1_chumsky"), expected);
+    }
+
+    #[test]
+    fn test_rejects_other_modes() {
+        let profile = PROFILE.replace("rust-heap", "rust-ad-hoc");
+
+        assert!(Summary::from_json(&profile).is_err());
+    }
+}
@@ -0,0 +1,429 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::ExitCode,
+    time::Duration,
+};
+
+use aoc_common::{progress, Cancelled, InputSource, Progress, SolutionEntry, DEFAULT_VARIANT};
+use clap::{Parser, Subcommand};
+
+mod batch;
+mod bench;
+mod cache;
+mod check;
+mod heap;
+mod registry;
+
+#[derive(Parser)]
+#[command(about = "Runs Advent of Code 2023 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Lists the registered days, parts and variants
+    List { day: Option<u8> },
+    /// Runs a solution against the given input, `AOC_INPUT` or the embedded input
+    Run {
+        day: u8,
+        /// Runs every part of the day when omitted
+        part: Option<u8>,
+        #[arg(short, long, default_value = DEFAULT_VARIANT)]
+        variant: String,
+        /// Path to the puzzle input, or `-` to read it from stdin
+        #[arg(short, long)]
+        input: Option<String>,
+        /// Also prints how long parsing and solving took
+        #[arg(short, long)]
+        time: bool,
+        /// Gives up on a solution after this many seconds, reporting how far it got
+        #[arg(long, value_parser = progress::parse_timeout)]
+        timeout: Option<Duration>,
+        /// Runs the solution even if `AOC_CACHE` has its answer, comparing the runtime with the
+        /// cached one
+        #[arg(long)]
+        no_cache: bool,
+    },
+    /// Checks every solution and variant against the answers in a manifest
+    Check {
+        /// Only checks the answers for this day
+        day: Option<u8>,
+        #[arg(short, long, default_value = "answers.toml")]
+        manifest: PathBuf,
+    },
+    /// Runs every part on a directory of inputs in parallel and writes a JSON report
+    Batch {
+        /// Directory with a `dayNN` directory of `*.txt` inputs per day
+        dir: PathBuf,
+        #[arg(short, long, default_value = DEFAULT_VARIANT)]
+        variant: String,
+        /// Where to write the report, stdout when omitted
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+        /// Gives up on each run after this many seconds, counting it as failed
+        #[arg(long, value_parser = progress::parse_timeout)]
+        timeout: Option<Duration>,
+        /// Runs every solution even if `AOC_CACHE` has its answer
+        #[arg(long)]
+        no_cache: bool,
+    },
+    /// Prints a random puzzle input for a day, see the day's `generate` module for what `size`
+    /// means
+    Generate {
+        day: u8,
+        size: usize,
+        #[arg(short, long, default_value_t = 0)]
+        seed: u64,
+    },
+    /// Summarizes the criterion results of every day in one table
+    BenchReport {
+        /// Criterion run saved with `--save-baseline` to compare against, `base` being the run
+        /// before the latest one
+        #[arg(short, long)]
+        baseline: Option<String>,
+        #[arg(short, long, value_enum, default_value_t = bench::Format::Markdown)]
+        format: bench::Format,
+        #[arg(long, default_value = "target/criterion")]
+        criterion_dir: PathBuf,
+    },
+    /// Reads the heap profiles written by the `dhat-heap` feature
+    Dhat {
+        #[command(subcommand)]
+        command: DhatCommand,
+    },
+}
+
+#[derive(Subcommand)]
+enum DhatCommand {
+    /// Prints the totals and the sites that allocate the most
+    Summary {
+        profile: PathBuf,
+        /// Number of allocation sites to show
+        #[arg(short, long, default_value_t = 10)]
+        top: usize,
+    },
+    /// Compares two profiles, e.g. two variants of the same part
+    Diff {
+        before: PathBuf,
+        after: PathBuf,
+        #[arg(short, long, default_value_t = 10)]
+        top: usize,
+    },
+    /// Profiles solutions under dhat and refreshes the day's `dhat-results` directory
+    Record {
+        day: u8,
+        /// Records every part of the day when omitted
+        part: Option<u8>,
+        /// Records every variant when omitted
+        #[arg(short, long)]
+        variant: Option<String>,
+    },
+}
+
+fn main() -> ExitCode {
+    #[cfg(feature = "trace")]
+    aoc_common::trace::init();
+
+    let cli = Cli::parse();
+
+    match cli.command {
+        Command::List { day } => {
+            list(day);
+            ExitCode::SUCCESS
+        }
+        Command::Run {
+            day,
+            part,
+            variant,
+            input,
+            time,
+            timeout,
+            no_cache,
+        } => run(
+            day,
+            part,
+            &variant,
+            input.as_deref(),
+            time,
+            timeout,
+            no_cache,
+        ),
+        Command::Check { day, manifest } => check(day, &manifest),
+        Command::Batch {
+            dir,
+            variant,
+            output,
+            timeout,
+            no_cache,
+        } => batch(&dir, &variant, output.as_deref(), timeout, no_cache),
+        Command::Generate { day, size, seed } => generate(day, size, seed),
+        Command::BenchReport {
+            baseline,
+            format,
+            criterion_dir,
+        } => bench_report(&criterion_dir, baseline.as_deref(), format),
+        Command::Dhat { command } => dhat(command),
+    }
+}
+
+fn list(day: Option<u8>) {
+    for solution in
+        registry::solutions().filter(|solution| day.is_none_or(|day| solution.day == day))
+    {
+        println!(
+            "day {:02} part {} {}",
+            solution.day, solution.part, solution.variant
+        );
+    }
+}
+
+fn run(
+    day: u8,
+    part: Option<u8>,
+    variant: &str,
+    input: Option<&str>,
+    time: bool,
+    timeout: Option<Duration>,
+    no_cache: bool,
+) -> ExitCode {
+    let solutions: Vec<&SolutionEntry> = match part {
+        Some(part) => registry::find(day, part, variant).into_iter().collect(),
+        None => registry::for_day(day)
+            .filter(|solution| solution.variant == variant)
+            .collect(),
+    };
+
+    if solutions.is_empty() {
+        eprintln!(
+            "No solution registered for day {} part {} variant {}",
+            day,
+            part.map_or("*".to_string(), |part| part.to_string()),
+            variant
+        );
+        eprintln!("Run `aoc list {}` to see what is available", day);
+        return ExitCode::FAILURE;
+    }
+
+    let source = input.map_or_else(InputSource::from_env, InputSource::from_arg);
+    let input = match source.read(registry::input(day)) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("{}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    // Without a cache directory nothing is looked up nor recorded, while `--no-cache` only skips
+    // the lookup so that the fresh run can be compared with the cached one.
+    let cache = cache::Cache::from_env();
+    let mut exit_code = ExitCode::SUCCESS;
+    for solution in solutions {
+        let label = format!(
+            "day {:02} part {} ({})",
+            solution.day, solution.part, solution.variant
+        );
+        let cached = cache.as_ref().and_then(|cache| cache.get(solution, &input));
+        match &cached {
+            Some(entry) if !no_cache && time => {
+                println!("{}: {} (cached, {})", label, entry.answer, entry.timings);
+                continue;
+            }
+            Some(entry) if !no_cache => {
+                println!("{}: {} (cached)", label, entry.answer);
+                continue;
+            }
+            _ => {}
+        }
+
+        let progress = match timeout {
+            Some(timeout) => Progress::stderr().with_timeout(timeout),
+            None => Progress::stderr(),
+        };
+        let result = (solution.run_with)(&input, &progress);
+        let status = progress.finish();
+
+        match result {
+            Ok((output, timings)) => {
+                let mut details = vec![];
+                if time {
+                    details.push(timings.to_string());
+                }
+                if let Some(entry) = &cached {
+                    details.push(entry.compare(&timings).to_string());
+                    if entry.answer != output {
+                        eprintln!("{}: cached answer was {}", label, entry.answer);
+                    }
+                }
+                if details.is_empty() {
+                    println!("{}: {}", label, output);
+                } else {
+                    println!("{}: {} ({})", label, output, details.join("; "));
+                }
+
+                if let Some(cache) = &cache {
+                    let entry = cache::Entry {
+                        answer: output,
+                        timings,
+                    };
+                    if let Err(err) = cache.insert(solution, &input, &entry) {
+                        eprintln!("{}", err);
+                    }
+                }
+            }
+            Err(err) if Cancelled::caused(err.as_ref()) => {
+                eprintln!("{}: {}", label, status);
+                exit_code = ExitCode::FAILURE;
+            }
+            Err(err) => {
+                eprintln!("{}: error: {:#}", label, err);
+                exit_code = ExitCode::FAILURE;
+            }
+        }
+    }
+
+    exit_code
+}
+
+fn check(day: Option<u8>, manifest: &Path) -> ExitCode {
+    let mut manifest = match check::Manifest::load(manifest) {
+        Ok(manifest) => manifest,
+        Err(err) => {
+            eprintln!("{}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+    manifest
+        .answers
+        .retain(|answer| day.is_none_or(|day| answer.day == day));
+
+    let outcomes = check::check(&manifest);
+    check::print_table(&outcomes);
+
+    if outcomes.iter().any(check::Outcome::is_failure) {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn batch(
+    dir: &Path,
+    variant: &str,
+    output: Option<&Path>,
+    timeout: Option<Duration>,
+    no_cache: bool,
+) -> ExitCode {
+    let inputs = match batch::find_inputs(dir) {
+        Ok(inputs) => inputs,
+        Err(err) => {
+            eprintln!("{}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let cache = cache::Cache::from_env();
+    let report = batch::run(&inputs, variant, timeout, cache.as_ref(), no_cache);
+    let json = serde_json::to_string_pretty(&report).expect("report is serializable");
+    match output {
+        Some(path) => {
+            if let Err(err) = fs::write(path, json) {
+                eprintln!("Could not write {}: {}", path.display(), err);
+                return ExitCode::FAILURE;
+            }
+        }
+        None => println!("{}", json),
+    }
+
+    let failed = report.runs.iter().filter(|run| run.is_failure()).count();
+    eprintln!(
+        "{} inputs, {} runs, {} failed",
+        inputs.len(),
+        report.runs.len(),
+        failed
+    );
+    if failed > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn generate(day: u8, size: usize, seed: u64) -> ExitCode {
+    match registry::generator(day) {
+        Some(generate) => {
+            println!("{}", generate(size, seed));
+            ExitCode::SUCCESS
+        }
+        None => {
+            eprintln!("No generator registered for day {}", day);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn bench_report(criterion_dir: &Path, baseline: Option<&str>, format: bench::Format) -> ExitCode {
+    let rows = match bench::collect(criterion_dir, baseline) {
+        Ok(rows) => rows,
+        Err(err) => {
+            eprintln!("{}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+    if rows.is_empty() {
+        eprintln!(
+            "No day benchmarks found under {}, run `cargo bench` first",
+            criterion_dir.display()
+        );
+        return ExitCode::FAILURE;
+    }
+
+    match format {
+        bench::Format::Markdown => print!("{}", bench::markdown(&rows)),
+        bench::Format::Csv => print!("{}", bench::csv(&rows)),
+    }
+    ExitCode::SUCCESS
+}
+
+fn dhat(command: DhatCommand) -> ExitCode {
+    let result = match command {
+        DhatCommand::Summary { profile, top } => {
+            heap::Summary::load(&profile).map(|summary| heap::print_summary(&summary, top))
+        }
+        DhatCommand::Diff { before, after, top } => heap::Summary::load(&before)
+            .and_then(|before| Ok((before, heap::Summary::load(&after)?)))
+            .map(|(before, after)| heap::print_diff(&before, &after, top)),
+        DhatCommand::Record { day, part, variant } => record(day, part, variant.as_deref()),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("{}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn record(day: u8, part: Option<u8>, variant: Option<&str>) -> Result<(), String> {
+    let solutions = registry::for_day(day)
+        .filter(|solution| part.is_none_or(|part| solution.part == part))
+        .filter(|solution| variant.is_none_or(|variant| solution.variant == variant))
+        .collect::<Vec<_>>();
+    if solutions.is_empty() {
+        return Err(format!(
+            "No solution registered for day {} part {} variant {}",
+            day,
+            part.map_or("*".to_string(), |part| part.to_string()),
+            variant.unwrap_or("*")
+        ));
+    }
+
+    for solution in solutions {
+        let path = heap::record(solution)?;
+        println!("wrote {}", path.display());
+    }
+    Ok(())
+}
@@ -0,0 +1,106 @@
+use aoc_common::SolutionEntry;
+
+static DAYS: &[&[SolutionEntry]] = &[
+    day_01::SOLUTIONS,
+    day_02::SOLUTIONS,
+    day_03::SOLUTIONS,
+    day_04::SOLUTIONS,
+    day_05::SOLUTIONS,
+    day_06::SOLUTIONS,
+    day_07::SOLUTIONS,
+    day_08::SOLUTIONS,
+    day_09::SOLUTIONS,
+    day_10::SOLUTIONS,
+    day_11::SOLUTIONS,
+    day_12::SOLUTIONS,
+    day_13::SOLUTIONS,
+    day_14::SOLUTIONS,
+    day_15::SOLUTIONS,
+    day_16::SOLUTIONS,
+    day_17::SOLUTIONS,
+    day_18::SOLUTIONS,
+    day_19::SOLUTIONS,
+    day_20::SOLUTIONS,
+    day_21::SOLUTIONS,
+    day_22::SOLUTIONS,
+    day_23::SOLUTIONS,
+    day_24::SOLUTIONS,
+    day_25::SOLUTIONS,
+];
+
+pub fn solutions() -> impl Iterator<Item = &'static SolutionEntry> {
+    DAYS.iter().flat_map(|day| day.iter())
+}
+
+pub fn find(day: u8, part: u8, variant: &str) -> Option<&'static SolutionEntry> {
+    solutions().find(|solution| {
+        solution.day == day && solution.part == part && solution.variant == variant
+    })
+}
+
+pub fn for_day(day: u8) -> impl Iterator<Item = &'static SolutionEntry> {
+    solutions().filter(move |solution| solution.day == day)
+}
+
+pub fn input(day: u8) -> &'static str {
+    match day {
+        1 => include_str!("../../day-01/src/input.txt"),
+        2 => include_str!("../../day-02/src/input.txt"),
+        3 => include_str!("../../day-03/src/input.txt"),
+        4 => include_str!("../../day-04/src/input.txt"),
+        5 => include_str!("../../day-05/src/input.txt"),
+        6 => include_str!("../../day-06/src/input.txt"),
+        7 => include_str!("../../day-07/src/input.txt"),
+        8 => include_str!("../../day-08/src/input.txt"),
+        9 => include_str!("../../day-09/src/input.txt"),
+        10 => include_str!("../../day-10/src/input.txt"),
+        11 => include_str!("../../day-11/src/input.txt"),
+        12 => include_str!("../../day-12/src/input.txt"),
+        13 => include_str!("../../day-13/src/input.txt"),
+        14 => include_str!("../../day-14/src/input.txt"),
+        15 => include_str!("../../day-15/src/input.txt"),
+        16 => include_str!("../../day-16/src/input.txt"),
+        17 => include_str!("../../day-17/src/input.txt"),
+        18 => include_str!("../../day-18/src/input.txt"),
+        19 => include_str!("../../day-19/src/input.txt"),
+        20 => include_str!("../../day-20/src/input.txt"),
+        21 => include_str!("../../day-21/src/input.txt"),
+        22 => include_str!("../../day-22/src/input.txt"),
+        23 => include_str!("../../day-23/src/input.txt"),
+        24 => include_str!("../../day-24/src/input.txt"),
+        25 => include_str!("../../day-25/src/input.txt"),
+        day => unreachable!("No input embedded for day {}", day),
+    }
+}
+
+pub fn generator(day: u8) -> Option<fn(usize, u64) -> String> {
+    let generate: fn(usize, u64) -> String = match day {
+        1 => day_01::generate::generate,
+        2 => day_02::generate::generate,
+        3 => day_03::generate::generate,
+        4 => day_04::generate::generate,
+        5 => day_05::generate::generate,
+        6 => day_06::generate::generate,
+        7 => day_07::generate::generate,
+        8 => day_08::generate::generate,
+        9 => day_09::generate::generate,
+        10 => day_10::generate::generate,
+        11 => day_11::generate::generate,
+        12 => day_12::generate::generate,
+        13 => day_13::generate::generate,
+        14 => day_14::generate::generate,
+        15 => day_15::generate::generate,
+        16 => day_16::generate::generate,
+        17 => day_17::generate::generate,
+        18 => day_18::generate::generate,
+        19 => day_19::generate::generate,
+        20 => day_20::generate::generate,
+        21 => day_21::generate::generate,
+        22 => day_22::generate::generate,
+        23 => day_23::generate::generate,
+        24 => day_24::generate::generate,
+        25 => day_25::generate::generate,
+        _ => return None,
+    };
+    Some(generate)
+}
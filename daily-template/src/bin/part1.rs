@@ -1,13 +1,15 @@
-use {{crate_name}}::part1::part1;
+use aoc_common::{input, print_solution, InputSource};
+use {{crate_name}}::part1::Part1;
+use std::process::ExitCode;
 
 #[cfg(feature = "dhat-heap")]
 #[global_allocator]
 static ALLOC: dhat::Alloc = dhat::Alloc;
 
-fn main() {
+fn main() -> ExitCode {
     #[cfg(feature = "dhat-heap")]
     let _profiler = dhat::Profiler::new_heap();
 
-    let input = include_str!("../input.txt");
-    println!("{}", part1(input));
+    let input = input::load(InputSource::from_args(), include_str!("../input.txt"));
+    print_solution::<Part1>(&input)
 }
@@ -0,0 +1,15 @@
+use aoc_common::variant_main;
+use {{crate_name}}::SOLUTIONS;
+use std::process::ExitCode;
+
+#[cfg(feature = "dhat-heap")]
+#[global_allocator]
+static ALLOC: dhat::Alloc = dhat::Alloc;
+
+/// `cargo run --bin variant -- <part> <variant> [input]`
+fn main() -> ExitCode {
+    #[cfg(feature = "dhat-heap")]
+    let _profiler = dhat::Profiler::new_heap();
+
+    variant_main(SOLUTIONS, include_str!("../input.txt"))
+}
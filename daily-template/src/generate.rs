@@ -0,0 +1,29 @@
+use aoc_generate::{rand::Rng, rng};
+
+/// `size` lines of puzzle input.
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = rng(seed);
+
+    (0..size)
+        .map(|_| rng.gen_range(0..100).to_string())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::SOLUTIONS;
+    use aoc_common::testing;
+
+    // The answers to the smallest input go in the first case once they are worked out by hand,
+    // the larger ones only check that the variants agree.
+    #[test]
+    fn test_generated_input_is_solved() {
+        testing::check_generated(
+            SOLUTIONS,
+            generate,
+            &[(1, 0, &[]), (100, 1, &[]), (1000, 2, &[])],
+        );
+    }
+}
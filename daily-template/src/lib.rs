@@ -1,2 +1,8 @@
+pub mod generate;
+pub mod parse;
 pub mod part1;
 pub mod part2;
+
+// Every solution registered here, e.g. a faster `part2_fast::Part2`, can be run with the `variant`
+// bin and is covered by the benches, the example tests and the differential tests.
+aoc_common::solutions!({{project-name | remove: "day-" | plus: 0}} => part1::Part1, part2::Part2);
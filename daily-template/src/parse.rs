@@ -0,0 +1,35 @@
+use std::{
+    fmt::{self, Display},
+    num::ParseIntError,
+};
+
+pub type ParseError = aoc_common::ParseError<ParseErrorKind>;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    InvalidNumber(ParseIntError),
+}
+
+impl Display for ParseErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseErrorKind::InvalidNumber(err) => write!(f, "invalid number ({})", err),
+        }
+    }
+}
+
+/// The puzzle input as both parts see it, parsed once.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Puzzle {
+    pub numbers: Vec<u64>,
+}
+
+/// One number per line.
+pub fn parse_puzzle(input: &str) -> Result<Puzzle, ParseError> {
+    let numbers = input
+        .lines()
+        .map(|line| ParseError::try_parse(input, line, ParseErrorKind::InvalidNumber))
+        .collect::<Result<_, _>>()?;
+
+    Ok(Puzzle { numbers })
+}
@@ -1,15 +1,24 @@
-pub fn part1(input: &str) -> &str {
-    unimplemented!()
-}
+use crate::parse::{self, ParseError, Puzzle};
+use aoc_common::Solution;
+
+pub struct Part1;
+
+impl Solution for Part1 {
+    const PART: u8 = 1;
 
-#[cfg(test)]
-mod test {
-    use super::*;
+    type Model<'a> = Puzzle;
+    type Answer = u64;
+    type Error = ParseError;
 
-    #[test]
-    fn test_part1() {
-        let input = "";
+    fn parse(input: &str) -> Result<Self::Model<'_>, Self::Error> {
+        parse::parse_puzzle(input)
+    }
 
-        assert_eq!(part1(input), "");
+    fn solve(puzzle: Self::Model<'_>) -> Result<Self::Answer, Self::Error> {
+        unimplemented!()
     }
 }
+
+pub fn part1(input: &str) -> Result<u64, ParseError> {
+    Part1::run(input)
+}
@@ -1,3 +1,24 @@
-pub fn part2(input: &str) -> &str {
-    unimplemented!()
+use crate::parse::{self, ParseError, Puzzle};
+use aoc_common::Solution;
+
+pub struct Part2;
+
+impl Solution for Part2 {
+    const PART: u8 = 2;
+
+    type Model<'a> = Puzzle;
+    type Answer = u64;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Model<'_>, Self::Error> {
+        parse::parse_puzzle(input)
+    }
+
+    fn solve(puzzle: Self::Model<'_>) -> Result<Self::Answer, Self::Error> {
+        unimplemented!()
+    }
+}
+
+pub fn part2(input: &str) -> Result<u64, ParseError> {
+    Part2::run(input)
 }
@@ -0,0 +1,13 @@
+//! Runs every registered solution on the inputs in `examples`, see
+//! [`aoc_common::testing::load_examples`] for how their answers are laid out.
+
+use aoc_common::testing;
+use std::path::Path;
+use {{crate_name}}::SOLUTIONS;
+
+#[test]
+fn test_examples() {
+    let examples = testing::load_examples(&Path::new(env!("CARGO_MANIFEST_DIR")).join("examples"));
+
+    testing::check_examples(SOLUTIONS, &examples);
+}
@@ -1,27 +1,45 @@
-use criterion::{criterion_group, criterion_main, Criterion};
-use day_01::*;
+use aoc_common::{bench, input, InputSource};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use day_01::{generate, SOLUTIONS};
 
-fn criterion_benchmark_part1(c: &mut Criterion) {
-    let input = include_str!("../src/input.txt");
+/// Sizes passed to [`generate::generate`], see there for what they mean for this day.
+const SIZES: [usize; 3] = [100, 1000, 10000];
 
-    let mut group = c.benchmark_group("day_01::part1");
-    group.bench_with_input("part1", input, |b, input| b.iter(|| part1::part1(input)));
+fn benchmark(c: &mut Criterion) {
+    let input = input::load(InputSource::from_env(), include_str!("../src/input.txt"));
 
-    group.finish();
+    bench::run(c, SOLUTIONS, &input);
+    bench::phases(c, SOLUTIONS, &input);
 }
 
-fn criterion_benchmark_part2(c: &mut Criterion) {
-    let input = include_str!("../src/input.txt");
-
-    let mut group = c.benchmark_group("day_01::part2");
-    group.bench_with_input("part2", input, |b, input| b.iter(|| part2::part2(input)));
+/// Sizes of the multi-megabyte calibration logs the part 1 variants are compared on.
+const LOG_SIZES: [usize; 2] = [100_000, 1_000_000];
 
+fn calibration_log_benchmark(c: &mut Criterion) {
+    let mut group = c.benchmark_group("day_01::calibration_log");
+    group.sample_size(10);
+    for size in LOG_SIZES {
+        let input = generate::generate(size, 0);
+        group.throughput(Throughput::Bytes(input.len() as u64));
+        for solution in SOLUTIONS.iter().filter(|solution| solution.part == 1) {
+            group.bench_with_input(
+                BenchmarkId::new(solution.variant, size),
+                &*input,
+                |b, input| b.iter(|| (solution.run)(input)),
+            );
+        }
+    }
     group.finish();
 }
 
+fn generated_benchmark(c: &mut Criterion) {
+    bench::generated(c, SOLUTIONS, &SIZES, generate::generate);
+}
+
 criterion_group!(
     benches,
-    criterion_benchmark_part1,
-    criterion_benchmark_part2
+    benchmark,
+    calibration_log_benchmark,
+    generated_benchmark
 );
 criterion_main!(benches);
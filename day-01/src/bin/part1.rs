@@ -1,13 +1,19 @@
-use day_01::part1::part1;
+use aoc_common::{input, print_solution};
+use day_01::{calibration, dictionary::Dictionary, part1::Part1};
+use std::process::ExitCode;
 
 #[cfg(feature = "dhat-heap")]
 #[global_allocator]
 static ALLOC: dhat::Alloc = dhat::Alloc;
 
-fn main() {
+fn main() -> ExitCode {
     #[cfg(feature = "dhat-heap")]
     let _profiler = dhat::Profiler::new_heap();
 
-    let input = include_str!("../input.txt");
-    println!("{}", part1(input));
+    let (annotate, source) = calibration::args();
+    let input = input::load(source, include_str!("../input.txt"));
+    if annotate {
+        print!("{}", calibration::annotate(&input, &Dictionary::digits()));
+    }
+    print_solution::<Part1>(&input)
 }
@@ -0,0 +1,15 @@
+use aoc_common::{input, print_solution, InputSource};
+use day_01::part1_simd::Part1;
+use std::process::ExitCode;
+
+#[cfg(feature = "dhat-heap")]
+#[global_allocator]
+static ALLOC: dhat::Alloc = dhat::Alloc;
+
+fn main() -> ExitCode {
+    #[cfg(feature = "dhat-heap")]
+    let _profiler = dhat::Profiler::new_heap();
+
+    let input = input::load(InputSource::from_args(), include_str!("../input.txt"));
+    print_solution::<Part1>(&input)
+}
@@ -1,13 +1,19 @@
-use day_01::part2::part2;
+use aoc_common::{input, print_solution};
+use day_01::{calibration, dictionary::Dictionary, part2::Part2};
+use std::process::ExitCode;
 
 #[cfg(feature = "dhat-heap")]
 #[global_allocator]
 static ALLOC: dhat::Alloc = dhat::Alloc;
 
-fn main() {
+fn main() -> ExitCode {
     #[cfg(feature = "dhat-heap")]
     let _profiler = dhat::Profiler::new_heap();
 
-    let input = include_str!("../input.txt");
-    println!("{}", part2(input));
+    let (annotate, source) = calibration::args();
+    let input = input::load(source, include_str!("../input.txt"));
+    if annotate {
+        print!("{}", calibration::annotate(&input, &Dictionary::english()));
+    }
+    print_solution::<Part2>(&input)
 }
@@ -0,0 +1,119 @@
+use crate::dictionary::{Dictionary, Match};
+use aoc_common::InputSource;
+use std::{
+    env,
+    fmt::{self, Display, Write},
+};
+
+/// Flag that makes the binaries print how every line was calibrated before the answer.
+pub const ANNOTATE_FLAG: &str = "--annotate";
+
+/// The words a line's calibration value is made of.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Calibration<'a> {
+    pub line: &'a str,
+    pub first: Option<Match<'a>>,
+    pub last: Option<Match<'a>>,
+}
+
+impl<'a> Calibration<'a> {
+    pub fn new(line: &'a str, dictionary: &Dictionary) -> Self {
+        Self {
+            line,
+            first: dictionary.first_match(line),
+            last: dictionary.last_match(line),
+        }
+    }
+
+    /// Lines without digits are worth nothing.
+    pub fn value(&self) -> u32 {
+        match (self.first, self.last) {
+            (Some(first), Some(last)) => (first.digit * 10 + last.digit) as u32,
+            _ => 0,
+        }
+    }
+}
+
+impl Display for Calibration<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} = {}", self.line, self.value())?;
+        match (self.first, self.last) {
+            (Some(first), Some(last)) => write!(
+                f,
+                " from {:?} at {}..{} and {:?} at {}..{}",
+                first.token, first.start, first.end, last.token, last.start, last.end
+            ),
+            _ => write!(f, " without digits"),
+        }
+    }
+}
+
+/// Calibration of every line of `input`, which add up to the answer of the part that uses
+/// `dictionary`.
+pub fn calibrations<'a>(
+    input: &'a str,
+    dictionary: &'a Dictionary,
+) -> impl Iterator<Item = Calibration<'a>> + 'a {
+    input.lines().map(|line| Calibration::new(line, dictionary))
+}
+
+/// One calibration per line, numbered from 1.
+pub fn annotate(input: &str, dictionary: &Dictionary) -> String {
+    let mut annotated = String::new();
+    for (idx, calibration) in calibrations(input, dictionary).enumerate() {
+        writeln!(annotated, "{:>5}: {}", idx + 1, calibration).unwrap();
+    }
+    annotated
+}
+
+/// Arguments of the binaries: [`ANNOTATE_FLAG`] if it comes first, then the input as
+/// [`InputSource::from_args`] takes it.
+pub fn args() -> (bool, InputSource) {
+    let mut args = env::args().skip(1).peekable();
+    let annotate = args.next_if(|arg| arg == ANNOTATE_FLAG).is_some();
+    let source = args
+        .next()
+        .map_or_else(InputSource::from_env, |arg| InputSource::from_arg(&arg));
+    (annotate, source)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{part1, part2};
+    use rstest::rstest;
+
+    #[test]
+    fn test_calibrations() {
+        let input = "two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen";
+
+        let english = Dictionary::english();
+        let digits = Dictionary::digits();
+        let sum = |dictionary| {
+            calibrations(input, dictionary)
+                .map(|calibration| calibration.value())
+                .sum::<u32>()
+        };
+        assert_eq!(sum(&english), part2::part2(input));
+        assert_eq!(sum(&digits), part1::part1(input));
+    }
+
+    #[rstest]
+    #[case("two1nine\ntreb7uchet\nabc\n")]
+    #[case("two1nine\r\ntreb7uchet\r\nabc")]
+    fn test_annotate(#[case] input: &str) {
+        assert_eq!(
+            annotate(input, &Dictionary::english()),
+            "    1: two1nine = 29 from \"two\" at 0..3 and \"nine\" at 4..8
+    2: treb7uchet = 77 from \"7\" at 4..5 and \"7\" at 4..5
+    3: abc = 0 without digits
+"
+        );
+    }
+}
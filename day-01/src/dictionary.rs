@@ -0,0 +1,257 @@
+use std::collections::VecDeque;
+
+/// Spelled-out digits of the puzzle, standing for 1 to 9 in order.
+pub static ENGLISH: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+/// Words that stand for a digit in a calibration line, on top of the digits themselves.
+///
+/// Lines are scanned once from each end with an Aho-Corasick automaton over the words, or over the
+/// reversed words from the back, so that overlapping words such as `eightwo` are both found.
+#[derive(Clone)]
+pub struct Dictionary {
+    forward: Automaton,
+    backward: Automaton,
+}
+
+impl Dictionary {
+    /// Words given more than once stand for the last digit given for them.
+    pub fn new<S: AsRef<str>>(words: impl IntoIterator<Item = (S, u8)>) -> Self {
+        let tokens = (b'0'..=b'9')
+            .map(|digit| (vec![digit], digit - b'0'))
+            .chain(words.into_iter().map(|(word, digit)| {
+                let word = word.as_ref();
+                assert!(!word.is_empty(), "words cannot be empty");
+                assert!(
+                    digit <= 9,
+                    "{} stands for {}, which is not a digit",
+                    word,
+                    digit
+                );
+                (word.as_bytes().to_vec(), digit)
+            }))
+            .collect::<Vec<_>>();
+        let reversed = tokens
+            .iter()
+            .map(|(token, digit)| (token.iter().rev().copied().collect(), *digit))
+            .collect::<Vec<_>>();
+
+        Self {
+            forward: Automaton::new(&tokens),
+            backward: Automaton::new(&reversed),
+        }
+    }
+
+    pub fn english() -> Self {
+        Self::new(ENGLISH.into_iter().zip(1..))
+    }
+
+    /// Only the digits themselves, as in part 1.
+    pub fn digits() -> Self {
+        Self::new(std::iter::empty::<(&str, u8)>())
+    }
+
+    /// Digit of the word that starts first, the longest one if several start there.
+    pub fn first(&self, line: &str) -> Option<u8> {
+        self.first_match(line).map(|found| found.digit)
+    }
+
+    /// Digit of the word that ends last, the longest one if several end there.
+    pub fn last(&self, line: &str) -> Option<u8> {
+        self.last_match(line).map(|found| found.digit)
+    }
+
+    /// Same as [`Dictionary::first`], along with where the word is.
+    pub fn first_match<'a>(&self, line: &'a str) -> Option<Match<'a>> {
+        let (start, len, digit) = self.forward.leftmost(line.bytes())?;
+        Some(Match::new(line, start, start + len, digit))
+    }
+
+    /// Same as [`Dictionary::last`], along with where the word is.
+    pub fn last_match<'a>(&self, line: &'a str) -> Option<Match<'a>> {
+        let (from_end, len, digit) = self.backward.leftmost(line.bytes().rev())?;
+        let end = line.len() - from_end;
+        Some(Match::new(line, end - len, end, digit))
+    }
+}
+
+/// A word of a [`Dictionary`] found in a line, `start` and `end` being byte offsets into it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Match<'a> {
+    pub start: usize,
+    pub end: usize,
+    pub token: &'a str,
+    pub digit: u8,
+}
+
+impl<'a> Match<'a> {
+    fn new(line: &'a str, start: usize, end: usize, digit: u8) -> Self {
+        Self {
+            start,
+            end,
+            token: &line[start..end],
+            digit,
+        }
+    }
+}
+
+impl Default for Dictionary {
+    fn default() -> Self {
+        Self::english()
+    }
+}
+
+/// Automaton with the failure links folded into the transitions, so that every byte takes a
+/// single lookup.
+#[derive(Clone)]
+struct Automaton {
+    transitions: Vec<[u32; 256]>,
+    /// Length and digit of the longest token that the bytes read up to each state end with.
+    outputs: Vec<Option<(usize, u8)>>,
+    max_len: usize,
+}
+
+impl Automaton {
+    fn new(tokens: &[(Vec<u8>, u8)]) -> Self {
+        // the root is never the child of another state, so 0 doubles as a missing edge of the trie
+        let mut transitions = vec![[0u32; 256]];
+        let mut outputs = vec![None];
+        for (token, digit) in tokens {
+            let mut state = 0;
+            for &byte in token {
+                if transitions[state][byte as usize] == 0 {
+                    transitions[state][byte as usize] = transitions.len() as u32;
+                    transitions.push([0; 256]);
+                    outputs.push(None);
+                }
+                state = transitions[state][byte as usize] as usize;
+            }
+            outputs[state] = Some((token.len(), *digit));
+        }
+
+        // Breadth first, so that the failure state of every state, being shallower, is complete by
+        // the time its transitions and output are borrowed.
+        let mut failures = vec![0; transitions.len()];
+        let mut queue = transitions[0]
+            .iter()
+            .filter(|&&child| child != 0)
+            .map(|&child| child as usize)
+            .collect::<VecDeque<_>>();
+        while let Some(state) = queue.pop_front() {
+            let failure = failures[state];
+            if outputs[state].is_none() {
+                outputs[state] = outputs[failure];
+            }
+            let fallbacks = transitions[failure];
+            for (next, fallback) in transitions[state].iter_mut().zip(fallbacks) {
+                if *next == 0 {
+                    *next = fallback;
+                } else {
+                    failures[*next as usize] = fallback as usize;
+                    queue.push_back(*next as usize);
+                }
+            }
+        }
+
+        Self {
+            transitions,
+            outputs,
+            max_len: tokens
+                .iter()
+                .map(|(token, _)| token.len())
+                .max()
+                .unwrap_or(0),
+        }
+    }
+
+    /// Start, length and digit of the match that starts first in `bytes`, the longest one if
+    /// several start there.
+    fn leftmost(&self, bytes: impl Iterator<Item = u8>) -> Option<(usize, usize, u8)> {
+        let mut state = 0;
+        let mut best: Option<(usize, usize, u8)> = None;
+        for (idx, byte) in bytes.enumerate() {
+            if best.is_some_and(|(start, _, _)| idx >= start + self.max_len) {
+                // every match from here on starts after the best one
+                break;
+            }
+            state = self.transitions[state][byte as usize] as usize;
+            if let Some((len, digit)) = self.outputs[state] {
+                let start = idx + 1 - len;
+                if best.is_none_or(|(best_start, _, _)| start <= best_start) {
+                    best = Some((start, len, digit));
+                }
+            }
+        }
+
+        best
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("eightwo", Some(8), Some(2))]
+    #[case("twone", Some(2), Some(1))]
+    #[case("xoneightx", Some(1), Some(8))]
+    #[case("sevenine3", Some(7), Some(3))]
+    #[case("0nin", Some(0), Some(0))]
+    #[case("abc", None, None)]
+    #[case("", None, None)]
+    fn test_english(#[case] line: &str, #[case] first: Option<u8>, #[case] last: Option<u8>) {
+        let dictionary = Dictionary::english();
+
+        assert_eq!(dictionary.first(line), first);
+        assert_eq!(dictionary.last(line), last);
+    }
+
+    #[rstest]
+    #[case("xfünfzweix", Some(5), Some(2))]
+    #[case("dreinsx", Some(3), Some(1))]
+    #[case("achtundneunzig", Some(8), Some(9))]
+    #[case("eightwo", None, None)]
+    fn test_german(#[case] line: &str, #[case] first: Option<u8>, #[case] last: Option<u8>) {
+        let dictionary = Dictionary::new([
+            ("eins", 1),
+            ("zwei", 2),
+            ("drei", 3),
+            ("vier", 4),
+            ("fünf", 5),
+            ("sechs", 6),
+            ("sieben", 7),
+            ("acht", 8),
+            ("neun", 9),
+        ]);
+
+        assert_eq!(dictionary.first(line), first);
+        assert_eq!(dictionary.last(line), last);
+    }
+
+    #[rstest]
+    #[case("eightwo", (0, "eight"), (4, "two"))]
+    #[case("x7seven", (1, "7"), (2, "seven"))]
+    #[case("zfünfz", (1, "fünf"), (1, "fünf"))]
+    fn test_matches(#[case] line: &str, #[case] first: (usize, &str), #[case] last: (usize, &str)) {
+        let dictionary = Dictionary::new(ENGLISH.into_iter().zip(1..).chain([("fünf", 5)]));
+        let span = |found: Match| (found.start, &line[found.start..found.end]);
+
+        assert_eq!(dictionary.first_match(line).map(span), Some(first));
+        assert_eq!(dictionary.last_match(line).map(span), Some(last));
+        assert_eq!(dictionary.first_match(line).unwrap().token, first.1);
+    }
+
+    #[rstest]
+    #[case("xabcdex", Some(1), Some(1))]
+    #[case("xbcdex", Some(2), Some(3))]
+    #[case("abcd", Some(1), Some(1))]
+    #[case("abcdexbc", Some(1), Some(2))]
+    fn test_nested_words(#[case] line: &str, #[case] first: Option<u8>, #[case] last: Option<u8>) {
+        let dictionary = Dictionary::new([("abcde", 1), ("bc", 2), ("cde", 3), ("abc", 1)]);
+
+        assert_eq!(dictionary.first(line), first);
+        assert_eq!(dictionary.last(line), last);
+    }
+}
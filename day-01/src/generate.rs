@@ -0,0 +1,59 @@
+use aoc_generate::{rand::Rng, rng, LOWERCASE};
+
+static WORDS: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+/// `size` lines of calibration values mixing digits, spelled out digits and other letters. Every
+/// line has at least one digit.
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = rng(seed);
+    let letters = LOWERCASE.as_bytes();
+
+    (0..size)
+        .map(|_| {
+            let tokens = rng.gen_range(1..=6);
+            let digit_at = rng.gen_range(0..tokens);
+            (0..tokens)
+                .map(|idx| match rng.gen_range(0..3) {
+                    _ if idx == digit_at => rng.gen_range(1..=9).to_string(),
+                    0 => rng.gen_range(1..=9).to_string(),
+                    1 => WORDS[rng.gen_range(0..WORDS.len())].to_string(),
+                    _ => (0..rng.gen_range(1..=5))
+                        .map(|_| letters[rng.gen_range(0..letters.len())] as char)
+                        .collect(),
+                })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::SOLUTIONS;
+    use aoc_common::testing;
+    use rstest::rstest;
+
+    #[test]
+    fn test_generated_input_is_solved() {
+        testing::check_generated(SOLUTIONS, generate, &[(1, 0, &["75", "75"]), (100, 1, &[])]);
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(100)]
+    fn test_generated_line_count(#[case] size: usize) {
+        assert_eq!(generate(size, 0).lines().count(), size);
+    }
+
+    #[rstest]
+    #[case(1000, 2)]
+    #[case(100_000, 3)]
+    fn test_variants_agree(#[case] size: usize, #[case] seed: u64) {
+        let input = generate(size, seed);
+
+        testing::assert_variants_agree(SOLUTIONS, &input);
+    }
+}
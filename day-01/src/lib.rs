@@ -1,2 +1,10 @@
+#![feature(portable_simd)]
+
+pub mod calibration;
+pub mod dictionary;
+pub mod generate;
 pub mod part1;
+pub mod part1_simd;
 pub mod part2;
+
+aoc_common::solutions!(1 => part1::Part1, part1_simd::Part1, part2::Part2);
@@ -1,9 +1,27 @@
-pub fn part1(input: &str) -> u16 {
-    let lines = input.split('\n');
-    lines
-        .into_iter()
-        .map(|a| -> u16 { part1_line(a) })
-        .sum::<u16>()
+use aoc_common::Solution;
+use std::convert::Infallible;
+
+pub struct Part1;
+
+impl Solution for Part1 {
+    const PART: u8 = 1;
+
+    type Model<'a> = Vec<&'a str>;
+    type Answer = u32;
+    type Error = Infallible;
+
+    fn parse(input: &str) -> Result<Self::Model<'_>, Self::Error> {
+        Ok(input.lines().collect())
+    }
+
+    fn solve(lines: Self::Model<'_>) -> Result<Self::Answer, Self::Error> {
+        Ok(lines.iter().map(|line| part1_line(line) as u32).sum())
+    }
+}
+
+pub fn part1(input: &str) -> u32 {
+    let Ok(answer) = Part1::run(input);
+    answer
 }
 
 fn part1_line(line: &str) -> u16 {
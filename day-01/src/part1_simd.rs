@@ -0,0 +1,171 @@
+use aoc_common::Solution;
+use rayon::prelude::*;
+use std::{
+    convert::Infallible,
+    simd::{
+        cmp::{SimdPartialEq, SimdPartialOrd},
+        u8x64,
+    },
+};
+
+/// Bytes handed to each rayon task, give or take the rest of the line the chunk ends in.
+const CHUNK_LEN: usize = 1 << 16;
+const LANES: usize = 64;
+
+pub struct Part1;
+
+impl Solution for Part1 {
+    const PART: u8 = 1;
+    const VARIANT: &'static str = "simd";
+
+    // The scan works on the raw bytes, splitting them into lines being most of the work.
+    type Model<'a> = &'a str;
+    type Answer = u32;
+    type Error = Infallible;
+
+    fn parse(input: &str) -> Result<Self::Model<'_>, Self::Error> {
+        Ok(input)
+    }
+
+    fn solve(input: Self::Model<'_>) -> Result<Self::Answer, Self::Error> {
+        Ok(part1(input))
+    }
+}
+
+pub fn part1(input: &str) -> u32 {
+    sum_chunks(input.as_bytes(), CHUNK_LEN)
+}
+
+/// Sums the calibration values of chunks of about `chunk_len` bytes in parallel, each of them
+/// ending right after a newline so that no line is split between two.
+fn sum_chunks(input: &[u8], chunk_len: usize) -> u32 {
+    // starting the thread pool takes far longer than scanning a single chunk
+    if input.len() <= chunk_len {
+        return sum_lines(input);
+    }
+
+    let mut chunks = vec![];
+    let mut start = 0;
+    while start < input.len() {
+        let end = (start + chunk_len).min(input.len());
+        let end = input[end..]
+            .iter()
+            .position(|&byte| byte == b'\n')
+            .map_or(input.len(), |newline| end + newline + 1);
+        chunks.push(&input[start..end]);
+        start = end;
+    }
+
+    chunks.into_par_iter().map(sum_lines).sum()
+}
+
+/// Sums the calibration values of the lines in `bytes`, 64 bytes at a time: the digits and
+/// newlines of each block are turned into bitmasks, so the first and last digit of every line in
+/// it are a count of zeros away.
+fn sum_lines(bytes: &[u8]) -> u32 {
+    let mut line = Line::default();
+    let mut sum = 0;
+
+    let mut blocks = bytes.chunks_exact(LANES);
+    for block in blocks.by_ref() {
+        sum += line.scan(block);
+    }
+    // zeros are neither digits nor newlines, so padding the rest leaves the line as it is
+    let mut rest = [0; LANES];
+    rest[..blocks.remainder().len()].copy_from_slice(blocks.remainder());
+    sum += line.scan(&rest);
+
+    sum + line.value()
+}
+
+/// First and last digit of the line being scanned, which may span several blocks.
+#[derive(Debug, Default)]
+struct Line {
+    first: Option<u8>,
+    last: u8,
+}
+
+impl Line {
+    /// Sum of the lines that end in `block`, keeping track of the one that goes on after it.
+    fn scan(&mut self, block: &[u8]) -> u32 {
+        let chunk = u8x64::from_slice(block);
+        let mut digits = (chunk - u8x64::splat(b'0'))
+            .simd_lt(u8x64::splat(10))
+            .to_bitmask();
+        let mut newlines = chunk.simd_eq(u8x64::splat(b'\n')).to_bitmask();
+
+        let mut sum = 0;
+        while newlines != 0 {
+            let newline = newlines.trailing_zeros();
+            let before = (1 << newline) - 1;
+            self.add(block, digits & before);
+            sum += self.value();
+            *self = Line::default();
+
+            digits &= !(before | 1 << newline);
+            newlines &= newlines - 1;
+        }
+        self.add(block, digits);
+
+        sum
+    }
+
+    fn add(&mut self, block: &[u8], digits: u64) {
+        if digits == 0 {
+            return;
+        }
+        let digit = |idx: u32| block[idx as usize] - b'0';
+        self.first.get_or_insert(digit(digits.trailing_zeros()));
+        self.last = digit(u64::BITS - 1 - digits.leading_zeros());
+    }
+
+    /// Lines without digits are worth nothing, like in [`crate::part1`].
+    fn value(&self) -> u32 {
+        self.first
+            .map_or(0, |first| (first * 10 + self.last) as u32)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::part1;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet", 142)]
+    #[case("safg2fhkjw7fdhalkf", 27)]
+    #[case("hklekogfajlekopgscgkl\n\n\n5", 55)]
+    #[case("", 0)]
+    #[case("\n", 0)]
+    fn test_part1(#[case] input: &str, #[case] expected: u32) {
+        assert_eq!(part1(input), expected);
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(7)]
+    #[case(64)]
+    #[case(100)]
+    #[case(CHUNK_LEN)]
+    fn test_sum_chunks(#[case] chunk_len: usize) {
+        // lines of every length up to a couple of blocks, so that they start and end everywhere
+        // in a block and some of them span several
+        let input = (0..150)
+            .map(|len| {
+                (0..len)
+                    .map(|idx| match idx % 7 {
+                        3 => char::from(b'0' + (idx % 10) as u8),
+                        _ => 'x',
+                    })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        assert_eq!(
+            sum_chunks(input.as_bytes(), chunk_len),
+            part1::part1(&input)
+        );
+    }
+}
@@ -1,62 +1,45 @@
-use std::cmp::min;
+use crate::dictionary::Dictionary;
+use aoc_common::Solution;
+use std::convert::Infallible;
+
+pub struct Part2;
+
+impl Solution for Part2 {
+    const PART: u8 = 2;
+
+    type Model<'a> = Vec<&'a str>;
+    type Answer = u32;
+    type Error = Infallible;
+
+    fn parse(input: &str) -> Result<Self::Model<'_>, Self::Error> {
+        Ok(input.lines().collect())
+    }
+
+    fn solve(lines: Self::Model<'_>) -> Result<Self::Answer, Self::Error> {
+        Ok(calibration_sum(lines, &Dictionary::english()))
+    }
+}
 
 pub fn part2(input: &str) -> u32 {
-    let lines = input.split('\n');
+    let Ok(answer) = Part2::run(input);
+    answer
+}
+
+/// Same as [`part2`] for calibration logs that spell out the digits with other words.
+pub fn part2_with_dictionary(input: &str, dictionary: &Dictionary) -> u32 {
+    calibration_sum(input.lines(), dictionary)
+}
+
+fn calibration_sum<'a>(lines: impl IntoIterator<Item = &'a str>, dictionary: &Dictionary) -> u32 {
     lines
         .into_iter()
-        .map(|a| -> u32 { part2_line(a) as u32 })
-        .sum::<u32>()
+        .map(|line| part2_line(line, dictionary) as u32)
+        .sum()
 }
 
-static VALID_WORDS: [&str; 9] = [
-    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
-];
-
-fn part2_line(line: &str) -> u16 {
-    let mut first_num = 0;
-    for (idx, c) in line.chars().enumerate() {
-        if c.is_ascii_digit() {
-            first_num = c as u8 - b'0';
-            break;
-        }
-        let slice_len = 5;
-        let start_slice_idx = idx;
-        let end_slice_idx = min(line.len() - 1, idx + slice_len);
-        let slice = &line[start_slice_idx..end_slice_idx];
-        let mut break_out = false;
-        for (word_idx, word) in VALID_WORDS.into_iter().enumerate() {
-            if slice.starts_with(word) {
-                first_num = word_idx as u8 + 1;
-                break_out = true;
-                break;
-            }
-        }
-        if break_out {
-            break;
-        }
-    }
-    let mut last_num = 0;
-    for (idx, c) in line.chars().rev().enumerate() {
-        if c.is_ascii_digit() {
-            last_num = c as u8 - b'0';
-            break;
-        }
-        let slice_len = 5;
-        let start_slice_idx = line.len() - idx - 1;
-        let end_slice_idx = min(line.len(), line.len() - idx - 1 + slice_len);
-        let slice = &line[start_slice_idx..end_slice_idx];
-        let mut break_out = false;
-        for (word_idx, word) in VALID_WORDS.into_iter().enumerate() {
-            if slice.starts_with(word) {
-                last_num = word_idx as u8 + 1;
-                break_out = true;
-                break;
-            }
-        }
-        if break_out {
-            break;
-        }
-    }
+pub fn part2_line(line: &str, dictionary: &Dictionary) -> u16 {
+    let first_num = dictionary.first(line).unwrap_or(0);
+    let last_num = dictionary.last(line).unwrap_or(0);
     (first_num * 10) as u16 + last_num as u16
 }
 
@@ -71,7 +54,7 @@ mod test {
     #[case("ads2kljh4jkldfas9ashdklj5dasdlk", 25)]
     #[case("hklekogfajlekopgscgkl", 0)]
     fn test_part1_still_works(#[case] line: &str, #[case] expected: u16) {
-        assert_eq!(part2_line(line), expected);
+        assert_eq!(part2_line(line, &Dictionary::english()), expected);
     }
 
     #[rstest]
@@ -80,7 +63,17 @@ mod test {
     #[case("dsgf4dhglkftwothree9asdkjlheightdsad", 48)]
     #[case("4dhglktwothree9asdkjlheightdsad2", 42)]
     #[case("ninedhglktwothree9asdkjlheightdsadthree", 93)]
+    #[case("eightwo", 82)]
+    #[case("7pqrstsixteen", 76)]
     fn test_part2(#[case] line: &str, #[case] expected: u16) {
-        assert_eq!(part2_line(line), expected);
+        assert_eq!(part2_line(line, &Dictionary::english()), expected);
+    }
+
+    #[test]
+    fn test_part2_with_dictionary() {
+        let dictionary = Dictionary::new([("uno", 1), ("dos", 2), ("tres", 3), ("x", 0)]);
+        let input = "xdosuno\nuntres\n4unodos";
+
+        assert_eq!(part2_with_dictionary(input, &dictionary), 1 + 33 + 42);
     }
 }
@@ -1,50 +1,39 @@
+use aoc_common::{bench, input, InputSource};
 use criterion::{criterion_group, criterion_main, Criterion};
-use day_02::*;
+use day_02::{generate, SOLUTIONS};
 
-fn criterion_benchmark_part1(c: &mut Criterion) {
-    let input = include_str!("../src/input.txt");
+/// Sizes passed to [`generate::generate`], see there for what they mean for this day.
+const SIZES: [usize; 3] = [10, 100, 1000];
 
-    let mut group = c.benchmark_group("day_02::part1");
-    group.bench_with_input("part1", input, |b, input| b.iter(|| part1::part1(input)));
+fn criterion_benchmark(c: &mut Criterion) {
+    let input = input::load(InputSource::from_env(), include_str!("../src/input.txt"));
 
-    group.finish();
-}
-
-fn criterion_benchmark_part1_chumsky(c: &mut Criterion) {
-    let input = include_str!("../src/input.txt");
+    for solution in SOLUTIONS {
+        let mut group = c.benchmark_group(format!("day_02::{}", solution.name()));
+        // Unlike the other days, the chumsky benchmarks were named after the variant, which saved
+        // baselines still go by.
+        group.bench_with_input(solution.name(), &*input, |b, input| {
+            b.iter(|| (solution.run)(input))
+        });
 
-    let mut group = c.benchmark_group("day_02::part1_chumsky");
-    group.bench_with_input("part1_chumsky", input, |b, input| {
-        b.iter(|| part1_chumsky::part1(input))
-    });
-
-    group.finish();
+        group.finish();
+    }
 }
 
-fn criterion_benchmark_part2(c: &mut Criterion) {
-    let input = include_str!("../src/input.txt");
-
-    let mut group = c.benchmark_group("day_02::part2");
-    group.bench_with_input("part2", input, |b, input| b.iter(|| part2::part2(input)));
+fn phases_benchmark(c: &mut Criterion) {
+    let input = input::load(InputSource::from_env(), include_str!("../src/input.txt"));
 
-    group.finish();
+    bench::phases(c, SOLUTIONS, &input);
 }
 
-fn criterion_benchmark_part2_chumsky(c: &mut Criterion) {
-    let input = include_str!("../src/input.txt");
-
-    let mut group = c.benchmark_group("day_02::part2_chumsky");
-    group.bench_with_input("part2_chumsky", input, |b, input| {
-        b.iter(|| part2_chumsky::part2(input))
-    });
-
-    group.finish();
+fn generated_benchmark(c: &mut Criterion) {
+    bench::generated(c, SOLUTIONS, &SIZES, generate::generate);
 }
+
 criterion_group!(
     benches,
-    criterion_benchmark_part1,
-    criterion_benchmark_part2,
-    criterion_benchmark_part1_chumsky,
-    criterion_benchmark_part2_chumsky,
+    criterion_benchmark,
+    phases_benchmark,
+    generated_benchmark
 );
 criterion_main!(benches);
@@ -1,13 +1,15 @@
-use day_02::part1_chumsky::part1;
+use aoc_common::{input, InputSource};
+use day_02::{parse, part1};
+use std::process::ExitCode;
 
 #[cfg(feature = "dhat-heap")]
 #[global_allocator]
 static ALLOC: dhat::Alloc = dhat::Alloc;
 
-fn main() {
+fn main() -> ExitCode {
     #[cfg(feature = "dhat-heap")]
     let _profiler = dhat::Profiler::new_heap();
 
-    let input = include_str!("../input.txt");
-    println!("{}", part1(input));
+    let input = input::load(InputSource::from_args(), include_str!("../input.txt"));
+    parse::parse_report(&input).print(part1::possible_ids)
 }
@@ -1,13 +1,15 @@
-use day_02::part2::part2;
+use aoc_common::{input, print_solution, InputSource};
+use day_02::part2::Part2;
+use std::process::ExitCode;
 
 #[cfg(feature = "dhat-heap")]
 #[global_allocator]
 static ALLOC: dhat::Alloc = dhat::Alloc;
 
-fn main() {
+fn main() -> ExitCode {
     #[cfg(feature = "dhat-heap")]
     let _profiler = dhat::Profiler::new_heap();
 
-    let input = include_str!("../input.txt");
-    println!("{}", part2(input));
+    let input = input::load(InputSource::from_args(), include_str!("../input.txt"));
+    print_solution::<Part2>(&input)
 }
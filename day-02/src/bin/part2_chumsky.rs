@@ -1,13 +1,15 @@
-use day_02::part2_chumsky::part2;
+use aoc_common::{input, InputSource};
+use day_02::{parse, part2};
+use std::process::ExitCode;
 
 #[cfg(feature = "dhat-heap")]
 #[global_allocator]
 static ALLOC: dhat::Alloc = dhat::Alloc;
 
-fn main() {
+fn main() -> ExitCode {
     #[cfg(feature = "dhat-heap")]
     let _profiler = dhat::Profiler::new_heap();
 
-    let input = include_str!("../input.txt");
-    println!("{}", part2(input));
+    let input = input::load(InputSource::from_args(), include_str!("../input.txt"));
+    parse::parse_report(&input).print(part2::powers)
 }
@@ -0,0 +1,43 @@
+use aoc_generate::{
+    rand::{seq::SliceRandom, Rng},
+    rng,
+};
+
+/// `size` games of up to 6 rounds. Every color shows up at most once per round.
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = rng(seed);
+
+    (1..=size)
+        .map(|id| {
+            let rounds = (0..rng.gen_range(1..=6))
+                .map(|_| {
+                    let mut colors = ["red", "green", "blue"];
+                    colors.shuffle(&mut rng);
+                    colors[..rng.gen_range(1..=3)]
+                        .iter()
+                        .map(|color| format!("{} {}", rng.gen_range(1..=20), color))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                })
+                .collect::<Vec<_>>();
+            format!("Game {}: {}", id, rounds.join("; "))
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::SOLUTIONS;
+    use aoc_common::testing;
+
+    #[test]
+    fn test_generated_input_is_solved() {
+        testing::check_generated(
+            SOLUTIONS,
+            generate,
+            &[(1, 0, &["0", "3800"]), (100, 1, &[])],
+        );
+    }
+}
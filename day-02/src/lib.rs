@@ -1,4 +1,68 @@
+pub mod generate;
+pub mod parse;
 pub mod part1;
 pub mod part1_chumsky;
 pub mod part2;
 pub mod part2_chumsky;
+
+aoc_common::solutions!(2 => part1::Part1, part1_chumsky::Part1, part2::Part2, part2_chumsky::Part2);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn answers(part: u8, input: &str) -> Vec<(&'static str, Result<String, String>)> {
+        SOLUTIONS
+            .iter()
+            .filter(|solution| solution.part == part)
+            .map(|solution| {
+                let answer = (solution.run)(input).map_err(|err| err.to_string());
+                (solution.variant, answer)
+            })
+            .collect()
+    }
+
+    /// Colors may show up more than once per round, unlike in the puzzle input.
+    fn round() -> impl Strategy<Value = String> {
+        let color = prop::sample::select(vec!["red", "green", "blue"]);
+        prop::collection::vec((1..=20u32, color), 1..=4).prop_map(|cubes| {
+            cubes
+                .iter()
+                .map(|(amount, color)| format!("{} {}", amount, color))
+                .collect::<Vec<_>>()
+                .join(", ")
+        })
+    }
+
+    fn games() -> impl Strategy<Value = String> {
+        let game = prop::collection::vec(round(), 1..=5).prop_map(|rounds| rounds.join("; "));
+        prop::collection::vec(game, 1..=10).prop_map(|games| {
+            games
+                .iter()
+                .enumerate()
+                .map(|(idx, game)| format!("Game {}: {}", idx + 1, game))
+                .collect::<Vec<_>>()
+                .join("\n")
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn test_parsers_agree(input in games()) {
+            let games = parse::parse_games(&input);
+            prop_assert!(games.is_ok(), "{:?}", games);
+            prop_assert_eq!(games, parse::parse_games_chumsky(&input));
+        }
+
+        #[test]
+        fn test_variants_agree(input in games(), part in 1..=2u8) {
+            let answers = answers(part, &input);
+            let (_, expected) = &answers[0];
+            prop_assert!(expected.is_ok(), "{:?}", expected);
+            for (variant, answer) in &answers[1..] {
+                prop_assert_eq!(answer, expected, "variant {} disagrees", variant);
+            }
+        }
+    }
+}
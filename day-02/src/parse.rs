@@ -0,0 +1,399 @@
+use aoc_common::print_answer;
+use chumsky::{error::Rich, prelude::*};
+use nom::{
+    bytes::complete::{is_not, tag},
+    character::complete::{alpha1, char, digit1},
+    combinator::{all_consuming, rest},
+    sequence::{delimited, separated_pair},
+};
+use std::{
+    convert::Infallible,
+    fmt::{self, Display},
+    num::ParseIntError,
+    process::ExitCode,
+};
+
+pub type ParseError = aoc_common::ParseError<ParseErrorKind>;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    MissingGameId,
+    InvalidNumber(ParseIntError),
+    InvalidCubes,
+    UnknownColor,
+    /// Rendered error of the chumsky parser.
+    Syntax(String),
+}
+
+impl Display for ParseErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseErrorKind::MissingGameId => {
+                write!(f, "expected a line starting with `Game <id>: `")
+            }
+            ParseErrorKind::InvalidNumber(err) => write!(f, "invalid number ({})", err),
+            ParseErrorKind::InvalidCubes => write!(f, "expected cubes as `<count> <color>`"),
+            ParseErrorKind::UnknownColor => write!(f, "unknown cube color"),
+            ParseErrorKind::Syntax(reason) => write!(f, "{}", reason),
+        }
+    }
+}
+
+/// Points a chumsky error at the text of `input` it spans.
+pub fn syntax_error(input: &str, err: &Rich<'_, char>) -> ParseError {
+    let span = err.span();
+    ParseError::at_offset(
+        input,
+        span.start.min(input.len())..span.end.min(input.len()),
+        ParseErrorKind::Syntax(err.reason().to_string()),
+    )
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Cubes {
+    pub red: u32,
+    pub green: u32,
+    pub blue: u32,
+}
+
+impl Cubes {
+    /// Whether every color fits into `bag`.
+    pub fn fits(&self, bag: &Cubes) -> bool {
+        self.red <= bag.red && self.green <= bag.green && self.blue <= bag.blue
+    }
+
+    pub fn max(self, other: Cubes) -> Cubes {
+        Cubes {
+            red: self.red.max(other.red),
+            green: self.green.max(other.green),
+            blue: self.blue.max(other.blue),
+        }
+    }
+
+    pub fn power(&self) -> u32 {
+        self.red * self.green * self.blue
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Color {
+    Red(u32),
+    Green(u32),
+    Blue(u32),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Round {
+    pub colors: Vec<Color>,
+}
+
+impl Round {
+    /// Cubes shown in the round, counting the most shown of colors that show up more than once.
+    pub fn cubes(&self) -> Cubes {
+        self.colors
+            .iter()
+            .fold(Cubes::default(), |mut cubes, color| {
+                match color {
+                    Color::Red(amount) => cubes.red = cubes.red.max(*amount),
+                    Color::Green(amount) => cubes.green = cubes.green.max(*amount),
+                    Color::Blue(amount) => cubes.blue = cubes.blue.max(*amount),
+                }
+                cubes
+            })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Game {
+    pub id: u32,
+    pub rounds: Vec<Round>,
+}
+
+impl Game {
+    /// Fewest cubes of each color the bag could have held for the game to be possible.
+    pub fn fewest_cubes(&self) -> Cubes {
+        self.rounds
+            .iter()
+            .map(Round::cubes)
+            .fold(Cubes::default(), Cubes::max)
+    }
+}
+
+/// Games of `input`, one per line, parsed with nom.
+pub fn parse_games(input: &str) -> Result<Vec<Game>, ParseError> {
+    input.lines().map(|line| parse_game(input, line)).collect()
+}
+
+fn parse_game(input: &str, line: &str) -> Result<Game, ParseError> {
+    let (log, id) = delimited(tag("Game "), digit1, tag(": "))(line)
+        .map_err(nom_error(input, ParseErrorKind::MissingGameId))?;
+    let id = ParseError::try_parse::<u32>(input, id, ParseErrorKind::InvalidNumber)?;
+    let rounds = log
+        .split("; ")
+        .map(|round| {
+            let colors = round
+                .split(", ")
+                .map(|cubes| parse_color(input, cubes))
+                .collect::<Result<_, _>>()?;
+            Ok(Round { colors })
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok(Game { id, rounds })
+}
+
+fn parse_color(input: &str, cubes: &str) -> Result<Color, ParseError> {
+    let (_, (amount, color)) =
+        all_consuming(separated_pair(is_not(" "), char(' '), rest::<&str, ()>))(cubes)
+            .map_err(|_| ParseError::new(input, cubes, ParseErrorKind::InvalidCubes))?;
+    let amount = ParseError::try_parse::<u32>(input, amount, ParseErrorKind::InvalidNumber)?;
+    match all_consuming(alpha1::<&str, ()>)(color) {
+        Ok((_, "red")) => Ok(Color::Red(amount)),
+        Ok((_, "green")) => Ok(Color::Green(amount)),
+        Ok((_, "blue")) => Ok(Color::Blue(amount)),
+        _ => Err(ParseError::new(input, color, ParseErrorKind::UnknownColor)),
+    }
+}
+
+/// Points at the rest of the line nom stopped at.
+fn nom_error<'a>(
+    input: &'a str,
+    kind: ParseErrorKind,
+) -> impl FnOnce(nom::Err<nom::error::Error<&str>>) -> ParseError + 'a {
+    move |err| match err {
+        nom::Err::Error(err) | nom::Err::Failure(err) => ParseError::new(input, err.input, kind),
+        nom::Err::Incomplete(_) => ParseError::new(input, &input[input.len()..], kind),
+    }
+}
+
+/// Games of `input`, one per line, parsed with chumsky. Fails with the first of the errors of
+/// [`parse_report`].
+pub fn parse_games_chumsky(input: &str) -> Result<Vec<Game>, ParseError> {
+    let report = parse_report(input);
+    match report.errors.into_iter().next() {
+        Some(err) => Err(err),
+        None => Ok(report.games),
+    }
+}
+
+/// Result of parsing the whole input with chumsky, which carries on with the next line after one
+/// it cannot parse.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    /// Games of the lines that parsed, in order.
+    pub games: Vec<Game>,
+    /// At least one error for every line that did not.
+    pub errors: Vec<ParseError>,
+}
+
+impl Report {
+    /// Prints the answer for the games that parsed, after listing the errors of the other lines
+    /// on stderr. Fails if there were any, even though an answer is printed.
+    pub fn print(&self, solve: impl FnOnce(&[Game]) -> u32) -> ExitCode {
+        for err in &self.errors {
+            eprintln!("error: {:#}\n", err);
+        }
+        if !self.errors.is_empty() {
+            eprintln!(
+                "solving the {} games that parsed, {} errors left out the rest",
+                self.games.len(),
+                self.errors.len()
+            );
+        }
+
+        let exit_code = print_answer(Ok::<_, Infallible>(solve(&self.games)));
+        if self.errors.is_empty() {
+            exit_code
+        } else {
+            ExitCode::FAILURE
+        }
+    }
+}
+
+pub fn parse_report(input: &str) -> Report {
+    let (games, errors) = games_parser().parse(input).into_output_errors();
+    Report {
+        games: games.into_iter().flatten().flatten().collect(),
+        errors: errors.iter().map(|err| syntax_error(input, err)).collect(),
+    }
+}
+
+/// One game per line, `None` for the lines that could not be parsed.
+fn games_parser<'a>() -> impl Parser<'a, &'a str, Vec<Option<Game>>, extra::Err<Rich<'a, char>>> {
+    let line_end = text::newline()
+        .or(end())
+        .rewind()
+        .map_err(|err: Rich<char>| Rich::custom(*err.span(), "expected the end of the line"));
+    // The rest of a bad line, or a blank line unless it is the end of the input, so that a final
+    // newline is not taken for one.
+    let skip_line = none_of('\n')
+        .repeated()
+        .at_least(1)
+        .ignored()
+        .or(just('\n').rewind().ignored());
+
+    game_parser()
+        .then_ignore(line_end)
+        .recover_with(via_parser(skip_line.to(None)))
+        .separated_by(text::newline())
+        .allow_trailing()
+        .collect::<Vec<_>>()
+        .then_ignore(end())
+}
+
+/// A line with an unknown color is still parsed, but gives `None` after reporting the color.
+fn game_parser<'a>() -> impl Parser<'a, &'a str, Option<Game>, extra::Err<Rich<'a, char>>> {
+    let game_id = just("Game ")
+        .ignore_then(text::int(10))
+        .from_str::<u32>()
+        .try_map(|id, span| id.map_err(|err| Rich::custom(span, err)));
+
+    let color = text::int(10)
+        .from_str::<u32>()
+        .try_map(|id, span| id.map_err(|err| Rich::custom(span, err)))
+        .then_ignore(just(' '))
+        .then(text::ascii::ident())
+        .padded_by(text::inline_whitespace())
+        .validate(|(amount, name), extra, emitter| match name {
+            "red" => Some(Color::Red(amount)),
+            "blue" => Some(Color::Blue(amount)),
+            "green" => Some(Color::Green(amount)),
+            _ => {
+                emitter.emit(Rich::custom(
+                    extra.span(),
+                    format!("{} is not a valid color", name),
+                ));
+                None
+            }
+        });
+    let round = color
+        .separated_by(just(','))
+        .at_least(1)
+        .collect::<Vec<_>>()
+        .map(|colors| colors.into_iter().collect::<Option<_>>())
+        .map(|colors| colors.map(|colors| Round { colors }));
+    game_id
+        .then_ignore(just(": "))
+        .then(
+            round
+                .padded_by(text::inline_whitespace())
+                .separated_by(just(';'))
+                .at_least(1)
+                .collect::<Vec<_>>(),
+        )
+        .map(|(id, rounds)| {
+            let rounds = rounds.into_iter().collect::<Option<_>>()?;
+            Some(Game { id, rounds })
+        })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green",
+        vec![Game {
+            id: 1,
+            rounds: vec![
+                Round{
+                    colors: vec![
+                        Color::Blue(3),
+                        Color::Red(4),
+                    ]
+                },
+                Round{
+                    colors: vec![
+                        Color::Red(1),
+                        Color::Green(2),
+                        Color::Blue(6),
+                    ]
+                },
+                Round{
+                    colors: vec![
+                        Color::Green(2),
+                    ]
+                }
+            ]
+        }]
+    )]
+    #[case("Game 7: 1 red\nGame 12: 2 green, 3 blue",
+        vec![
+            Game { id: 7, rounds: vec![Round { colors: vec![Color::Red(1)] }] },
+            Game { id: 12, rounds: vec![Round { colors: vec![Color::Green(2), Color::Blue(3)] }] },
+        ]
+    )]
+    #[case("", vec![])]
+    fn test_parsers(#[case] input: &str, #[case] expected: Vec<Game>) {
+        assert_eq!(parse_games(input), Ok(expected.clone()));
+        assert_eq!(parse_games_chumsky(input), Ok(expected));
+    }
+
+    #[rstest]
+    #[case("Game 1: 3 blue, 4 red; 1 red, 2 purple, 6 blue; 2 green")]
+    #[case("Game 1: 3 blue; 1red")]
+    #[case("Game 1: 3 blue; red")]
+    #[case("Game 1: 99999999999 blue")]
+    #[case("Game x: 3 blue")]
+    #[case("1: 3 blue")]
+    #[case("Game 1: 3 blue\nGame 2")]
+    fn test_parser_failure(#[case] input: &str) {
+        assert!(parse_games(input).is_err());
+        assert!(parse_games_chumsky(input).is_err());
+    }
+
+    #[test]
+    fn test_fewest_cubes() {
+        let game = parse_games("Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green")
+            .unwrap()
+            .remove(0);
+
+        assert_eq!(
+            game.fewest_cubes(),
+            Cubes {
+                red: 20,
+                green: 13,
+                blue: 6
+            }
+        );
+        assert_eq!(game.fewest_cubes().power(), 1560);
+    }
+
+    #[test]
+    fn test_report() {
+        let input = "Game 1: 3 blue, 4 red
+Game 2: 3 blue, 4 purple
+Game 3: 2 green
+
+Game x: 1 red
+Game 5: 1 red 2 blue
+Game 6: 1 red; 2 green, 3 blue
+";
+        let report = parse_report(input);
+
+        assert_eq!(
+            report.games.iter().map(|game| game.id).collect::<Vec<_>>(),
+            vec![1, 3, 6]
+        );
+        assert_eq!(
+            report
+                .errors
+                .iter()
+                .map(|err| (err.line, err.column))
+                .collect::<Vec<_>>(),
+            vec![(2, 16), (4, 1), (5, 6), (6, 15)]
+        );
+        assert_eq!(
+            format!("{:#}", report.errors[0]),
+            "purple is not a valid color at line 2, column 16: \" 4 purple\"
+  |
+2 | Game 2: 3 blue, 4 purple
+  |                ^^^^^^^^^"
+        );
+        assert_eq!(
+            report.errors[3].to_string(),
+            "expected the end of the line at line 6, column 15: \"2\""
+        );
+    }
+}
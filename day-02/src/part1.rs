@@ -1,12 +1,26 @@
-pub fn part1(input: &str) -> u32 {
-    let games = input.lines();
-    games.map(part1_game).sum::<u32>()
+use crate::parse::{self, Cubes, Game, ParseError};
+use aoc_common::Solution;
+
+pub struct Part1;
+
+impl Solution for Part1 {
+    const PART: u8 = 1;
+
+    type Model<'a> = Vec<Game>;
+    type Answer = u32;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Model<'_>, Self::Error> {
+        parse::parse_games(input)
+    }
+
+    fn solve(games: Self::Model<'_>) -> Result<Self::Answer, Self::Error> {
+        Ok(possible_ids(&games))
+    }
 }
 
-struct Cubes {
-    pub red: u32,
-    pub green: u32,
-    pub blue: u32,
+pub fn part1(input: &str) -> Result<u32, ParseError> {
+    Part1::run(input)
 }
 
 static POSSIBLE_CUBES: Cubes = Cubes {
@@ -14,59 +28,42 @@ static POSSIBLE_CUBES: Cubes = Cubes {
     green: 13,
     blue: 14,
 };
-static IMPOSSIBLE_GAME: u32 = 0;
 
-fn part1_game(game: &str) -> u32 {
-    if let Some((id, log)) = game.split_once(": ") {
-        let id = id
-            .strip_prefix("Game ")
-            .unwrap()
-            .parse::<u32>()
-            .expect("Not a valid game id");
-        let rounds = log.split("; ");
-        for r in rounds {
-            let cubes = r.split(", ");
-            for c in cubes {
-                if let Some((num_str, color)) = c.split_once(' ') {
-                    let number = num_str.parse::<u32>().expect("Cube count was not a number");
-                    match color {
-                        "red" => {
-                            if number > POSSIBLE_CUBES.red {
-                                return IMPOSSIBLE_GAME;
-                            }
-                        }
-                        "green" => {
-                            if number > POSSIBLE_CUBES.green {
-                                return IMPOSSIBLE_GAME;
-                            }
-                        }
-                        "blue" => {
-                            if number > POSSIBLE_CUBES.blue {
-                                return IMPOSSIBLE_GAME;
-                            }
-                        }
-                        _ => panic!("Non-valid color provided"),
-                    }
-                } else {
-                    panic!("Failed on game line: {}", game);
-                }
-            }
-        }
-        id
-    } else {
-        IMPOSSIBLE_GAME
-    }
+/// Sum of the ids of the games that were possible with [`POSSIBLE_CUBES`] in the bag.
+pub fn possible_ids(games: &[Game]) -> u32 {
+    games
+        .iter()
+        .filter(|game| game.fewest_cubes().fits(&POSSIBLE_CUBES))
+        .map(|game| game.id)
+        .sum()
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::parse::ParseErrorKind;
     use rstest::rstest;
 
     #[rstest]
     #[case("Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green", 1)]
     #[case("Game 1: 3 blue, 40 red; 1 red, 2 green, 6 blue; 2 green", 0)]
+    #[case("Game 1: 10 red, 10 red", 1)]
     fn test_part1_possible(#[case] input: &str, #[case] expected: u32) {
-        assert_eq!(part1_game(input), expected)
+        assert_eq!(part1(input), Ok(expected))
+    }
+
+    #[rstest]
+    #[case("Game 1: 3 blue\nGame 2: 3 blue, 4 purple", ParseErrorKind::UnknownColor, (2, 19))]
+    #[case("Game 1: 3 blue; 1red", ParseErrorKind::InvalidCubes, (1, 17))]
+    #[case("1: 3 blue", ParseErrorKind::MissingGameId, (1, 1))]
+    fn test_part1_failure(
+        #[case] input: &str,
+        #[case] expected: ParseErrorKind,
+        #[case] position: (usize, usize),
+    ) {
+        let err = part1(input).unwrap_err();
+
+        assert_eq!(err.kind, expected);
+        assert_eq!((err.line, err.column), position);
     }
 }
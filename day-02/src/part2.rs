@@ -1,58 +1,33 @@
-pub fn part2(input: &str) -> u32 {
-    let games = input.lines();
-    games.map(part2_game).sum::<u32>()
-}
+use crate::parse::{self, Game, ParseError};
+use aoc_common::Solution;
 
-struct Cubes {
-    pub red: u32,
-    pub green: u32,
-    pub blue: u32,
-}
+pub struct Part2;
+
+impl Solution for Part2 {
+    const PART: u8 = 2;
 
-static IMPOSSIBLE_GAME: u32 = 0;
+    type Model<'a> = Vec<Game>;
+    type Answer = u32;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Model<'_>, Self::Error> {
+        parse::parse_games(input)
+    }
 
-fn part2_game(game: &str) -> u32 {
-    if let Some((_, log)) = game.split_once(": ") {
-        let rounds = log.split("; ");
-        let mut max_cubes = Cubes {
-            red: 0,
-            green: 0,
-            blue: 0,
-        };
-        for r in rounds {
-            let cubes = r.split(", ");
-            for c in cubes {
-                if let Some((num_str, color)) = c.split_once(' ') {
-                    let number = num_str.parse::<u32>().expect("Cube count was not a number");
-                    match color {
-                        "red" => {
-                            if number > max_cubes.red {
-                                max_cubes.red = number;
-                            }
-                        }
-                        "green" => {
-                            if number > max_cubes.green {
-                                max_cubes.green = number;
-                            }
-                        }
-                        "blue" => {
-                            if number > max_cubes.blue {
-                                max_cubes.blue = number;
-                            }
-                        }
-                        _ => panic!("Non-valid color provided"),
-                    }
-                } else {
-                    panic!("Failed on game line: {}", game);
-                }
-            }
-        }
-        max_cubes.red * max_cubes.green * max_cubes.blue
-    } else {
-        IMPOSSIBLE_GAME
+    fn solve(games: Self::Model<'_>) -> Result<Self::Answer, Self::Error> {
+        Ok(powers(&games))
     }
 }
 
+pub fn part2(input: &str) -> Result<u32, ParseError> {
+    Part2::run(input)
+}
+
+/// Sum of the powers of the fewest cubes each game could have been played with.
+pub fn powers(games: &[Game]) -> u32 {
+    games.iter().map(|game| game.fewest_cubes().power()).sum()
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -73,6 +48,6 @@ mod test {
     #[case("Game 6: 3 blue, 40 red; 1 red, 2 green, 6 blue; 2 green", 480)]
     #[case("Game 7: 36 blue, 20 red; 15 red, 18 green, 6 blue; 2 green", 12960)]
     fn test_part1_possible(#[case] input: &str, #[case] expected: u32) {
-        assert_eq!(part2_game(input), expected)
+        assert_eq!(part2(input), Ok(expected))
     }
 }
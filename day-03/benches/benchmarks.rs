@@ -1,27 +1,15 @@
+use aoc_common::{bench, input, InputSource};
 use criterion::{criterion_group, criterion_main, Criterion};
-use day_03::*;
+use day_03::{generate, SOLUTIONS};
 
-fn criterion_benchmark_part1(c: &mut Criterion) {
-    let input = include_str!("../src/input.txt");
+/// Sizes passed to [`generate::generate`], see there for what they mean for this day.
+const SIZES: [usize; 4] = [35, 70, 140, 280];
 
-    let mut group = c.benchmark_group("day_03::part1");
-    group.bench_with_input("part1", input, |b, input| b.iter(|| part1::part1(input)));
+fn benchmark(c: &mut Criterion) {
+    let input = input::load(InputSource::from_env(), include_str!("../src/input.txt"));
 
-    group.finish();
+    bench::all(c, SOLUTIONS, &input, &SIZES, generate::generate);
 }
 
-fn criterion_benchmark_part2(c: &mut Criterion) {
-    let input = include_str!("../src/input.txt");
-
-    let mut group = c.benchmark_group("day_03::part2");
-    group.bench_with_input("part2", input, |b, input| b.iter(|| part2::part2(input)));
-
-    group.finish();
-}
-
-criterion_group!(
-    benches,
-    criterion_benchmark_part1,
-    criterion_benchmark_part2
-);
+criterion_group!(benches, benchmark);
 criterion_main!(benches);
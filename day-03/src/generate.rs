@@ -0,0 +1,45 @@
+use aoc_generate::{rand::Rng, rng};
+
+static SYMBOLS: &[u8] = b"*#+$/=%@&-";
+
+/// Engine schematic of `size` x `size` chars with part numbers of up to 3 digits and symbols
+/// scattered between the dots.
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = rng(seed);
+
+    (0..size)
+        .map(|_| {
+            let mut row = String::with_capacity(size);
+            while row.len() < size {
+                let left = size - row.len();
+                match rng.gen_range(0..10) {
+                    0..=1 => {
+                        let digits = rng.gen_range(1..=3).min(left);
+                        let number =
+                            rng.gen_range(10_u32.pow(digits as u32 - 1)..10_u32.pow(digits as u32));
+                        row.push_str(&number.to_string());
+                        if row.len() < size {
+                            row.push('.');
+                        }
+                    }
+                    2 => row.push(SYMBOLS[rng.gen_range(0..SYMBOLS.len())] as char),
+                    _ => row.push('.'),
+                }
+            }
+            row
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::SOLUTIONS;
+    use aoc_common::testing;
+
+    #[test]
+    fn test_generated_input_is_solved() {
+        testing::check_generated(SOLUTIONS, generate, &[(1, 0, &["0", "0"]), (40, 1, &[])]);
+    }
+}
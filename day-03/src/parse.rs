@@ -0,0 +1,87 @@
+use nom::character;
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Display},
+};
+
+pub type ParseError = aoc_common::ParseError<ParseErrorKind>;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    NumberTooLarge,
+}
+
+impl Display for ParseErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseErrorKind::NumberTooLarge => write!(f, "part number does not fit into a u32"),
+        }
+    }
+}
+
+/// Reads the part number at the start of `digits`, a slice of `input`.
+pub fn part_number(input: &str, digits: &str) -> Result<u32, ParseError> {
+    character::complete::u32::<_, ()>(digits)
+        .map(|(_, number)| number)
+        .map_err(|_| {
+            let len = digits
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(digits.len());
+            ParseError::new(input, &digits[..len], ParseErrorKind::NumberTooLarge)
+        })
+}
+
+/// Numbers and symbols of the engine schematic by position, which is 1-indexed so that looking
+/// around a symbol on the first row or column does not underflow.
+pub type Schematic = BTreeMap<(usize, usize), PointType>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PointType {
+    Symbol,
+    /// Every digit of a number points at the position of its first digit.
+    Number((usize, usize), u32),
+}
+
+/// Keeps the chars `is_symbol` holds for as symbols and leaves out the others.
+pub fn parse_schematic(input: &str, is_symbol: fn(char) -> bool) -> Result<Schematic, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .flat_map(|(row_idx, line)| {
+            let mut digit_location: Option<((usize, usize), u32)> = None;
+            line.chars()
+                .enumerate()
+                .filter_map(move |(col_idx, c)| {
+                    if c.is_ascii_digit() {
+                        if let Some((location, value)) = digit_location {
+                            Some(Ok(((row_idx, col_idx), PointType::Number(location, value))))
+                        } else {
+                            let num = match part_number(input, &line[col_idx..]) {
+                                Ok(num) => num,
+                                Err(err) => return Some(Err(err)),
+                            };
+                            digit_location = Some(((row_idx, col_idx), num));
+                            Some(Ok((
+                                (row_idx, col_idx),
+                                PointType::Number((row_idx, col_idx), num),
+                            )))
+                        }
+                    } else if is_symbol(c) {
+                        digit_location = None;
+                        Some(Ok(((row_idx, col_idx), PointType::Symbol)))
+                    } else {
+                        digit_location = None;
+                        None
+                    }
+                })
+                // 1-index the positions to prevent overflow errors when looking around symbols
+                .map(|item| {
+                    let (pos, mut point) = item?;
+                    if let PointType::Number(pos, val) = point {
+                        point = PointType::Number((pos.0 + 1, pos.1 + 1), val);
+                    }
+                    Ok(((pos.0 + 1, pos.1 + 1), point))
+                })
+        })
+        .collect()
+}
@@ -1,26 +1,14 @@
-use std::collections::{BTreeMap, HashMap};
+use crate::parse::{self, ParseError, PointType, Schematic};
+use aoc_common::Solution;
+use std::collections::HashMap;
 
-use nom::{character, AsChar, IResult};
-
-fn get_number(input: &str) -> IResult<&str, u32> {
-    let (input, number) = character::complete::u32(input)?;
-    Ok((input, number))
-}
+use nom::AsChar;
 
 fn is_symbol(c: char) -> bool {
     !c.is_alphanum() && c != '.'
 }
 
-#[derive(Debug)]
-enum PointType {
-    Symbol,
-    Number((usize, usize), u32),
-}
-
-fn numbers_near_symbol(
-    schematic: &BTreeMap<(usize, usize), PointType>,
-    symbol_location: (usize, usize),
-) -> Vec<u32> {
+fn numbers_near_symbol(schematic: &Schematic, symbol_location: (usize, usize)) -> Vec<u32> {
     let positions = [
         (symbol_location.0 - 1, symbol_location.1 - 1),
         (symbol_location.0 - 1, symbol_location.1),
@@ -50,48 +38,33 @@ fn numbers_near_symbol(
     return_value
 }
 
-pub fn part1(input: &str) -> u32 {
-    let lines = input.lines();
-    let schematic: BTreeMap<(usize, usize), PointType> = lines
-        .enumerate()
-        .flat_map(|(row_idx, line)| {
-            let mut digit_location: Option<((usize, usize), u32)> = None;
-            line.chars()
-                .enumerate()
-                .filter_map(move |(col_idx, c)| {
-                    if c.is_ascii_digit() {
-                        if let Some((location, value)) = digit_location {
-                            Some(((row_idx, col_idx), PointType::Number(location, value)))
-                        } else {
-                            let (_, num) = get_number(&line[col_idx..]).expect("no number");
-                            digit_location = Some(((row_idx, col_idx), num));
-                            Some((
-                                (row_idx, col_idx),
-                                PointType::Number((row_idx, col_idx), num),
-                            ))
-                        }
-                    } else if is_symbol(c) {
-                        digit_location = None;
-                        Some(((row_idx, col_idx), PointType::Symbol))
-                    } else {
-                        digit_location = None;
-                        None
-                    }
-                })
-                // 1-index the positions to prevent overflow errors when looking around symbols
-                .map(|(pos, mut point)| {
-                    if let PointType::Number(pos, val) = point {
-                        point = PointType::Number((pos.0 + 1, pos.1 + 1), val);
-                    }
-                    ((pos.0 + 1, pos.1 + 1), point)
-                })
-        })
-        .collect::<BTreeMap<_, _>>();
+pub struct Part1;
+
+impl Solution for Part1 {
+    const PART: u8 = 1;
+
+    type Model<'a> = Schematic;
+    type Answer = u32;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Model<'_>, Self::Error> {
+        parse::parse_schematic(input, is_symbol)
+    }
+
+    fn solve(schematic: Self::Model<'_>) -> Result<Self::Answer, Self::Error> {
+        Ok(part_number_sum(&schematic))
+    }
+}
 
+pub fn part1(input: &str) -> Result<u32, ParseError> {
+    Part1::run(input)
+}
+
+fn part_number_sum(schematic: &Schematic) -> u32 {
     schematic
         .iter()
         .flat_map(|(pos, cell)| match cell {
-            PointType::Symbol => numbers_near_symbol(&schematic, *pos),
+            PointType::Symbol => numbers_near_symbol(schematic, *pos),
             _ => vec![],
         })
         .sum::<u32>()
@@ -113,7 +86,7 @@ mod test {
 ......755.
 ...$.*....
 .664.598..";
-        assert_eq!(part1(input), 4361);
+        assert_eq!(part1(input), Ok(4361));
     }
     #[test]
     fn test_part1_custom() {
@@ -121,6 +94,17 @@ mod test {
 ............773/..891............*....................744.....805...14................../..../................320&.567..#...................
 .962..708............&........399....146.....385.................*..........825.......................................-..655....485...-.....
 ...*.........+..........................*76...+..................242....997..*......185..........207.390..870...883............*.......337..";
-        assert_eq!(part1(input), 9270);
+        assert_eq!(part1(input), Ok(9270));
+    }
+
+    #[test]
+    fn test_part1_number_too_large() {
+        let input = "467..114..
+...*......
+..99999999999.";
+        let err = part1(input).unwrap_err();
+
+        assert_eq!((err.line, err.column), (3, 3));
+        assert_eq!(err.text, "99999999999");
     }
 }
@@ -1,20 +1,12 @@
-use std::collections::{BTreeMap, HashMap};
-
-use nom::{character, IResult};
-
-fn get_number(input: &str) -> IResult<&str, u32> {
-    let (input, number) = character::complete::u32(input)?;
-    Ok((input, number))
-}
+use crate::parse::{self, ParseError, PointType, Schematic};
+use aoc_common::Solution;
+use std::collections::HashMap;
 
 fn is_symbol(c: char) -> bool {
     c == '*'
 }
 
-fn get_gear_ratio(
-    schematic: &BTreeMap<(usize, usize), PointType>,
-    symbol_location: (usize, usize),
-) -> Option<u64> {
+fn get_gear_ratio(schematic: &Schematic, symbol_location: (usize, usize)) -> Option<u64> {
     let gear_ratio_req_count = 2;
     let positions = [
         (symbol_location.0 - 1, symbol_location.1 - 1),
@@ -49,53 +41,33 @@ fn get_gear_ratio(
     }
 }
 
-enum PointType {
-    Symbol,
-    Number((usize, usize), u32),
+pub struct Part2;
+
+impl Solution for Part2 {
+    const PART: u8 = 2;
+
+    type Model<'a> = Schematic;
+    type Answer = u64;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Model<'_>, Self::Error> {
+        parse::parse_schematic(input, is_symbol)
+    }
+
+    fn solve(schematic: Self::Model<'_>) -> Result<Self::Answer, Self::Error> {
+        Ok(gear_ratio_sum(&schematic))
+    }
 }
 
-pub fn part2(input: &str) -> u64 {
-    let lines = input.lines();
-    let schematic: BTreeMap<(usize, usize), PointType> = lines
-        .enumerate()
-        .flat_map(|(row_idx, line)| {
-            let mut digit_location: Option<((usize, usize), u32)> = None;
-            line.chars()
-                .enumerate()
-                .filter_map(move |(col_idx, c)| {
-                    if c.is_ascii_digit() {
-                        if let Some((location, value)) = digit_location {
-                            Some(((row_idx, col_idx), PointType::Number(location, value)))
-                        } else {
-                            let (_, num) = get_number(&line[col_idx..]).expect("no number");
-                            digit_location = Some(((row_idx, col_idx), num));
-                            Some((
-                                (row_idx, col_idx),
-                                PointType::Number((row_idx, col_idx), num),
-                            ))
-                        }
-                    } else if is_symbol(c) {
-                        digit_location = None;
-                        Some(((row_idx, col_idx), PointType::Symbol))
-                    } else {
-                        digit_location = None;
-                        None
-                    }
-                })
-                // 1-index the positions to prevent overflow errors when looking around symbols
-                .map(|(pos, mut point)| {
-                    if let PointType::Number(pos, val) = point {
-                        point = PointType::Number((pos.0 + 1, pos.1 + 1), val);
-                    }
-                    ((pos.0 + 1, pos.1 + 1), point)
-                })
-        })
-        .collect::<BTreeMap<_, _>>();
+pub fn part2(input: &str) -> Result<u64, ParseError> {
+    Part2::run(input)
+}
 
+fn gear_ratio_sum(schematic: &Schematic) -> u64 {
     schematic
         .iter()
         .filter_map(|(pos, cell)| match cell {
-            PointType::Symbol => get_gear_ratio(&schematic, *pos),
+            PointType::Symbol => get_gear_ratio(schematic, *pos),
             _ => None,
         })
         .sum::<u64>()
@@ -117,6 +89,6 @@ mod test {
 ......755.
 ...$.*....
 .664.598..";
-        assert_eq!(part2(input), 467835);
+        assert_eq!(part2(input), Ok(467835));
     }
 }
@@ -0,0 +1,65 @@
+use aoc_generate::{
+    rand::{
+        seq::{index, SliceRandom},
+        Rng,
+    },
+    rng,
+};
+
+const WINNING_NUMBERS: usize = 10;
+const NUMBERS: usize = 25;
+
+/// `size` scratchcards with 10 winning numbers and 25 numbers you have, like the puzzle input.
+///
+/// Most cards have no matches, so that the number of copies won in part 2 grows linearly with
+/// `size` instead of overflowing.
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = rng(seed);
+    let id_width = size.to_string().len();
+
+    (1..=size)
+        .map(|id| {
+            let matches = if rng.gen_bool(0.7) {
+                0
+            } else {
+                rng.gen_range(1..=4).min(size - id)
+            };
+            let numbers = index::sample(&mut rng, 99, WINNING_NUMBERS + NUMBERS - matches)
+                .into_iter()
+                .map(|n| n + 1)
+                .collect::<Vec<_>>();
+            let (winning, rest) = numbers.split_at(WINNING_NUMBERS);
+            let mut have = winning[..matches].to_vec();
+            have.extend(rest);
+            have.shuffle(&mut rng);
+
+            let format = |numbers: &[usize]| {
+                numbers
+                    .iter()
+                    .map(|n| format!("{:>2}", n))
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            };
+            format!(
+                "Card {:>width$}: {} | {}",
+                id,
+                format(winning),
+                format(&have),
+                width = id_width
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::SOLUTIONS;
+    use aoc_common::testing;
+
+    #[test]
+    fn test_generated_input_is_solved() {
+        testing::check_generated(SOLUTIONS, generate, &[(1, 0, &["0", "1"]), (200, 1, &[])]);
+    }
+}
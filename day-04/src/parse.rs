@@ -0,0 +1,66 @@
+use std::{
+    collections::HashSet,
+    fmt::{self, Display},
+    num::ParseIntError,
+};
+
+pub type ParseError = aoc_common::ParseError<ParseErrorKind>;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    MissingCardId,
+    MissingSeparator,
+    InvalidNumber(ParseIntError),
+}
+
+impl Display for ParseErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseErrorKind::MissingCardId => {
+                write!(f, "expected a line starting with `Card <id>: `")
+            }
+            ParseErrorKind::MissingSeparator => write!(f, "expected numbers separated by ` | `"),
+            ParseErrorKind::InvalidNumber(err) => write!(f, "invalid number ({})", err),
+        }
+    }
+}
+
+/// Winning numbers of a scratchcard along with the numbers on it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Card {
+    pub winning_numbers: HashSet<u32>,
+    pub my_numbers: HashSet<u32>,
+}
+
+impl Card {
+    /// Counts how many of the numbers on the card are winning numbers.
+    pub fn matching_numbers(&self) -> u32 {
+        self.winning_numbers.intersection(&self.my_numbers).count() as u32
+    }
+}
+
+/// One card per line, in order.
+pub fn parse_cards(input: &str) -> Result<Vec<Card>, ParseError> {
+    input.lines().map(|line| parse_card(input, line)).collect()
+}
+
+/// Parses the `card` line of `input`.
+pub fn parse_card(input: &str, card: &str) -> Result<Card, ParseError> {
+    let (_, card_numbers) = card
+        .split_once(": ")
+        .ok_or_else(|| ParseError::new(input, card, ParseErrorKind::MissingCardId))?;
+    let (winning_numbers_str, my_numbers_str) = card_numbers
+        .split_once(" | ")
+        .ok_or_else(|| ParseError::new(input, card_numbers, ParseErrorKind::MissingSeparator))?;
+    let numbers = |numbers_str: &str| {
+        numbers_str
+            .split_whitespace()
+            .map(|a| ParseError::try_parse::<u32>(input, a, ParseErrorKind::InvalidNumber))
+            .collect::<Result<HashSet<_>, _>>()
+    };
+
+    Ok(Card {
+        winning_numbers: numbers(winning_numbers_str)?,
+        my_numbers: numbers(my_numbers_str)?,
+    })
+}
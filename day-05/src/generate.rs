@@ -0,0 +1,76 @@
+use aoc_generate::{
+    rand::{seq::SliceRandom, Rng},
+    rng,
+};
+
+static MAPS: [&str; 7] = [
+    "seed-to-soil",
+    "soil-to-fertilizer",
+    "fertilizer-to-water",
+    "water-to-light",
+    "light-to-temperature",
+    "temperature-to-humidity",
+    "humidity-to-location",
+];
+const SEED_RANGES: usize = 10;
+const MAX_ID: u64 = 1 << 32;
+
+/// Almanac with 10 seed ranges and `size` ranges per map. Each map shuffles the ranges it covers
+/// around, so every id is mapped to exactly one other id.
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = rng(seed);
+
+    let seeds = (0..SEED_RANGES)
+        .map(|_| {
+            let start = rng.gen_range(0..MAX_ID - 1);
+            let len = rng.gen_range(1..=(MAX_ID - start).min(MAX_ID / 16));
+            format!("{} {}", start, len)
+        })
+        .collect::<Vec<_>>();
+
+    let maps = MAPS.iter().map(|name| {
+        let mut bounds = (0..size.max(1) - 1)
+            .map(|_| rng.gen_range(1..MAX_ID))
+            .collect::<Vec<_>>();
+        bounds.extend([0, MAX_ID]);
+        bounds.sort_unstable();
+        bounds.dedup();
+        let mut sources = bounds
+            .windows(2)
+            .map(|pair| (pair[0], pair[1] - pair[0]))
+            .collect::<Vec<_>>();
+        sources.shuffle(&mut rng);
+
+        let mut dest = 0;
+        let ranges = sources
+            .iter()
+            .map(|&(source, len)| {
+                let range = format!("{} {} {}", dest, source, len);
+                dest += len;
+                range
+            })
+            .collect::<Vec<_>>();
+        format!("{} map:\n{}", name, ranges.join("\n"))
+    });
+
+    std::iter::once(format!("seeds: {}", seeds.join(" ")))
+        .chain(maps)
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::SOLUTIONS;
+    use aoc_common::testing;
+
+    #[test]
+    fn test_generated_input_is_solved() {
+        testing::check_generated(
+            SOLUTIONS,
+            generate,
+            &[(1, 0, &["2204495", "111001858"]), (50, 1, &[])],
+        );
+    }
+}
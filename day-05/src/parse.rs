@@ -0,0 +1,100 @@
+use std::{
+    fmt::{self, Display},
+    num::ParseIntError,
+};
+
+pub type ParseError = aoc_common::ParseError<ParseErrorKind>;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    MissingHeader,
+    UnknownSection,
+    InvalidNumber(ParseIntError),
+    InvalidMapping,
+}
+
+impl Display for ParseErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseErrorKind::MissingHeader => {
+                write!(f, "expected a section starting with `<name>:`")
+            }
+            ParseErrorKind::UnknownSection => write!(f, "unknown almanac section"),
+            ParseErrorKind::InvalidNumber(err) => write!(f, "invalid number ({})", err),
+            ParseErrorKind::InvalidMapping => {
+                write!(f, "expected a mapping as `<destination> <source> <length>`")
+            }
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Almanac {
+    /// Seed numbers, read as pairs of start and length by part 2.
+    pub seeds: Vec<u64>,
+    /// One layer of mappings per section, from seed to location.
+    pub mappings: Vec<Vec<Mapping>>,
+}
+
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct Mapping {
+    pub start_source: u64,
+    pub start_dest: u64,
+    pub range: u64,
+}
+
+/// Parses whitespace separated numbers from `numbers`, a slice of `input`.
+pub fn parse_numbers(input: &str, numbers: &str) -> Result<Vec<u64>, ParseError> {
+    numbers
+        .split_whitespace()
+        .map(|n| ParseError::try_parse(input, n, ParseErrorKind::InvalidNumber))
+        .collect()
+}
+
+pub fn parse_mappings(input: &str, lines: &str) -> Result<Vec<Mapping>, ParseError> {
+    lines
+        .trim()
+        .lines()
+        .map(|line| match parse_numbers(input, line)?[..] {
+            [start_dest, start_source, range] => Ok(Mapping {
+                start_source,
+                start_dest,
+                range,
+            }),
+            _ => Err(ParseError::new(input, line, ParseErrorKind::InvalidMapping)),
+        })
+        .collect()
+}
+
+pub fn parse_almanac(input: &str) -> Result<Almanac, ParseError> {
+    let mut almanac = Almanac::default();
+
+    for group in input.split("\n\n") {
+        let (group_name, numbers) = group
+            .split_once(':')
+            .ok_or_else(|| ParseError::new(input, group, ParseErrorKind::MissingHeader))?;
+        match group_name {
+            "seeds" => {
+                almanac.seeds = parse_numbers(input, numbers)?;
+            }
+            "seed-to-soil map"
+            | "soil-to-fertilizer map"
+            | "fertilizer-to-water map"
+            | "water-to-light map"
+            | "light-to-temperature map"
+            | "temperature-to-humidity map"
+            | "humidity-to-location map" => {
+                almanac.mappings.push(parse_mappings(input, numbers)?);
+            }
+            _ => {
+                return Err(ParseError::new(
+                    input,
+                    group_name,
+                    ParseErrorKind::UnknownSection,
+                ))
+            }
+        }
+    }
+
+    Ok(almanac)
+}
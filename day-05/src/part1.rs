@@ -1,7 +1,30 @@
-pub fn part1(input: &str) -> u64 {
-    let almanac = parse_input(input);
+use crate::parse::{self, Almanac, ParseError};
+use aoc_common::Solution;
 
-    let mut val = almanac.initial_seeds;
+pub struct Part1;
+
+impl Solution for Part1 {
+    const PART: u8 = 1;
+
+    type Model<'a> = Almanac;
+    type Answer = u64;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Model<'_>, Self::Error> {
+        parse::parse_almanac(input)
+    }
+
+    fn solve(almanac: Self::Model<'_>) -> Result<Self::Answer, Self::Error> {
+        Ok(lowest_location(almanac))
+    }
+}
+
+pub fn part1(input: &str) -> Result<u64, ParseError> {
+    Part1::run(input)
+}
+
+fn lowest_location(almanac: Almanac) -> u64 {
+    let mut val = almanac.seeds;
     for mapping_layer in almanac.mappings {
         val = val
             .iter()
@@ -11,88 +34,17 @@ pub fn part1(input: &str) -> u64 {
                         return *v - map.start_source + map.start_dest;
                     }
                 }
-                return *v;
+                *v
             })
             .collect::<Vec<_>>()
     }
     *val.iter().min().unwrap()
 }
 
-#[derive(Debug, Default, Copy, Clone)]
-struct Mapping {
-    start_source: u64,
-    start_dest: u64,
-    range: u64,
-}
-
-#[derive(Debug, Default, Clone)]
-struct Almanac {
-    initial_seeds: Vec<u64>,
-    mappings: Vec<Vec<Mapping>>,
-}
-
-fn parse_mappings(lines: &str) -> Vec<Mapping> {
-    let line_mappings = lines.trim().lines();
-    let mappings = line_mappings
-        .map(|line| {
-            let mut numbers_for_mapping = line.split_whitespace().map(|n| {
-                n.parse::<u64>()
-                    .expect("value in numbers array was not a number")
-            });
-            Mapping {
-                start_dest: numbers_for_mapping
-                    .next()
-                    .expect("Number not found for mapping"),
-                start_source: numbers_for_mapping
-                    .next()
-                    .expect("Number not found for mapping"),
-                range: numbers_for_mapping
-                    .next()
-                    .expect("Number not found for mapping"),
-            }
-        })
-        .collect::<Vec<_>>();
-    mappings
-}
-fn parse_input(input: &str) -> Almanac {
-    let groupings = input.split("\n\n").collect::<Vec<_>>();
-
-    let mut almanac = Almanac::default();
-
-    for group in groupings {
-        let (group_name, numbers) = group
-            .split_once(":")
-            .expect("grouping does not have a : delimiter");
-        match group_name {
-            "seeds" => {
-                almanac.initial_seeds = numbers
-                    .trim()
-                    .split_whitespace()
-                    .map(|n| {
-                        n.parse::<u64>()
-                            .expect("value in numbers array was not a number")
-                    })
-                    .collect::<Vec<_>>();
-            }
-            "seed-to-soil map"
-            | "soil-to-fertilizer map"
-            | "fertilizer-to-water map"
-            | "water-to-light map"
-            | "light-to-temperature map"
-            | "temperature-to-humidity map"
-            | "humidity-to-location map" => {
-                almanac.mappings.push(parse_mappings(numbers));
-            }
-            _ => panic!("unknown grouping found"),
-        }
-    }
-
-    almanac
-}
-
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::parse::ParseErrorKind;
 
     #[test]
     fn test_part1() {
@@ -130,6 +82,19 @@ humidity-to-location map:
 60 56 37
 56 93 4";
 
-        assert_eq!(part1(input), 35);
+        assert_eq!(part1(input), Ok(35));
+    }
+
+    #[test]
+    fn test_part1_invalid_mapping() {
+        let input = "seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50";
+        let err = part1(input).unwrap_err();
+
+        assert_eq!(err.kind, ParseErrorKind::InvalidMapping);
+        assert_eq!((err.line, err.column), (5, 1));
     }
 }
@@ -1,13 +1,36 @@
+use crate::parse::{self, Almanac, ParseError};
+use aoc_common::Solution;
 use std::ops::RangeInclusive;
 
 use range_set::RangeSet;
 
-pub fn part2(input: &str) -> u64 {
-    let almanac = parse_input(input);
+pub struct Part2;
+
+impl Solution for Part2 {
+    const PART: u8 = 2;
+
+    type Model<'a> = Almanac;
+    type Answer = u64;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Model<'_>, Self::Error> {
+        parse::parse_almanac(input)
+    }
+
+    fn solve(almanac: Self::Model<'_>) -> Result<Self::Answer, Self::Error> {
+        Ok(lowest_location(almanac))
+    }
+}
+
+pub fn part2(input: &str) -> Result<u64, ParseError> {
+    Part2::run(input)
+}
+
+fn lowest_location(almanac: Almanac) -> u64 {
     let ranges = almanac
-        .initial_seeds
-        .iter()
-        .map(|seeds| seeds.start_seed..=(seeds.start_seed + seeds.range - 1))
+        .seeds
+        .chunks_exact(2)
+        .map(|seeds| seeds[0]..=(seeds[0] + seeds[1] - 1))
         .collect::<Vec<_>>();
 
     let mut val = RangeSet::<[RangeInclusive<u64>; 4096]>::new();
@@ -42,107 +65,6 @@ pub fn part2(input: &str) -> u64 {
     val.min().unwrap()
 }
 
-#[derive(Debug, Default, Copy, Clone)]
-struct Mapping {
-    start_source: u64,
-    start_dest: u64,
-    range: u64,
-}
-
-#[derive(Debug, Default, Copy, Clone)]
-struct SeedRange {
-    start_seed: u64,
-    range: u64,
-}
-
-#[derive(Debug, Default, Clone)]
-struct Almanac {
-    initial_seeds: Vec<SeedRange>,
-    mappings: Vec<Vec<Mapping>>,
-}
-
-fn parse_mappings(lines: &str) -> Vec<Mapping> {
-    let line_mappings = lines.trim().lines();
-    let mappings = line_mappings
-        .map(|line| {
-            let mut numbers_for_mapping = line.split_whitespace().map(|n| {
-                n.parse::<u64>()
-                    .expect("value in numbers array was not a number")
-            });
-            Mapping {
-                start_dest: numbers_for_mapping
-                    .next()
-                    .expect("Number not found for mapping"),
-                start_source: numbers_for_mapping
-                    .next()
-                    .expect("Number not found for mapping"),
-                range: numbers_for_mapping
-                    .next()
-                    .expect("Number not found for mapping"),
-            }
-        })
-        .collect::<Vec<_>>();
-    mappings
-}
-fn parse_input(input: &str) -> Almanac {
-    let groupings = input.split("\n\n").collect::<Vec<_>>();
-
-    let mut almanac = Almanac::default();
-
-    for group in groupings {
-        let (group_name, numbers) = group
-            .split_once(":")
-            .expect("grouping does not have a : delimiter");
-        match group_name {
-            "seeds" => {
-                let parsed_numbers = numbers
-                    .trim()
-                    .split_whitespace()
-                    .map(|n| {
-                        n.parse::<u64>()
-                            .expect("value in numbers array was not a number")
-                    })
-                    .collect::<Vec<_>>();
-                let ranges = parsed_numbers.iter().enumerate().filter_map(|(idx, n)| {
-                    if idx % 2 == 0 {
-                        None
-                    } else {
-                        Some(n)
-                    }
-                });
-
-                let start_seed = parsed_numbers.iter().enumerate().filter_map(|(idx, n)| {
-                    if idx % 2 == 0 {
-                        Some(n)
-                    } else {
-                        None
-                    }
-                });
-
-                almanac.initial_seeds = start_seed
-                    .zip(ranges)
-                    .map(|(start, range)| SeedRange {
-                        start_seed: *start,
-                        range: *range,
-                    })
-                    .collect::<Vec<_>>()
-            }
-            "seed-to-soil map"
-            | "soil-to-fertilizer map"
-            | "fertilizer-to-water map"
-            | "water-to-light map"
-            | "light-to-temperature map"
-            | "temperature-to-humidity map"
-            | "humidity-to-location map" => {
-                almanac.mappings.push(parse_mappings(numbers));
-            }
-            _ => panic!("unknown grouping found"),
-        }
-    }
-
-    almanac
-}
-
 #[cfg(test)]
 mod test {
     use super::*;
@@ -183,6 +105,6 @@ humidity-to-location map:
 60 56 37
 56 93 4";
 
-        assert_eq!(part2(input), 46);
+        assert_eq!(part2(input), Ok(46));
     }
 }
@@ -0,0 +1,46 @@
+use aoc_generate::{rand::Rng, rng};
+
+/// `size` races, at most 4 so that the single race of part 2 still fits in a `u64`.
+///
+/// Times have 2 digits and distances 3, which keeps every race winnable, including the long one.
+pub fn generate(size: usize, seed: u64) -> String {
+    assert!(
+        (1..=4).contains(&size),
+        "part 2 only fits 1 to 4 races, not {}",
+        size
+    );
+    let mut rng = rng(seed);
+
+    let races = (0..size)
+        .map(|_| {
+            let time = rng.gen_range(40..100_u32);
+            let record = rng.gen_range(100..(time * time / 4).min(1000));
+            (time, record)
+        })
+        .collect::<Vec<_>>();
+    let row = |name: &str, numbers: Vec<String>| format!("{:<9}{}", name, numbers.join("   "));
+
+    format!(
+        "{}\n{}",
+        row(
+            "Time:",
+            races.iter().map(|race| format!("{:>4}", race.0)).collect()
+        ),
+        row(
+            "Distance:",
+            races.iter().map(|race| format!("{:>4}", race.1)).collect()
+        )
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::SOLUTIONS;
+    use aoc_common::testing;
+
+    #[test]
+    fn test_generated_input_is_solved() {
+        testing::check_generated(SOLUTIONS, generate, &[(1, 0, &["69", "69"]), (4, 1, &[])]);
+    }
+}
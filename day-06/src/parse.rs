@@ -0,0 +1,41 @@
+use std::{
+    fmt::{self, Display},
+    num::ParseIntError,
+};
+
+pub type ParseError = aoc_common::ParseError<ParseErrorKind>;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    MissingLine,
+    MissingLabel(&'static str),
+    InvalidNumber(ParseIntError),
+}
+
+impl Display for ParseErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseErrorKind::MissingLine => write!(f, "expected a `Time:` and a `Distance:` line"),
+            ParseErrorKind::MissingLabel(label) => {
+                write!(f, "expected a line starting with `{}`", label)
+            }
+            ParseErrorKind::InvalidNumber(err) => write!(f, "invalid number ({})", err),
+        }
+    }
+}
+
+/// Splits the input into the numbers following `Time:` and the ones following `Distance:`.
+pub fn parse_records(input: &str) -> Result<(&str, &str), ParseError> {
+    let (times, distances) = input.split_once('\n').ok_or_else(|| {
+        ParseError::new(input, &input[input.len()..], ParseErrorKind::MissingLine)
+    })?;
+    Ok((
+        strip_label(input, times, "Time:")?,
+        strip_label(input, distances, "Distance:")?,
+    ))
+}
+
+fn strip_label<'a>(input: &str, line: &'a str, label: &'static str) -> Result<&'a str, ParseError> {
+    line.strip_prefix(label)
+        .ok_or_else(|| ParseError::new(input, line, ParseErrorKind::MissingLabel(label)))
+}
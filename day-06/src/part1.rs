@@ -1,25 +1,47 @@
-pub fn part1(input: &str) -> u32 {
-    let (times, distances) = input.split_once("\n").expect("could not parse correctly");
-    let times = times
-        .strip_prefix("Time:")
-        .unwrap()
-        .trim()
-        .split_whitespace()
-        .map(|t| t.parse::<u32>().expect("time did not parse"));
-    let distances = distances
-        .strip_prefix("Distance:")
-        .unwrap()
-        .trim()
-        .split_whitespace()
-        .map(|d| d.parse::<u32>().expect("distance did not parse"));
-
-    times
-        .zip(distances)
-        .map(|(t, d)| {
-            let lower_bound = find_lowest_time_possible(0, t / 2 + 1, t, d);
-            t - lower_bound * 2 + 1
-        })
-        .product()
+use crate::parse::{self, ParseError, ParseErrorKind};
+use aoc_common::Solution;
+
+pub struct Part1;
+
+impl Solution for Part1 {
+    const PART: u8 = 1;
+
+    type Model<'a> = Vec<(u32, u32)>;
+    type Answer = u32;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Model<'_>, Self::Error> {
+        parse_races(input)
+    }
+
+    fn solve(races: Self::Model<'_>) -> Result<Self::Answer, Self::Error> {
+        Ok(races
+            .into_iter()
+            .map(|(t, d)| {
+                let lower_bound = find_lowest_time_possible(0, t / 2 + 1, t, d);
+                t - lower_bound * 2 + 1
+            })
+            .product())
+    }
+}
+
+pub fn part1(input: &str) -> Result<u32, ParseError> {
+    Part1::run(input)
+}
+
+/// Time and record distance of every race.
+fn parse_races(input: &str) -> Result<Vec<(u32, u32)>, ParseError> {
+    let (times, distances) = parse::parse_records(input)?;
+    let parse_numbers = |numbers: &str| {
+        numbers
+            .split_whitespace()
+            .map(|n| ParseError::try_parse::<u32>(input, n, ParseErrorKind::InvalidNumber))
+            .collect::<Result<Vec<_>, _>>()
+    };
+    let times = parse_numbers(times)?;
+    let distances = parse_numbers(distances)?;
+
+    Ok(times.into_iter().zip(distances).collect())
 }
 
 fn find_lowest_time_possible(t_lower: u32, t_upper: u32, total_time: u32, distance: u32) -> u32 {
@@ -44,6 +66,16 @@ mod test {
     fn test_part1() {
         let input = "Time:      7  15   30
 Distance:  9  40  200";
-        assert_eq!(part1(input), 288);
+        assert_eq!(part1(input), Ok(288));
+    }
+
+    #[test]
+    fn test_part1_missing_label() {
+        let input = "Time:      7  15   30
+Distanc:  9  40  200";
+        let err = part1(input).unwrap_err();
+
+        assert_eq!(err.kind, ParseErrorKind::MissingLabel("Distance:"));
+        assert_eq!((err.line, err.column), (2, 1));
     }
 }
@@ -1,24 +1,44 @@
-pub fn part2(input: &str) -> u64 {
-    let (times, distances) = input.split_once("\n").expect("could not parse correctly");
-    let time = times
-        .strip_prefix("Time:")
-        .unwrap()
-        .trim()
-        .split_whitespace()
-        .collect::<String>()
-        .parse::<u64>()
-        .expect("time did not parse");
-    let distance = distances
-        .strip_prefix("Distance:")
-        .unwrap()
-        .trim()
-        .split_whitespace()
-        .collect::<String>()
-        .parse::<u64>()
-        .expect("distance did not parse");
-
-    let lower_bound = find_lowest_time_possible(0, time / 2 + 1, time, distance);
-    time - lower_bound * 2 + 1
+use crate::parse::{self, ParseError, ParseErrorKind};
+use aoc_common::Solution;
+
+pub struct Part2;
+
+impl Solution for Part2 {
+    const PART: u8 = 2;
+
+    type Model<'a> = (u64, u64);
+    type Answer = u64;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Model<'_>, Self::Error> {
+        parse_race(input)
+    }
+
+    fn solve((time, distance): Self::Model<'_>) -> Result<Self::Answer, Self::Error> {
+        let lower_bound = find_lowest_time_possible(0, time / 2 + 1, time, distance);
+        Ok(time - lower_bound * 2 + 1)
+    }
+}
+
+pub fn part2(input: &str) -> Result<u64, ParseError> {
+    Part2::run(input)
+}
+
+/// Time and record distance of the one race, as the numbers are kerned so all digits on a line make
+/// up a single number.
+fn parse_race(input: &str) -> Result<(u64, u64), ParseError> {
+    let (times, distances) = parse::parse_records(input)?;
+    let parse_number = |numbers: &str| {
+        numbers
+            .split_whitespace()
+            .collect::<String>()
+            .parse::<u64>()
+            .map_err(|err| {
+                ParseError::new(input, numbers.trim(), ParseErrorKind::InvalidNumber(err))
+            })
+    };
+
+    Ok((parse_number(times)?, parse_number(distances)?))
 }
 
 fn find_lowest_time_possible(t_lower: u64, t_upper: u64, total_time: u64, distance: u64) -> u64 {
@@ -43,6 +63,6 @@ mod test {
     fn test_part2() {
         let input = "Time:      7  15   30
 Distance:  9  40  200";
-        assert_eq!(part2(input), 71503);
+        assert_eq!(part2(input), Ok(71503));
     }
 }
@@ -0,0 +1,34 @@
+use aoc_generate::{rand::Rng, rng};
+
+static CARDS: &[u8] = b"23456789TJQKA";
+
+/// `size` hands of 5 cards, each with a bid of up to 1000.
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = rng(seed);
+
+    (0..size)
+        .map(|_| {
+            let hand = (0..5)
+                .map(|_| CARDS[rng.gen_range(0..CARDS.len())] as char)
+                .collect::<String>();
+            format!("{} {}", hand, rng.gen_range(1..=1000))
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::SOLUTIONS;
+    use aoc_common::testing;
+
+    #[test]
+    fn test_generated_input_is_solved() {
+        testing::check_generated(
+            SOLUTIONS,
+            generate,
+            &[(1, 0, &["123", "123"]), (500, 1, &[])],
+        );
+    }
+}
@@ -1,2 +1,6 @@
+pub mod generate;
+pub mod parse;
 pub mod part1;
 pub mod part2;
+
+aoc_common::solutions!(7 => part1::Part1, part2::Part2);
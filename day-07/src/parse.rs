@@ -0,0 +1,27 @@
+use std::{
+    fmt::{self, Display},
+    num::ParseIntError,
+};
+
+pub type ParseError = aoc_common::ParseError<ParseErrorKind>;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    MissingBid,
+    UnknownCard,
+    WrongHandSize(usize),
+    InvalidNumber(ParseIntError),
+}
+
+impl Display for ParseErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseErrorKind::MissingBid => write!(f, "expected a hand followed by its bid"),
+            ParseErrorKind::UnknownCard => write!(f, "unknown card"),
+            ParseErrorKind::WrongHandSize(size) => {
+                write!(f, "expected a hand of 5 cards but found {}", size)
+            }
+            ParseErrorKind::InvalidNumber(err) => write!(f, "invalid number ({})", err),
+        }
+    }
+}
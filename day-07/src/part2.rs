@@ -1,18 +1,35 @@
+use crate::parse::{ParseError, ParseErrorKind};
+use aoc_common::Solution;
 use itertools::Itertools;
 use num_enum::IntoPrimitive;
 
-pub fn part2(input: &str) -> u32 {
-    let mut ranks = input
-        .lines()
-        .map(|line| parse_line(line).get_rank())
-        .collect::<Vec<_>>();
-    ranks.sort();
-
-    ranks
-        .iter()
-        .enumerate()
-        .map(|(idx, rank)| rank.bid * (idx + 1) as u32)
-        .sum()
+pub struct Part2;
+
+impl Solution for Part2 {
+    const PART: u8 = 2;
+
+    type Model<'a> = Vec<Hand>;
+    type Answer = u32;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Model<'_>, Self::Error> {
+        input.lines().map(|line| parse_line(input, line)).collect()
+    }
+
+    fn solve(hands: Self::Model<'_>) -> Result<Self::Answer, Self::Error> {
+        let mut ranks = hands.into_iter().map(Hand::get_rank).collect::<Vec<_>>();
+        ranks.sort();
+
+        Ok(ranks
+            .iter()
+            .enumerate()
+            .map(|(idx, rank)| rank.bid * (idx + 1) as u32)
+            .sum())
+    }
+}
+
+pub fn part2(input: &str) -> Result<u32, ParseError> {
+    Part2::run(input)
 }
 
 #[derive(Debug, Clone, Copy, Hash, IntoPrimitive, PartialEq, Eq, PartialOrd, Ord)]
@@ -56,7 +73,7 @@ impl TryFrom<char> for Card {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
-struct Hand {
+pub struct Hand {
     cards: Vec<Card>,
     bid: u32,
 }
@@ -64,7 +81,7 @@ struct Hand {
 static COUNT_COMBOS: [&'static [usize]; 6] = [&[5], &[4], &[3, 2], &[3], &[2, 2], &[2]];
 
 impl Hand {
-    pub fn get_rank(self) -> HandRank {
+    fn get_rank(self) -> HandRank {
         let mut card_count_map = self.cards.iter().counts();
         let number_of_jacks = card_count_map.remove(&Card::Jack).unwrap_or(0);
 
@@ -137,17 +154,31 @@ impl PartialOrd for HandRank {
     }
 }
 
-fn parse_line(line: &str) -> Hand {
-    let (hand, bid_str) = line.split_once(' ').expect("Line did not parse");
+fn parse_line(input: &str, line: &str) -> Result<Hand, ParseError> {
+    let (hand, bid_str) = line
+        .split_once(' ')
+        .ok_or_else(|| ParseError::new(input, line, ParseErrorKind::MissingBid))?;
     let cards = hand
-        .chars()
-        .map(|card| Card::try_from(card).expect("Did not parse character"))
-        .collect::<Vec<_>>();
-    let bid_amt = bid_str.parse::<u32>().expect("Did not parse bid amount");
-    Hand {
+        .char_indices()
+        .map(|(idx, card)| {
+            Card::try_from(card).map_err(|_| {
+                let card = &hand[idx..idx + card.len_utf8()];
+                ParseError::new(input, card, ParseErrorKind::UnknownCard)
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    if cards.len() != 5 {
+        return Err(ParseError::new(
+            input,
+            hand,
+            ParseErrorKind::WrongHandSize(cards.len()),
+        ));
+    }
+    let bid_amt = ParseError::try_parse::<u32>(input, bid_str, ParseErrorKind::InvalidNumber)?;
+    Ok(Hand {
         cards,
         bid: bid_amt,
-    }
+    })
 }
 
 #[cfg(test)]
@@ -162,6 +193,6 @@ KK677 28
 KTJJT 220
 QQQJA 483
 ";
-        assert_eq!(part2(input), 5905);
+        assert_eq!(part2(input), Ok(5905));
     }
 }
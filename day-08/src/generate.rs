@@ -0,0 +1,65 @@
+use aoc_generate::{
+    rand::{seq::SliceRandom, Rng},
+    rng, unique_names,
+};
+
+const GHOSTS: usize = 6;
+/// Names of the nodes in between must not end with `A` or `Z`.
+const INNER: &str = "BCDEFGHIJKLMNOPQRSTUVWXY";
+
+/// Network of 6 loops, one per ghost, with up to `size` nodes each. The loop of the first ghost
+/// leads from `AAA` to `ZZZ`.
+///
+/// Every loop goes from its `..A` node to its `..Z` node and then back to the node after `..A`,
+/// so the ghosts meet again after a multiple of the loop lengths like in the puzzle input.
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = rng(seed);
+    let directions = (0..rng.gen_range(size.max(2)..=size.max(2) * 2))
+        .map(|_| if rng.gen_bool(0.5) { 'L' } else { 'R' })
+        .collect::<String>();
+
+    let lengths = (0..GHOSTS)
+        .map(|_| rng.gen_range(size.div_ceil(2).max(1)..=size.max(1)))
+        .collect::<Vec<_>>();
+    let mut names = unique_names(&mut rng, lengths.iter().sum(), 3, INNER);
+    let prefixes = unique_names(&mut rng, GHOSTS - 1, 2, INNER);
+
+    let mut nodes = vec![];
+    for (ghost, &len) in lengths.iter().enumerate() {
+        let (start, end) = match ghost {
+            0 => ("AAA".to_string(), "ZZZ".to_string()),
+            _ => {
+                let prefix = &prefixes[ghost - 1];
+                (format!("{}A", prefix), format!("{}Z", prefix))
+            }
+        };
+        let inner = names.split_off(names.len() - len);
+        let first = inner.first().unwrap_or(&end).clone();
+        let path = inner.iter().chain([&end]).collect::<Vec<_>>();
+
+        nodes.push((start, first.clone()));
+        for pair in path.windows(2) {
+            nodes.push((pair[0].clone(), pair[1].clone()));
+        }
+        nodes.push((end, first));
+    }
+
+    nodes.shuffle(&mut rng);
+    let nodes = nodes
+        .iter()
+        .map(|(node, next)| format!("{} = ({}, {})", node, next, next))
+        .collect::<Vec<_>>();
+    format!("{}\n\n{}", directions, nodes.join("\n"))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::SOLUTIONS;
+    use aoc_common::testing;
+
+    #[test]
+    fn test_generated_input_is_solved() {
+        testing::check_generated(SOLUTIONS, generate, &[(1, 0, &["2", "2"]), (100, 1, &[])]);
+    }
+}
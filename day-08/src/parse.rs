@@ -0,0 +1,31 @@
+use std::fmt::{self, Display};
+
+pub type ParseError = aoc_common::ParseError<ParseErrorKind>;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    MissingNodes,
+    UnknownDirection,
+    InvalidNode,
+    UnknownNode,
+    MissingNode(&'static str),
+}
+
+impl Display for ParseErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseErrorKind::MissingNodes => {
+                write!(
+                    f,
+                    "expected the nodes after a blank line following the directions"
+                )
+            }
+            ParseErrorKind::UnknownDirection => write!(f, "unknown direction"),
+            ParseErrorKind::InvalidNode => {
+                write!(f, "expected a node as `<name> = (<left>, <right>)`")
+            }
+            ParseErrorKind::UnknownNode => write!(f, "node is never defined"),
+            ParseErrorKind::MissingNode(name) => write!(f, "there is no node named {}", name),
+        }
+    }
+}
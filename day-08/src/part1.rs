@@ -1,15 +1,37 @@
+use crate::parse::{ParseError, ParseErrorKind};
+use aoc_common::Solution;
 use std::collections::HashMap;
 
 use nom::{bytes::complete::tag, character::complete::alpha1, sequence::separated_pair, IResult};
 
-pub fn part1(input: &str) -> u32 {
-    let map = Map::parse_map(input);
+pub struct Part1;
 
+impl Solution for Part1 {
+    const PART: u8 = 1;
+
+    type Model<'a> = Map<'a>;
+    type Answer = u32;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Model<'_>, Self::Error> {
+        Map::parse_map(input)
+    }
+
+    fn solve(map: Self::Model<'_>) -> Result<Self::Answer, Self::Error> {
+        Ok(steps(&map))
+    }
+}
+
+pub fn part1(input: &str) -> Result<u32, ParseError> {
+    Part1::run(input)
+}
+
+fn steps(map: &Map) -> u32 {
     let mut steps = 0;
     let mut current_node = "AAA";
     loop {
         for direction in &map.pattern {
-            let node = map.nodes.get(current_node).expect("Could not find node");
+            let node = &map.nodes[current_node];
             if node.name == "ZZZ" {
                 return steps;
             }
@@ -33,7 +55,7 @@ enum Direction {
 }
 
 #[derive(Debug, Clone)]
-struct Map<'a> {
+pub struct Map<'a> {
     pattern: Vec<Direction>,
     nodes: HashMap<&'a str, Node<'a>>,
 }
@@ -45,42 +67,66 @@ struct Node<'a> {
     right: &'a str,
 }
 
-fn parse_node<'a>(line: &'a str) -> IResult<&'a str, (&'a str, &'a str)> {
+fn parse_node(line: &str) -> IResult<&str, (&str, &str)> {
     let (line, _) = tag("(")(line)?;
     let (input, (left, right)) = separated_pair(alpha1, tag(", "), alpha1)(line)?;
     Ok((input, (left, right)))
 }
 impl<'a> Map<'a> {
-    fn parse_map(input: &'a str) -> Self {
-        let (pattern, nodes_str) = input.split_once("\n\n").expect("Could not parse correctly");
+    fn parse_map(input: &'a str) -> Result<Self, ParseError> {
+        let (pattern, nodes_str) = input.split_once("\n\n").ok_or_else(|| {
+            ParseError::new(input, &input[input.len()..], ParseErrorKind::MissingNodes)
+        })?;
         let pattern = pattern
-            .chars()
-            .map(|c| match c {
-                'L' => Direction::Left,
-                'R' => Direction::Right,
-                c => panic!("Unknown character found: {}", c),
+            .char_indices()
+            .map(|(idx, c)| match c {
+                'L' => Ok(Direction::Left),
+                'R' => Ok(Direction::Right),
+                c => {
+                    let c = &pattern[idx..idx + c.len_utf8()];
+                    Err(ParseError::new(input, c, ParseErrorKind::UnknownDirection))
+                }
             })
-            .collect::<Vec<_>>();
+            .collect::<Result<Vec<_>, _>>()?;
 
         let nodes = nodes_str
             .lines()
             .map(|line: &'a str| {
-                let (node_name, edges) = line.split_once("=").expect("Could not split node line");
-                let (_, (left, right)) = parse_node(edges.trim()).expect("Could not parse edges");
+                let invalid_node = || ParseError::new(input, line, ParseErrorKind::InvalidNode);
+                let (node_name, edges) = line.split_once("=").ok_or_else(invalid_node)?;
+                let (_, (left, right)) = parse_node(edges.trim()).map_err(|_| invalid_node())?;
 
                 let node_name = node_name.trim();
-                (
+                if node_name.is_empty() {
+                    return Err(invalid_node());
+                }
+                Ok((
                     node_name,
                     Node {
                         name: node_name,
                         left,
                         right,
                     },
-                )
+                ))
             })
-            .collect::<HashMap<&'a str, Node<'a>>>();
+            .collect::<Result<HashMap<&'a str, Node<'a>>, _>>()?;
+        for node in nodes.values() {
+            for edge in [node.left, node.right] {
+                if !nodes.contains_key(edge) {
+                    return Err(ParseError::new(input, edge, ParseErrorKind::UnknownNode));
+                }
+            }
+        }
+        if !nodes.contains_key("AAA") {
+            let end = &input[input.len()..];
+            return Err(ParseError::new(
+                input,
+                end,
+                ParseErrorKind::MissingNode("AAA"),
+            ));
+        }
 
-        Self { pattern, nodes }
+        Ok(Self { pattern, nodes })
     }
 }
 
@@ -100,7 +146,7 @@ EEE = (EEE, EEE)
 GGG = (GGG, GGG)
 ZZZ = (ZZZ, ZZZ)";
 
-        assert_eq!(part1(input), 2);
+        assert_eq!(part1(input), Ok(2));
     }
     #[test]
     fn test2_part1() {
@@ -110,6 +156,18 @@ AAA = (BBB, BBB)
 BBB = (AAA, ZZZ)
 ZZZ = (ZZZ, ZZZ)";
 
-        assert_eq!(part1(input), 6);
+        assert_eq!(part1(input), Ok(6));
+    }
+
+    #[test]
+    fn test_part1_unknown_node() {
+        let input = "LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)";
+        let err = part1(input).unwrap_err();
+
+        assert_eq!(err.kind, ParseErrorKind::UnknownNode);
+        assert_eq!((err.line, err.column), (4, 13));
     }
 }
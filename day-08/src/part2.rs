@@ -1,3 +1,5 @@
+use crate::parse::{ParseError, ParseErrorKind};
+use aoc_common::Solution;
 use std::collections::HashMap;
 
 use nom::{
@@ -12,9 +14,29 @@ use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 ///
 /// The reason why LCM holds for this case is due to there being no offset. If there was an offset,
 /// then the current only other way to do it is by brute forcing iterating through the graph.
-pub fn part2(input: &str) -> u64 {
-    let map = Map::parse_map(input);
+pub struct Part2;
 
+impl Solution for Part2 {
+    const PART: u8 = 2;
+
+    type Model<'a> = Map<'a>;
+    type Answer = u64;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Model<'_>, Self::Error> {
+        Map::parse_map(input)
+    }
+
+    fn solve(map: Self::Model<'_>) -> Result<Self::Answer, Self::Error> {
+        Ok(steps(&map))
+    }
+}
+
+pub fn part2(input: &str) -> Result<u64, ParseError> {
+    Part2::run(input)
+}
+
+fn steps(map: &Map) -> u64 {
     let cycles = map
         .start_nodes
         .par_iter()
@@ -25,10 +47,7 @@ pub fn part2(input: &str) -> u64 {
                 .iter()
                 .cycle()
                 .position(|direction| {
-                    let lookedup_node = map
-                        .nodes
-                        .get(curr_node)
-                        .expect("node not found in node map");
+                    let lookedup_node = &map.nodes[curr_node];
                     curr_node = match direction {
                         Direction::Left => lookedup_node.left,
                         Direction::Right => lookedup_node.right,
@@ -40,7 +59,7 @@ pub fn part2(input: &str) -> u64 {
             a
         })
         .reduce(|| 1, lcm);
-    return cycles as u64;
+    cycles as u64
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -50,7 +69,7 @@ enum Direction {
 }
 
 #[derive(Debug, Clone)]
-struct Map<'a> {
+pub struct Map<'a> {
     pattern: Vec<Direction>,
     start_nodes: Vec<&'a str>,
     nodes: HashMap<&'a str, Node<'a>>,
@@ -58,57 +77,63 @@ struct Map<'a> {
 
 #[derive(Debug, Clone)]
 struct Node<'a> {
-    name: &'a str,
     left: &'a str,
     right: &'a str,
 }
 
-fn parse_node<'a>(line: &'a str) -> IResult<&'a str, (&'a str, &'a str)> {
+fn parse_node(line: &str) -> IResult<&str, (&str, &str)> {
     let (line, _) = tag("(")(line)?;
     let (input, (left, right)) = separated_pair(alphanumeric1, tag(", "), alphanumeric1)(line)?;
     Ok((input, (left, right)))
 }
 impl<'a> Map<'a> {
-    fn parse_map(input: &'a str) -> Self {
-        let (pattern, nodes_str) = input.split_once("\n\n").expect("Could not parse correctly");
+    fn parse_map(input: &'a str) -> Result<Self, ParseError> {
+        let (pattern, nodes_str) = input.split_once("\n\n").ok_or_else(|| {
+            ParseError::new(input, &input[input.len()..], ParseErrorKind::MissingNodes)
+        })?;
         let pattern = pattern
-            .chars()
-            .map(|c| match c {
-                'L' => Direction::Left,
-                'R' => Direction::Right,
-                c => panic!("Unknown character found: {}", c),
+            .char_indices()
+            .map(|(idx, c)| match c {
+                'L' => Ok(Direction::Left),
+                'R' => Ok(Direction::Right),
+                c => {
+                    let c = &pattern[idx..idx + c.len_utf8()];
+                    Err(ParseError::new(input, c, ParseErrorKind::UnknownDirection))
+                }
             })
-            .collect::<Vec<_>>();
+            .collect::<Result<Vec<_>, _>>()?;
 
         let nodes = nodes_str
             .lines()
             .map(|line: &'a str| {
-                let (node_name, edges) = line.split_once("=").expect("Could not split node line");
-                let (_, (left, right)) = parse_node(edges.trim()).expect("Could not parse edges");
+                let invalid_node = || ParseError::new(input, line, ParseErrorKind::InvalidNode);
+                let (node_name, edges) = line.split_once("=").ok_or_else(invalid_node)?;
+                let (_, (left, right)) = parse_node(edges.trim()).map_err(|_| invalid_node())?;
 
                 let node_name = node_name.trim();
-                (
-                    node_name,
-                    Node {
-                        name: node_name,
-                        left,
-                        right,
-                    },
-                )
+                if node_name.is_empty() {
+                    return Err(invalid_node());
+                }
+                Ok((node_name, Node { left, right }))
             })
-            .collect::<HashMap<&'a str, Node<'a>>>();
+            .collect::<Result<HashMap<&'a str, Node<'a>>, _>>()?;
+        for node in nodes.values() {
+            for edge in [node.left, node.right] {
+                if !nodes.contains_key(edge) {
+                    return Err(ParseError::new(input, edge, ParseErrorKind::UnknownNode));
+                }
+            }
+        }
 
         let start_nodes = nodes
             .keys()
-            .filter_map(|&node| {
-                (node.chars().last().expect("node during parsing is empty") == 'A').then_some(node)
-            })
+            .filter_map(|&node| node.ends_with('A').then_some(node))
             .collect::<Vec<_>>();
-        Self {
+        Ok(Self {
             pattern,
             start_nodes,
             nodes,
-        }
+        })
     }
 }
 
@@ -129,7 +154,7 @@ mod test {
 22Z = (22B, 22B)
 XXX = (XXX, XXX)";
 
-        assert_eq!(part2(input), 6);
+        assert_eq!(part2(input), Ok(6));
     }
 
     #[test]
@@ -149,6 +174,6 @@ XXX = (XXX, XXX)";
 22Z = (22B, 22B)
 XXX = (XXX, XXX)";
 
-        assert_eq!(part2(input), 8);
+        assert_eq!(part2(input), Ok(8));
     }
 }
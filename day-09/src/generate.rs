@@ -0,0 +1,51 @@
+use aoc_generate::{rand::Rng, rng};
+
+const READINGS: i64 = 21;
+
+/// `size` histories of 21 readings, each following a polynomial of degree 6 or less so that the
+/// differences eventually reach all zeroes.
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = rng(seed);
+
+    (0..size)
+        .map(|_| {
+            // Leading value of every row of differences, down to the constant one.
+            let leading = (0..=rng.gen_range(0..=6))
+                .map(|_| rng.gen_range(-10..=10))
+                .collect::<Vec<i64>>();
+            (0..READINGS)
+                .map(|x| {
+                    let mut binomial = 1;
+                    leading
+                        .iter()
+                        .enumerate()
+                        .map(|(k, value)| {
+                            let term = value * binomial;
+                            binomial = binomial * (x - k as i64) / (k as i64 + 1);
+                            term
+                        })
+                        .sum::<i64>()
+                        .to_string()
+                })
+                .collect::<Vec<_>>()
+                .join(" ")
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::SOLUTIONS;
+    use aoc_common::testing;
+
+    #[test]
+    fn test_generated_input_is_solved() {
+        testing::check_generated(
+            SOLUTIONS,
+            generate,
+            &[(1, 0, &["42781", "2"]), (100, 1, &[])],
+        );
+    }
+}
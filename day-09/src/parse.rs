@@ -0,0 +1,31 @@
+use std::{
+    fmt::{self, Display},
+    num::ParseIntError,
+};
+
+pub type ParseError = aoc_common::ParseError<ParseErrorKind>;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    InvalidNumber(ParseIntError),
+}
+
+impl Display for ParseErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseErrorKind::InvalidNumber(err) => write!(f, "invalid number ({})", err),
+        }
+    }
+}
+
+/// One history of readings per line.
+pub fn parse_readings(input: &str) -> Result<Vec<Vec<i32>>, ParseError> {
+    input
+        .lines()
+        .map(|line| {
+            line.split(' ')
+                .map(|num_str| ParseError::try_parse(input, num_str, ParseErrorKind::InvalidNumber))
+                .collect()
+        })
+        .collect()
+}
@@ -1,25 +1,38 @@
-pub fn part1(input: &str) -> i32 {
-    let lines = input.lines();
-
-    let readings = lines.map(|line| {
-        line.split(" ")
-            .map(|num_str| num_str.parse::<i32>().expect("Could not parse integer"))
-            .collect::<Vec<_>>()
-    });
-
-    readings
-        .map(|mut diff| {
-            let mut last_elements = Vec::new();
-            while !diff.iter().all(|d| *d == 0) {
-                last_elements.push(diff.last().cloned().unwrap());
-                diff = diff
-                    .iter()
-                    .map_windows(|[&x, &y]| y - x)
-                    .collect::<Vec<_>>();
-            }
-            last_elements.iter().rfold(0, |acc, last| last + acc)
-        })
-        .sum()
+use crate::parse::{self, ParseError};
+use aoc_common::Solution;
+
+pub struct Part1;
+
+impl Solution for Part1 {
+    const PART: u8 = 1;
+
+    type Model<'a> = Vec<Vec<i32>>;
+    type Answer = i32;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Model<'_>, Self::Error> {
+        parse::parse_readings(input)
+    }
+
+    fn solve(readings: Self::Model<'_>) -> Result<Self::Answer, Self::Error> {
+        Ok(readings.into_iter().map(extrapolate).sum())
+    }
+}
+
+pub fn part1(input: &str) -> Result<i32, ParseError> {
+    Part1::run(input)
+}
+
+fn extrapolate(mut diff: Vec<i32>) -> i32 {
+    let mut last_elements = Vec::new();
+    while !diff.iter().all(|d| *d == 0) {
+        last_elements.push(diff.last().cloned().unwrap());
+        diff = diff
+            .iter()
+            .map_windows(|[&x, &y]| y - x)
+            .collect::<Vec<_>>();
+    }
+    last_elements.iter().rfold(0, |acc, last| last + acc)
 }
 
 #[cfg(test)]
@@ -32,6 +45,6 @@ mod test {
 1 3 6 10 15 21
 10 13 16 21 30 45";
 
-        assert_eq!(part1(input), 114);
+        assert_eq!(part1(input), Ok(114));
     }
 }
@@ -1,25 +1,38 @@
-pub fn part2(input: &str) -> i32 {
-    let lines = input.lines();
-
-    let readings = lines.map(|line| {
-        line.split(" ")
-            .map(|num_str| num_str.parse::<i32>().expect("Could not parse integer"))
-            .collect::<Vec<_>>()
-    });
-
-    readings
-        .map(|mut diff| {
-            let mut first_elements = Vec::new();
-            while !diff.iter().all(|d| *d == 0) {
-                first_elements.push(diff.first().cloned().unwrap());
-                diff = diff
-                    .iter()
-                    .map_windows(|[&x, &y]| y - x)
-                    .collect::<Vec<_>>();
-            }
-            first_elements.iter().rfold(0, |acc, first| first - acc)
-        })
-        .sum()
+use crate::parse::{self, ParseError};
+use aoc_common::Solution;
+
+pub struct Part2;
+
+impl Solution for Part2 {
+    const PART: u8 = 2;
+
+    type Model<'a> = Vec<Vec<i32>>;
+    type Answer = i32;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Model<'_>, Self::Error> {
+        parse::parse_readings(input)
+    }
+
+    fn solve(readings: Self::Model<'_>) -> Result<Self::Answer, Self::Error> {
+        Ok(readings.into_iter().map(extrapolate_backwards).sum())
+    }
+}
+
+pub fn part2(input: &str) -> Result<i32, ParseError> {
+    Part2::run(input)
+}
+
+fn extrapolate_backwards(mut diff: Vec<i32>) -> i32 {
+    let mut first_elements = Vec::new();
+    while !diff.iter().all(|d| *d == 0) {
+        first_elements.push(diff.first().cloned().unwrap());
+        diff = diff
+            .iter()
+            .map_windows(|[&x, &y]| y - x)
+            .collect::<Vec<_>>();
+    }
+    first_elements.iter().rfold(0, |acc, first| first - acc)
 }
 
 #[cfg(test)]
@@ -32,6 +45,6 @@ mod test {
 1 3 6 10 15 21
 10 13 16 21 30 45";
 
-        assert_eq!(part2(input), 2);
+        assert_eq!(part2(input), Ok(2));
     }
 }
@@ -0,0 +1,55 @@
+use aoc_generate::{rand::Rng, rng, Outline};
+use aoc_grid::{Direction, Grid};
+
+static PIPES: &[u8] = b"|-LJ7F.";
+
+/// `size` x `size` field of pipes with a single loop through `S`, surrounded by random pipes that
+/// are not part of it.
+pub fn generate(size: usize, seed: u64) -> String {
+    assert!(size >= 4, "the loop needs a field of at least 4x4");
+    let mut rng = rng(seed);
+
+    let pipes = (0..size * size)
+        .map(|_| PIPES[rng.gen_range(0..PIPES.len())] as char)
+        .collect();
+    let mut field = Grid::new(size, size, pipes);
+
+    let points = Outline::random(&mut rng, size - 3, size - 3)
+        .points()
+        .into_iter()
+        .map(|(row, col)| (row + 1, col + 1))
+        .collect::<Vec<_>>();
+    let towards = |from: (usize, usize), to: (usize, usize)| {
+        Direction::ALL
+            .into_iter()
+            .find(|direction| direction.checked_offset(from, 1) == Some(to))
+            .unwrap()
+    };
+    for (idx, &pos) in points.iter().enumerate() {
+        let prev = points[(idx + points.len() - 1) % points.len()];
+        let next = points[(idx + 1) % points.len()];
+        field[pos] = match [towards(pos, prev), towards(pos, next)] {
+            [Direction::North, Direction::South] | [Direction::South, Direction::North] => '|',
+            [Direction::East, Direction::West] | [Direction::West, Direction::East] => '-',
+            [Direction::North, Direction::East] | [Direction::East, Direction::North] => 'L',
+            [Direction::North, Direction::West] | [Direction::West, Direction::North] => 'J',
+            [Direction::South, Direction::West] | [Direction::West, Direction::South] => '7',
+            _ => 'F',
+        };
+    }
+    field[points[rng.gen_range(0..points.len())]] = 'S';
+
+    field.to_string()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::SOLUTIONS;
+    use aoc_common::testing;
+
+    #[test]
+    fn test_generated_input_is_solved() {
+        testing::check_generated(SOLUTIONS, generate, &[(4, 0, &["2", "1"]), (40, 1, &[])]);
+    }
+}
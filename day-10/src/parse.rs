@@ -0,0 +1,86 @@
+use aoc_grid::{Grid, GridError};
+use std::fmt::{self, Display};
+
+pub type ParseError = aoc_common::ParseError<ParseErrorKind>;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    Grid(GridError),
+    MissingStart,
+}
+
+impl Display for ParseErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseErrorKind::Grid(err) => write!(f, "{}", err),
+            ParseErrorKind::MissingStart => write!(f, "no start tile `S` found"),
+        }
+    }
+}
+
+/// A sketch that could not be parsed, or one whose start tile is not on a loop.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    Parse(ParseError),
+    NoLoop,
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Parse(err) => err.fmt(f),
+            Error::NoLoop => write!(f, "start tile is not part of a loop"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<ParseError> for Error {
+    fn from(err: ParseError) -> Self {
+        Error::Parse(err)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Pipe {
+    Vertical,
+    Horizontal,
+    L,
+    J,
+    Seven,
+    F,
+    Ground,
+    Start,
+}
+
+/// The pipe grid together with the position of the start tile.
+#[derive(Debug, Clone)]
+pub struct Sketch {
+    pub pipes: Grid<Pipe>,
+    pub start: (usize, usize),
+}
+
+pub fn parse_sketch(input: &str) -> Result<Sketch, ParseError> {
+    let pipes = Grid::parse(input, |c| {
+        use Pipe::*;
+        match c {
+            '|' => Some(Vertical),
+            '-' => Some(Horizontal),
+            'L' => Some(L),
+            'J' => Some(J),
+            '7' => Some(Seven),
+            'F' => Some(F),
+            '.' => Some(Ground),
+            'S' => Some(Start),
+            _ => None,
+        }
+    })
+    .map_err(|err| err.map_kind(ParseErrorKind::Grid))?;
+
+    let start = pipes.position(|pipe| *pipe == Pipe::Start).ok_or_else(|| {
+        ParseError::new(input, &input[input.len()..], ParseErrorKind::MissingStart)
+    })?;
+
+    Ok(Sketch { pipes, start })
+}
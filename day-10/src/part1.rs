@@ -1,58 +1,58 @@
-use std::collections::{HashMap, VecDeque};
+use crate::parse::{self, Error, Pipe, Sketch};
+use aoc_common::Solution;
+use aoc_grid::{Direction, Grid};
+use std::collections::VecDeque;
 
 use rayon::iter::{IntoParallelIterator, ParallelIterator};
 
-pub fn part1(input: &str) -> usize {
-    let (pipes, start_pos) = input
-        .lines()
-        .enumerate()
-        .flat_map(|(row_idx, line)| {
-            line.chars().enumerate().map(move |(col_idx, c)| {
-                use Pipe::*;
-                let pos = (row_idx, col_idx);
-                match c {
-                    '|' => ((pos, Vertical), None),
-                    '-' => ((pos, Horizontal), None),
-                    'L' => ((pos, L), None),
-                    'J' => ((pos, J), None),
-                    '7' => ((pos, Seven), None),
-                    'F' => ((pos, F), None),
-                    '.' => ((pos, Ground), None),
-                    'S' => ((pos, Start), Some((row_idx, col_idx))),
-                    c => panic!("Unexpected char found: {}", c),
-                }
-            })
-        })
-        .fold((HashMap::new(), None), |mut acc, (pipe, start_pos)| {
-            acc.0.insert(pipe.0, pipe.1);
-            if acc.1.is_none() {
-                acc.1 = start_pos;
-            }
-            acc
-        });
+pub struct Part1;
+
+impl Solution for Part1 {
+    const PART: u8 = 1;
+
+    type Model<'a> = Sketch;
+    type Answer = usize;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Self::Model<'_>, Self::Error> {
+        Ok(parse::parse_sketch(input)?)
+    }
+
+    fn solve(sketch: Self::Model<'_>) -> Result<Self::Answer, Self::Error> {
+        farthest_distance(&sketch)
+    }
+}
+
+pub fn part1(input: &str) -> Result<usize, Error> {
+    Part1::run(input)
+}
 
-    let start_pos = start_pos.expect("No start point found");
+fn farthest_distance(sketch: &Sketch) -> Result<usize, Error> {
+    let Sketch {
+        pipes,
+        start: start_pos,
+    } = sketch;
 
     [
         PipeNode {
             pos: start_pos.clone(),
-            going: Direction::Up,
+            going: Direction::North,
         },
         PipeNode {
             pos: start_pos.clone(),
-            going: Direction::Left,
+            going: Direction::West,
         },
         PipeNode {
             pos: start_pos.clone(),
-            going: Direction::Down,
+            going: Direction::South,
         },
         PipeNode {
             pos: start_pos.clone(),
-            going: Direction::Right,
+            going: Direction::East,
         },
     ]
     .into_par_iter()
-    .filter_map(|node| check_next(&node, &pipes))
+    .filter_map(|node| check_next(&node, pipes))
     .filter_map(|next_start| {
         let mut stack = VecDeque::from([next_start.clone()]);
 
@@ -60,11 +60,11 @@ pub fn part1(input: &str) -> usize {
         while !stack.is_empty() {
             if let Some(curr) = stack.pop_back() {
                 stack.push_back(curr);
-                if let Some(pipe) = pipes.get(&curr.pos) {
+                if let Some(pipe) = pipes.get(curr.pos) {
                     use Pipe::*;
                     if match pipe {
                         Vertical | Horizontal | L | J | Seven | F => {
-                            if let Some(next_node) = check_next(&curr, &pipes) {
+                            if let Some(next_node) = check_next(&curr, pipes) {
                                 stack.push_back(next_node);
                             } else {
                                 return None;
@@ -87,16 +87,8 @@ pub fn part1(input: &str) -> usize {
         }
     })
     .max()
-    .unwrap()
-        / 2
-}
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum Direction {
-    Up,
-    Down,
-    Left,
-    Right,
+    .map(|loop_length| loop_length / 2)
+    .ok_or(Error::NoLoop)
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -105,48 +97,17 @@ struct PipeNode {
     going: Direction,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum Pipe {
-    Vertical,
-    Horizontal,
-    L,
-    J,
-    Seven,
-    F,
-    Ground,
-    Start,
-}
-
-fn check_next(node: &PipeNode, pipes: &HashMap<(usize, usize), Pipe>) -> Option<PipeNode> {
+fn check_next(node: &PipeNode, pipes: &Grid<Pipe>) -> Option<PipeNode> {
     use Pipe::*;
-    let next_position = match node.going {
-        Direction::Up => (
-            if node.pos.0 > 0 {
-                node.pos.0 - 1
-            } else {
-                return None;
-            },
-            node.pos.1,
-        ),
-        Direction::Down => (node.pos.0 + 1, node.pos.1),
-        Direction::Left => (
-            node.pos.0,
-            if node.pos.1 > 0 {
-                node.pos.1 - 1
-            } else {
-                return None;
-            },
-        ),
-        Direction::Right => (node.pos.0, node.pos.1 + 1),
-    };
+    let next_position = node.going.checked_offset(node.pos, 1)?;
     let acceptable_pipe_types = match node.going {
-        Direction::Up => vec![Vertical, Seven, F, Start],
-        Direction::Down => vec![Vertical, L, J, Start],
-        Direction::Left => vec![Horizontal, L, F, Start],
-        Direction::Right => vec![Horizontal, Seven, J, Start],
+        Direction::North => vec![Vertical, Seven, F, Start],
+        Direction::South => vec![Vertical, L, J, Start],
+        Direction::West => vec![Horizontal, L, F, Start],
+        Direction::East => vec![Horizontal, Seven, J, Start],
     };
 
-    pipes.get(&next_position).and_then(|pipe_type| {
+    pipes.get(next_position).and_then(|pipe_type| {
         if acceptable_pipe_types.contains(pipe_type) {
             let going_next = get_next_going_direction(pipe_type, node.going);
             Some(PipeNode {
@@ -163,37 +124,37 @@ fn get_next_going_direction(pipe: &Pipe, incoming_going: Direction) -> Direction
     use Pipe::*;
     match pipe {
         Vertical => match incoming_going {
-            Direction::Up => Direction::Up,
-            Direction::Down => Direction::Down,
+            Direction::North => Direction::North,
+            Direction::South => Direction::South,
             _ => panic!("not valid incoming direction"),
         },
         Horizontal => match incoming_going {
-            Direction::Left => Direction::Left,
-            Direction::Right => Direction::Right,
+            Direction::West => Direction::West,
+            Direction::East => Direction::East,
             _ => panic!("not valid incoming direction"),
         },
         L => match incoming_going {
-            Direction::Down => Direction::Right,
-            Direction::Left => Direction::Up,
+            Direction::South => Direction::East,
+            Direction::West => Direction::North,
             _ => panic!("not valid incoming direction"),
         },
         J => match incoming_going {
-            Direction::Down => Direction::Left,
-            Direction::Right => Direction::Up,
+            Direction::South => Direction::West,
+            Direction::East => Direction::North,
             _ => panic!("not valid incoming direction"),
         },
         Seven => match incoming_going {
-            Direction::Up => Direction::Left,
-            Direction::Right => Direction::Down,
+            Direction::North => Direction::West,
+            Direction::East => Direction::South,
             _ => panic!("not valid incoming direction"),
         },
         F => match incoming_going {
-            Direction::Up => Direction::Right,
-            Direction::Left => Direction::Down,
+            Direction::North => Direction::East,
+            Direction::West => Direction::South,
             _ => panic!("not valid incoming direction"),
         },
         Ground => panic!("Ground is not a pipe"),
-        Start => Direction::Up, // arbitrary direction
+        Start => Direction::North, // arbitrary direction
     }
 }
 
@@ -202,6 +163,8 @@ mod test {
     use rstest::rstest;
 
     use super::*;
+    use crate::parse::ParseErrorKind;
+    use aoc_grid::GridError;
 
     #[rstest]
     #[case(
@@ -234,6 +197,29 @@ L--J.L7...LJS7F-7L7.
         70
     )]
     fn test_part1(#[case] input: &str, #[case] expected: usize) {
-        assert_eq!(part1(input), expected);
+        assert_eq!(part1(input), Ok(expected));
+    }
+
+    #[rstest]
+    #[case(".....\n.F-7.\n.|.|.\n.L-J.\n.....", ParseErrorKind::MissingStart, (5, 6))]
+    #[case(".....\n.S-7.\n.|.|.\n.L-X.\n.....", ParseErrorKind::Grid(GridError::UnexpectedChar('X')), (4, 4))]
+    fn test_part1_parse_error(
+        #[case] input: &str,
+        #[case] expected: ParseErrorKind,
+        #[case] position: (usize, usize),
+    ) {
+        let Err(Error::Parse(err)) = part1(input) else {
+            panic!("{:?} should not parse", input);
+        };
+
+        assert_eq!(err.kind, expected);
+        assert_eq!((err.line, err.column), position);
+    }
+
+    #[test]
+    fn test_part1_no_loop() {
+        let input = ".....\n.S-7.\n.|.-.\n.L-J.\n.....";
+
+        assert_eq!(part1(input), Err(Error::NoLoop));
     }
 }
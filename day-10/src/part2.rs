@@ -1,58 +1,57 @@
-use std::collections::{BTreeMap, HashSet, VecDeque};
+use crate::parse::{self, Error, Pipe, Sketch};
+use aoc_common::Solution;
+use aoc_grid::{Direction, Grid};
+use std::collections::{HashSet, VecDeque};
 
 use rayon::iter::{IntoParallelIterator, ParallelIterator};
 
-pub fn part2(input: &str) -> usize {
-    let (pipes, start_pos) = input
-        .lines()
-        .enumerate()
-        .flat_map(|(row_idx, line)| {
-            line.chars().enumerate().map(move |(col_idx, c)| {
-                use Pipe::*;
-                let pos = (row_idx, col_idx);
-                match c {
-                    '|' => ((pos, Vertical), None),
-                    '-' => ((pos, Horizontal), None),
-                    'L' => ((pos, L), None),
-                    'J' => ((pos, J), None),
-                    '7' => ((pos, Seven), None),
-                    'F' => ((pos, F), None),
-                    '.' => ((pos, Ground), None),
-                    'S' => ((pos, Start), Some((row_idx, col_idx))),
-                    c => panic!("Unexpected char found: {}", c),
-                }
-            })
-        })
-        .fold((BTreeMap::new(), None), |mut acc, (pipe, start_pos)| {
-            acc.0.insert(pipe.0, pipe.1);
-            if acc.1.is_none() {
-                acc.1 = start_pos;
-            }
-            acc
-        });
+pub struct Part2;
+
+impl Solution for Part2 {
+    const PART: u8 = 2;
+
+    type Model<'a> = Sketch;
+    type Answer = usize;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Self::Model<'_>, Self::Error> {
+        Ok(parse::parse_sketch(input)?)
+    }
+
+    fn solve(sketch: Self::Model<'_>) -> Result<Self::Answer, Self::Error> {
+        enclosed_tiles(&sketch)
+    }
+}
 
-    let start_pos = start_pos.expect("No start point found");
-    let (max_row, max_col) = pipes.keys().last().unwrap();
+pub fn part2(input: &str) -> Result<usize, Error> {
+    Part2::run(input)
+}
+
+fn enclosed_tiles(sketch: &Sketch) -> Result<usize, Error> {
+    let Sketch {
+        pipes,
+        start: start_pos,
+    } = sketch;
     let path = [
         PipeNode {
             pos: start_pos.clone(),
-            going: Direction::Up,
+            going: Direction::North,
         },
         PipeNode {
             pos: start_pos.clone(),
-            going: Direction::Left,
+            going: Direction::West,
         },
         PipeNode {
             pos: start_pos.clone(),
-            going: Direction::Down,
+            going: Direction::South,
         },
         PipeNode {
             pos: start_pos.clone(),
-            going: Direction::Right,
+            going: Direction::East,
         },
     ]
     .into_par_iter()
-    .filter_map(|node| check_next(&node, &pipes))
+    .filter_map(|node| check_next(&node, pipes))
     .filter_map(|next_start| {
         let mut stack = VecDeque::from([next_start.clone()]);
 
@@ -60,11 +59,11 @@ pub fn part2(input: &str) -> usize {
         while !stack.is_empty() {
             if let Some(curr) = stack.pop_back() {
                 stack.push_back(curr);
-                if let Some(pipe) = pipes.get(&curr.pos) {
+                if let Some(pipe) = pipes.get(curr.pos) {
                     use Pipe::*;
                     if match pipe {
                         Vertical | Horizontal | L | J | Seven | F => {
-                            if let Some(next_node) = check_next(&curr, &pipes) {
+                            if let Some(next_node) = check_next(&curr, pipes) {
                                 stack.push_back(next_node);
                             } else {
                                 return None;
@@ -87,19 +86,19 @@ pub fn part2(input: &str) -> usize {
         }
     })
     .max_by(|a, b| a.len().cmp(&b.len()))
-    .unwrap()
+    .ok_or(Error::NoLoop)?
     .into_iter()
     .collect::<HashSet<_>>();
 
-    (0..=(*max_row))
+    let count = (0..pipes.height())
         .map(|r| {
             let mut count = 0;
             // it just so happens that the tests and input have S start on a F or 7 pipe, so we
             // jsut treat it the same way
             let not_crossing_pipes = vec![Pipe::Horizontal, Pipe::F, Pipe::Start, Pipe::Seven];
             let mut is_adding = false;
-            for c in 0..=*max_col {
-                let pipe = pipes.get(&(r, c)).expect("pipe not found");
+            for c in 0..pipes.width() {
+                let pipe = pipes.get((r, c)).expect("pipe not found");
                 if path.contains(&(r, c)) {
                     if !not_crossing_pipes.contains(pipe) {
                         is_adding ^= true;
@@ -113,15 +112,8 @@ pub fn part2(input: &str) -> usize {
             }
             count
         })
-        .sum()
-}
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum Direction {
-    Up,
-    Down,
-    Left,
-    Right,
+        .sum();
+    Ok(count)
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -130,48 +122,17 @@ struct PipeNode {
     going: Direction,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum Pipe {
-    Vertical,
-    Horizontal,
-    L,
-    J,
-    Seven,
-    F,
-    Ground,
-    Start,
-}
-
-fn check_next(node: &PipeNode, pipes: &BTreeMap<(usize, usize), Pipe>) -> Option<PipeNode> {
+fn check_next(node: &PipeNode, pipes: &Grid<Pipe>) -> Option<PipeNode> {
     use Pipe::*;
-    let next_position = match node.going {
-        Direction::Up => (
-            if node.pos.0 > 0 {
-                node.pos.0 - 1
-            } else {
-                return None;
-            },
-            node.pos.1,
-        ),
-        Direction::Down => (node.pos.0 + 1, node.pos.1),
-        Direction::Left => (
-            node.pos.0,
-            if node.pos.1 > 0 {
-                node.pos.1 - 1
-            } else {
-                return None;
-            },
-        ),
-        Direction::Right => (node.pos.0, node.pos.1 + 1),
-    };
+    let next_position = node.going.checked_offset(node.pos, 1)?;
     let acceptable_pipe_types = match node.going {
-        Direction::Up => vec![Vertical, Seven, F, Start],
-        Direction::Down => vec![Vertical, L, J, Start],
-        Direction::Left => vec![Horizontal, L, F, Start],
-        Direction::Right => vec![Horizontal, Seven, J, Start],
+        Direction::North => vec![Vertical, Seven, F, Start],
+        Direction::South => vec![Vertical, L, J, Start],
+        Direction::West => vec![Horizontal, L, F, Start],
+        Direction::East => vec![Horizontal, Seven, J, Start],
     };
 
-    pipes.get(&next_position).and_then(|pipe_type| {
+    pipes.get(next_position).and_then(|pipe_type| {
         if acceptable_pipe_types.contains(pipe_type) {
             let going_next = get_next_going_direction(pipe_type, node.going);
             Some(PipeNode {
@@ -188,37 +149,37 @@ fn get_next_going_direction(pipe: &Pipe, incoming_going: Direction) -> Direction
     use Pipe::*;
     match pipe {
         Vertical => match incoming_going {
-            Direction::Up => Direction::Up,
-            Direction::Down => Direction::Down,
+            Direction::North => Direction::North,
+            Direction::South => Direction::South,
             _ => panic!("not valid incoming direction"),
         },
         Horizontal => match incoming_going {
-            Direction::Left => Direction::Left,
-            Direction::Right => Direction::Right,
+            Direction::West => Direction::West,
+            Direction::East => Direction::East,
             _ => panic!("not valid incoming direction"),
         },
         L => match incoming_going {
-            Direction::Down => Direction::Right,
-            Direction::Left => Direction::Up,
+            Direction::South => Direction::East,
+            Direction::West => Direction::North,
             _ => panic!("not valid incoming direction"),
         },
         J => match incoming_going {
-            Direction::Down => Direction::Left,
-            Direction::Right => Direction::Up,
+            Direction::South => Direction::West,
+            Direction::East => Direction::North,
             _ => panic!("not valid incoming direction"),
         },
         Seven => match incoming_going {
-            Direction::Up => Direction::Left,
-            Direction::Right => Direction::Down,
+            Direction::North => Direction::West,
+            Direction::East => Direction::South,
             _ => panic!("not valid incoming direction"),
         },
         F => match incoming_going {
-            Direction::Up => Direction::Right,
-            Direction::Left => Direction::Down,
+            Direction::North => Direction::East,
+            Direction::West => Direction::South,
             _ => panic!("not valid incoming direction"),
         },
         Ground => panic!("Ground is not a pipe"),
-        Start => Direction::Up, // arbitrary direction
+        Start => Direction::North, // arbitrary direction
     }
 }
 
@@ -268,6 +229,6 @@ L7JLJL-JLJLJL--JLJ.L",
         10
     )]
     fn test_part2(#[case] input: &str, #[case] expected: usize) {
-        assert_eq!(part2(input), expected);
+        assert_eq!(part2(input), Ok(expected));
     }
 }
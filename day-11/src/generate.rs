@@ -0,0 +1,37 @@
+use aoc_generate::{rand::Rng, rng};
+
+/// `size` x `size` image with galaxies on about 1 in 40 tiles. About a tenth of the rows and
+/// columns are kept empty so that there is something to expand.
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = rng(seed);
+    let empty_rows = (0..size).map(|_| rng.gen_bool(0.1)).collect::<Vec<_>>();
+    let empty_cols = (0..size).map(|_| rng.gen_bool(0.1)).collect::<Vec<_>>();
+
+    (0..size)
+        .map(|row| {
+            (0..size)
+                .map(|col| {
+                    let empty = empty_rows[row] || empty_cols[col];
+                    if !empty && rng.gen_ratio(1, 40) {
+                        '#'
+                    } else {
+                        '.'
+                    }
+                })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::SOLUTIONS;
+    use aoc_common::testing;
+
+    #[test]
+    fn test_generated_input_is_solved() {
+        testing::check_generated(SOLUTIONS, generate, &[(1, 0, &["0", "0"]), (60, 1, &[])]);
+    }
+}
@@ -0,0 +1,52 @@
+use std::{
+    collections::BTreeSet,
+    fmt::{self, Display},
+};
+
+pub type ParseError = aoc_common::ParseError<ParseErrorKind>;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    UnknownChar,
+}
+
+impl Display for ParseErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseErrorKind::UnknownChar => write!(f, "expected `#` or `.`"),
+        }
+    }
+}
+
+/// Positions of every galaxy as `(row, col)`, along with the size of the image they were found in.
+#[derive(Debug, Clone)]
+pub struct Image {
+    pub galaxies: BTreeSet<(usize, usize)>,
+    pub height: usize,
+    pub width: usize,
+}
+
+pub fn parse_image(input: &str) -> Result<Image, ParseError> {
+    let galaxies = input
+        .lines()
+        .enumerate()
+        .flat_map(|(row_idx, line)| {
+            line.char_indices()
+                .enumerate()
+                .filter_map(move |(col_idx, (idx, c))| match c {
+                    '#' => Some(Ok((row_idx, col_idx))),
+                    '.' => None,
+                    c => {
+                        let c = &line[idx..idx + c.len_utf8()];
+                        Some(Err(ParseError::new(input, c, ParseErrorKind::UnknownChar)))
+                    }
+                })
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok(Image {
+        galaxies,
+        height: input.lines().count(),
+        width: input.lines().map(|line| line.len()).max().unwrap_or(0),
+    })
+}
@@ -1,23 +1,35 @@
+use crate::parse::{self, Image, ParseError};
+use aoc_common::Solution;
 use itertools::Itertools;
-use std::collections::BTreeSet;
 
-pub fn part1(input: &str) -> i64 {
-    let mut space = input
-        .lines()
-        .enumerate()
-        .flat_map(|(row_idx, line)| {
-            line.chars()
-                .enumerate()
-                .filter_map(move |(col_idx, c)| match c {
-                    '#' => Some((row_idx, col_idx)),
-                    '.' => None,
-                    c => panic!("Not a valid character: {}", c),
-                })
-        })
-        .collect::<BTreeSet<_>>();
+pub struct Part1;
+
+impl Solution for Part1 {
+    const PART: u8 = 1;
+
+    type Model<'a> = Image;
+    type Answer = i64;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Model<'_>, Self::Error> {
+        parse::parse_image(input)
+    }
+
+    fn solve(image: Self::Model<'_>) -> Result<Self::Answer, Self::Error> {
+        Ok(distance_sum(image))
+    }
+}
+
+pub fn part1(input: &str) -> Result<i64, ParseError> {
+    Part1::run(input)
+}
 
-    let max_row = input.lines().count();
-    let max_col = input.lines().map(|line| line.len()).max().unwrap();
+fn distance_sum(image: Image) -> i64 {
+    let Image {
+        galaxies: mut space,
+        height: max_row,
+        width: max_col,
+    } = image;
     let mut no_galaxy_rows = (0..max_row)
         .filter(|r| !(0..max_col).any(|c| space.contains(&(*r, c))))
         .collect::<Vec<_>>();
@@ -101,6 +113,6 @@ mod test {
 .......#..
 #...#.....";
 
-        assert_eq!(part1(input), 374);
+        assert_eq!(part1(input), Ok(374));
     }
 }
@@ -1,27 +1,39 @@
+use crate::parse::{self, Image, ParseError};
+use aoc_common::Solution;
 use itertools::Itertools;
-use std::collections::BTreeSet;
 
-pub fn part2(input: &str) -> i64 {
-    part2_helper(input, 1_000_000)
+pub struct Part2;
+
+impl Solution for Part2 {
+    const PART: u8 = 2;
+
+    type Model<'a> = Image;
+    type Answer = i64;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Model<'_>, Self::Error> {
+        parse::parse_image(input)
+    }
+
+    fn solve(image: Self::Model<'_>) -> Result<Self::Answer, Self::Error> {
+        Ok(distance_sum(image, 1_000_000))
+    }
 }
 
-pub fn part2_helper(input: &str, scale_factor: usize) -> i64 {
-    let mut space = input
-        .lines()
-        .enumerate()
-        .flat_map(|(row_idx, line)| {
-            line.chars()
-                .enumerate()
-                .filter_map(move |(col_idx, c)| match c {
-                    '#' => Some((row_idx, col_idx)),
-                    '.' => None,
-                    c => panic!("Not a valid character: {}", c),
-                })
-        })
-        .collect::<BTreeSet<_>>();
+pub fn part2(input: &str) -> Result<i64, ParseError> {
+    Part2::run(input)
+}
+
+pub fn part2_helper(input: &str, scale_factor: usize) -> Result<i64, ParseError> {
+    Ok(distance_sum(parse::parse_image(input)?, scale_factor))
+}
 
-    let max_row = input.lines().count();
-    let max_col = input.lines().map(|line| line.len()).max().unwrap();
+fn distance_sum(image: Image, scale_factor: usize) -> i64 {
+    let Image {
+        galaxies: mut space,
+        height: max_row,
+        width: max_col,
+    } = image;
     let mut no_galaxy_rows = (0..max_row)
         .filter(|r| !(0..max_col).any(|c| space.contains(&(*r, c))))
         .collect::<Vec<_>>();
@@ -105,7 +117,7 @@ mod test {
 .......#..
 #...#.....";
 
-        assert_eq!(part2_helper(input, 100), 8410);
+        assert_eq!(part2_helper(input, 100), Ok(8410));
     }
     #[test]
     fn test_part2_scale_10() {
@@ -120,6 +132,6 @@ mod test {
 .......#..
 #...#.....";
 
-        assert_eq!(part2_helper(input, 10), 1030);
+        assert_eq!(part2_helper(input, 10), Ok(1030));
     }
 }
@@ -0,0 +1,62 @@
+use aoc_generate::{rand::Rng, rng};
+
+const RECORDS: usize = 100;
+
+/// 100 condition records of `size` springs each. The groups are taken from a random arrangement,
+/// so every record has at least one, before about half of the springs are hidden behind `?`.
+///
+/// Unfolded records of all `?` can have more arrangements than fit in a `usize` from about 20
+/// springs on. The generated ones keep far fewer, as about half of their springs are known, which
+/// is checked for the sizes of the benchmarks.
+pub fn generate(size: usize, seed: u64) -> String {
+    assert!(size > 0, "records need at least one spring");
+    let mut rng = rng(seed);
+
+    (0..RECORDS)
+        .map(|_| {
+            let mut springs = (0..size)
+                .map(|_| if rng.gen_bool(0.5) { '#' } else { '.' })
+                .collect::<Vec<_>>();
+            let damaged = rng.gen_range(0..size);
+            springs[damaged] = '#';
+
+            let groups = springs
+                .split(|&spring| spring == '.')
+                .filter(|group| !group.is_empty())
+                .map(|group| group.len().to_string())
+                .collect::<Vec<_>>();
+            let record = springs
+                .iter()
+                .map(|&spring| if rng.gen_bool(0.5) { '?' } else { spring })
+                .collect::<String>();
+            format!("{} {}", record, groups.join(","))
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::SOLUTIONS;
+    use aoc_common::testing;
+
+    // The sizes and seed of the benchmarks, counted with the overflow checks of the test build.
+    #[test]
+    fn test_benchmark_sizes_fit() {
+        testing::check_generated(
+            SOLUTIONS,
+            generate,
+            &[(5, 0, &[]), (10, 0, &[]), (20, 0, &[]), (30, 0, &[])],
+        );
+    }
+
+    #[test]
+    fn test_generated_input_is_solved() {
+        testing::check_generated(
+            SOLUTIONS,
+            generate,
+            &[(1, 0, &["100", "100"]), (20, 1, &[])],
+        );
+    }
+}
@@ -1,3 +1,7 @@
 #![feature(never_type)]
+pub mod generate;
+pub mod parse;
 pub mod part1;
 pub mod part2;
+
+aoc_common::solutions!(12 => part1::Part1, part2::Part2);
@@ -0,0 +1,38 @@
+use std::{
+    fmt::{self, Display},
+    num::ParseIntError,
+};
+
+pub type ParseError = aoc_common::ParseError<ParseErrorKind>;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    MissingGroups,
+    InvalidNumber(ParseIntError),
+}
+
+impl Display for ParseErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseErrorKind::MissingGroups => write!(f, "expected springs followed by group sizes"),
+            ParseErrorKind::InvalidNumber(err) => write!(f, "invalid number ({})", err),
+        }
+    }
+}
+
+/// Condition records along with the sizes of their contiguous groups of damaged springs.
+pub fn parse_records(input: &str) -> Result<Vec<(&str, Vec<usize>)>, ParseError> {
+    input
+        .lines()
+        .map(|line| {
+            let (record, group_str) = line
+                .split_once(' ')
+                .ok_or_else(|| ParseError::new(input, line, ParseErrorKind::MissingGroups))?;
+            let groups = group_str
+                .split(',')
+                .map(|g| ParseError::try_parse(input, g, ParseErrorKind::InvalidNumber))
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok((record, groups))
+        })
+        .collect()
+}
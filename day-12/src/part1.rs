@@ -1,26 +1,32 @@
+use crate::parse::{self, ParseError};
+use aoc_common::Solution;
 use std::collections::BTreeMap;
 
-use itertools::Itertools;
-
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 
-pub fn part1(input: &str) -> usize {
-    let lines = input
-        .lines()
-        .map(|line| line.split_once(' ').expect("No space delimiter"))
-        .collect_vec();
-
-    lines
-        .par_iter()
-        .map(|(record, group_str)| {
-            let groups = group_str
-                .split(',')
-                .map(|g| g.parse::<usize>().expect("Grouping is not a number"))
-                .collect_vec();
-
-            evaluate_record_pattern(record, groups.as_slice())
-        })
-        .sum()
+pub struct Part1;
+
+impl Solution for Part1 {
+    const PART: u8 = 1;
+
+    type Model<'a> = Vec<(&'a str, Vec<usize>)>;
+    type Answer = usize;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Model<'_>, Self::Error> {
+        parse::parse_records(input)
+    }
+
+    fn solve(lines: Self::Model<'_>) -> Result<Self::Answer, Self::Error> {
+        Ok(lines
+            .par_iter()
+            .map(|(record, groups)| evaluate_record_pattern(record, groups.as_slice()))
+            .sum())
+    }
+}
+
+pub fn part1(input: &str) -> Result<usize, ParseError> {
+    Part1::run(input)
 }
 
 fn evaluate_record_pattern(partial_record: &str, groupings: &[usize]) -> usize {
@@ -96,7 +102,7 @@ mod test {
 ????.######..#####. 1,6,5
 ?###???????? 3,2,1";
 
-        assert_eq!(part1(input), 21);
+        assert_eq!(part1(input), Ok(21));
     }
 
     #[rstest]
@@ -114,6 +120,6 @@ mod test {
     #[case("???????????. 6,1", 10)]
     #[case("?..#???#??. 1,3", 3)]
     fn test_individual_lines(#[case] input: &str, #[case] expected: usize) {
-        assert_eq!(part1(input), expected)
+        assert_eq!(part1(input), Ok(expected))
     }
 }
@@ -1,29 +1,39 @@
+use crate::parse::{self, ParseError};
+use aoc_common::Solution;
 use std::collections::BTreeMap;
 
 use itertools::Itertools;
 
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 
-pub fn part2(input: &str) -> usize {
-    let lines = input
-        .lines()
-        .map(|line| line.split_once(' ').expect("No space delimiter"))
-        .collect_vec();
-
-    lines
-        .par_iter()
-        .map(|(record, group_str)| {
-            let record = [record, record, record, record, record].iter().join("?");
-            let groups = [group_str, group_str, group_str, group_str, group_str]
-                .iter()
-                .join(",")
-                .split(',')
-                .map(|g| g.parse::<usize>().expect("Grouping is not a number"))
-                .collect_vec();
-
-            evaluate_record_pattern(record.as_str(), groups.as_slice())
-        })
-        .sum()
+pub struct Part2;
+
+impl Solution for Part2 {
+    const PART: u8 = 2;
+
+    type Model<'a> = Vec<(&'a str, Vec<usize>)>;
+    type Answer = usize;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Model<'_>, Self::Error> {
+        parse::parse_records(input)
+    }
+
+    fn solve(lines: Self::Model<'_>) -> Result<Self::Answer, Self::Error> {
+        Ok(lines
+            .par_iter()
+            .map(|(record, groups)| {
+                let record = [record, record, record, record, record].iter().join("?");
+                let groups = groups.repeat(5);
+
+                evaluate_record_pattern(record.as_str(), groups.as_slice())
+            })
+            .sum())
+    }
+}
+
+pub fn part2(input: &str) -> Result<usize, ParseError> {
+    Part2::run(input)
 }
 
 fn evaluate_record_pattern(partial_record: &str, groupings: &[usize]) -> usize {
@@ -100,7 +110,7 @@ mod test {
 ????.######..#####. 1,6,5
 ?###???????? 3,2,1";
 
-        assert_eq!(part2(input), 525152);
+        assert_eq!(part2(input), Ok(525152));
     }
 
     #[rstest]
@@ -111,6 +121,6 @@ mod test {
     #[case("????.######..#####. 1,6,5", 2500)]
     #[case("?###???????? 3,2,1", 506250)]
     fn test_individual_lines(#[case] input: &str, #[case] expected: usize) {
-        assert_eq!(part2(input), expected)
+        assert_eq!(part2(input), Ok(expected))
     }
 }
@@ -0,0 +1,114 @@
+use aoc_generate::{rand::Rng, rng};
+
+/// `size` patterns of 5 to 17 rows and columns.
+///
+/// Each pattern is reflected perfectly across one line, and across one other line except for a
+/// single smudge, so that both parts find a mirror.
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = rng(seed);
+
+    (0..size)
+        .map(|_| {
+            let height = rng.gen_range(5..=17);
+            let width = rng.gen_range(5..=17);
+            let pattern = loop {
+                let pattern = pattern(&mut rng, width, height);
+                let mut smudges = smudges_per_line(&pattern);
+                smudges.sort_unstable();
+                // Any other line that happens to (almost) reflect would make the answer ambiguous.
+                if smudges.starts_with(&[0, 1]) && smudges.get(2).is_none_or(|&s| s > 1) {
+                    break pattern;
+                }
+            };
+
+            let pattern = if rng.gen_bool(0.5) {
+                (0..width)
+                    .map(|col| pattern.iter().map(|row| row[col]).collect::<Vec<_>>())
+                    .collect()
+            } else {
+                pattern
+            };
+            pattern
+                .iter()
+                .map(|row| row.iter().collect::<String>())
+                .collect::<Vec<_>>()
+                .join("\n")
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+/// Rows are mirrored across a horizontal line, and every row is mirrored across a vertical line
+/// before one of the rows left out by the horizontal reflection gets the smudge.
+fn pattern(rng: &mut impl Rng, width: usize, height: usize) -> Vec<Vec<char>> {
+    let below = loop {
+        let below = rng.gen_range(1..height);
+        if below * 2 != height {
+            break below;
+        }
+    };
+    let right = rng.gen_range(1..width);
+    let mirrored_cols = right.min(width - right);
+
+    let mut rows = (0..height)
+        .map(|_| {
+            let mut row = (0..width)
+                .map(|_| if rng.gen_bool(0.5) { '#' } else { '.' })
+                .collect::<Vec<_>>();
+            for offset in 0..mirrored_cols {
+                row[right + offset] = row[right - 1 - offset];
+            }
+            row
+        })
+        .collect::<Vec<_>>();
+    let mirrored_rows = below.min(height - below);
+    for offset in 0..mirrored_rows {
+        rows[below + offset] = rows[below - 1 - offset].clone();
+    }
+
+    let smudge_row = if below * 2 < height {
+        rng.gen_range(below * 2..height)
+    } else {
+        rng.gen_range(0..below * 2 - height)
+    };
+    let smudge_col = rng.gen_range(right - mirrored_cols..right);
+    let smudge = &mut rows[smudge_row][smudge_col];
+    *smudge = if *smudge == '#' { '.' } else { '#' };
+    rows
+}
+
+/// How many tiles don't match their reflection across every vertical and horizontal line.
+fn smudges_per_line(pattern: &[Vec<char>]) -> Vec<usize> {
+    let (height, width) = (pattern.len(), pattern[0].len());
+    let vertical = (1..width).map(|line| {
+        pattern
+            .iter()
+            .map(|row| {
+                let (left, right) = row.split_at(line);
+                left.iter().rev().zip(right).filter(|(a, b)| a != b).count()
+            })
+            .sum()
+    });
+    let horizontal = (1..height).map(|line| {
+        let (above, below) = pattern.split_at(line);
+        above
+            .iter()
+            .rev()
+            .zip(below)
+            .map(|(a, b)| a.iter().zip(b).filter(|(a, b)| a != b).count())
+            .sum()
+    });
+    vertical.chain(horizontal).collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::SOLUTIONS;
+    use aoc_common::testing;
+
+    #[test]
+    fn test_generated_input_is_solved() {
+        testing::check_generated(SOLUTIONS, generate, &[(1, 0, &["100", "9"]), (50, 1, &[])]);
+    }
+}
@@ -0,0 +1,98 @@
+use std::fmt::{self, Display};
+
+pub type ParseError = aoc_common::ParseError<ParseErrorKind>;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    EmptyPattern,
+    UnknownChar,
+    RaggedLine { expected: usize, found: usize },
+}
+
+impl Display for ParseErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseErrorKind::EmptyPattern => write!(f, "expected a pattern"),
+            ParseErrorKind::UnknownChar => write!(f, "expected `#` or `.`"),
+            ParseErrorKind::RaggedLine { expected, found } => write!(
+                f,
+                "expected a line of {} characters, found {}",
+                expected, found
+            ),
+        }
+    }
+}
+
+/// Patterns that could not be parsed, or one without a line of reflection, counted from 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    Parse(ParseError),
+    NoMirror(usize),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Parse(err) => err.fmt(f),
+            Error::NoMirror(pattern) => {
+                write!(f, "pattern {} has no single line of reflection", pattern)
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<ParseError> for Error {
+    fn from(err: ParseError) -> Self {
+        Error::Parse(err)
+    }
+}
+
+/// Rows of a single pattern, all of the same width.
+#[derive(Debug, Clone)]
+pub struct Pattern {
+    pub rows: Vec<Vec<char>>,
+}
+
+/// Every pattern of `input`, separated by blank lines.
+pub fn parse_patterns(input: &str) -> Result<Vec<Pattern>, ParseError> {
+    input
+        .split("\n\n")
+        .map(|pattern| parse_pattern(input, pattern))
+        .collect()
+}
+
+/// A single `pattern` out of `input`.
+pub fn parse_pattern(input: &str, pattern: &str) -> Result<Pattern, ParseError> {
+    let width = pattern
+        .lines()
+        .next()
+        .map(|line| line.chars().count())
+        .filter(|&width| width > 0)
+        .ok_or_else(|| ParseError::new(input, pattern, ParseErrorKind::EmptyPattern))?;
+
+    let rows = pattern
+        .lines()
+        .map(|line| {
+            if let Some((idx, c)) = line.char_indices().find(|(_, c)| !matches!(c, '#' | '.')) {
+                let c = &line[idx..idx + c.len_utf8()];
+                return Err(ParseError::new(input, c, ParseErrorKind::UnknownChar));
+            }
+            let found = line.chars().count();
+            if found != width {
+                return Err(ParseError::new(
+                    input,
+                    line,
+                    ParseErrorKind::RaggedLine {
+                        expected: width,
+                        found,
+                    },
+                ));
+            }
+            Ok(line.chars().collect())
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok(Pattern { rows })
+}
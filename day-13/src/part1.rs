@@ -1,18 +1,39 @@
+use crate::parse::{self, Error, Pattern};
+use aoc_common::Solution;
 use std::collections::HashSet;
 
 use itertools::Itertools;
 
-pub fn part1(input: &str) -> usize {
-    input.split("\n\n").map(part1_pattern).sum()
+pub struct Part1;
+
+impl Solution for Part1 {
+    const PART: u8 = 1;
+
+    type Model<'a> = Vec<Pattern>;
+    type Answer = usize;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Self::Model<'_>, Self::Error> {
+        Ok(parse::parse_patterns(input)?)
+    }
+
+    fn solve(patterns: Self::Model<'_>) -> Result<Self::Answer, Self::Error> {
+        patterns
+            .iter()
+            .enumerate()
+            .map(|(idx, pattern)| part1_pattern(pattern).ok_or(Error::NoMirror(idx + 1)))
+            .sum()
+    }
 }
 
-fn part1_pattern(pattern: &str) -> usize {
-    let pattern = pattern
-        .lines()
-        .map(|line| line.chars().collect_vec())
-        .collect_vec();
+pub fn part1(input: &str) -> Result<usize, Error> {
+    Part1::run(input)
+}
 
-    let canidate_vertical_mirrors = pattern
+fn part1_pattern(pattern: &Pattern) -> Option<usize> {
+    let rows = &pattern.rows;
+
+    let canidate_vertical_mirrors = rows
         .iter()
         .map(|row| {
             (1..row.len())
@@ -41,20 +62,20 @@ fn part1_pattern(pattern: &str) -> usize {
         .into_iter()
         .collect_vec();
     if let Some(&mirror_idx) = canidate_vertical_mirrors.first() {
-        return mirror_idx;
+        return Some(mirror_idx);
     }
-    let canidate_horizontal_mirrors = (0..pattern[0].len())
+    let canidate_horizontal_mirrors = (0..rows[0].len())
         .map(|c| {
-            (1..pattern.len())
+            (1..rows.len())
                 .filter(|canidate_horizontal_mirror| {
-                    for offset in 0..pattern.len() {
+                    for offset in 0..rows.len() {
                         let left_chat_idx = canidate_horizontal_mirror - offset - 1;
                         let right_char_idx = canidate_horizontal_mirror + offset;
-                        if right_char_idx >= pattern.len() {
+                        if right_char_idx >= rows.len() {
                             break;
                         }
 
-                        if pattern[right_char_idx][c] != pattern[left_chat_idx][c] {
+                        if rows[right_char_idx][c] != rows[left_chat_idx][c] {
                             return false;
                         }
 
@@ -71,9 +92,9 @@ fn part1_pattern(pattern: &str) -> usize {
         .into_iter()
         .collect_vec();
     if let Some(&mirror_idx) = canidate_horizontal_mirrors.first() {
-        return mirror_idx * 100;
+        return Some(mirror_idx * 100);
     }
-    todo!()
+    None
 }
 
 #[cfg(test)]
@@ -81,6 +102,7 @@ mod test {
     use rstest::rstest;
 
     use super::*;
+    use crate::parse::ParseErrorKind;
 
     #[rstest]
     #[case(
@@ -133,6 +155,29 @@ mod test {
         10
     )]
     fn test_part1(#[case] input: &str, #[case] expected: usize) {
-        assert_eq!(part1_pattern(input), expected);
+        let pattern = parse::parse_pattern(input, input).unwrap();
+        assert_eq!(part1_pattern(&pattern), Some(expected));
+    }
+
+    #[rstest]
+    #[case("#.#\n..\n#.#", ParseErrorKind::RaggedLine { expected: 3, found: 2 }, (2, 1))]
+    #[case("#.#\n.x.\n#.#", ParseErrorKind::UnknownChar, (2, 2))]
+    fn test_part1_parse_error(
+        #[case] input: &str,
+        #[case] expected: ParseErrorKind,
+        #[case] position: (usize, usize),
+    ) {
+        let Err(Error::Parse(err)) = part1(input) else {
+            panic!("{:?} should not parse", input);
+        };
+
+        assert_eq!(err.kind, expected);
+        assert_eq!((err.line, err.column), position);
+    }
+    #[test]
+    fn test_part1_no_mirror() {
+        let input = "##\n..\n\n#..\n..#";
+
+        assert_eq!(part1(input), Err(Error::NoMirror(2)));
     }
 }
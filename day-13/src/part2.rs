@@ -1,35 +1,51 @@
+use crate::parse::{self, Error, Pattern};
+use aoc_common::Solution;
 use std::collections::HashSet;
 
 use itertools::Itertools;
 
-pub fn part2(input: &str) -> usize {
-    input.split("\n\n").map(part2_pattern).sum()
+pub struct Part2;
+
+impl Solution for Part2 {
+    const PART: u8 = 2;
+
+    type Model<'a> = Vec<Pattern>;
+    type Answer = usize;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Self::Model<'_>, Self::Error> {
+        Ok(parse::parse_patterns(input)?)
+    }
+
+    fn solve(patterns: Self::Model<'_>) -> Result<Self::Answer, Self::Error> {
+        patterns
+            .iter()
+            .enumerate()
+            .map(|(idx, pattern)| part2_pattern(pattern).ok_or(Error::NoMirror(idx + 1)))
+            .sum()
+    }
 }
 
-fn part2_pattern(pattern: &str) -> usize {
-    let pattern = pattern
-        .lines()
-        .map(|line| line.chars().collect_vec())
-        .collect_vec();
+pub fn part2(input: &str) -> Result<usize, Error> {
+    Part2::run(input)
+}
 
-    let original_mirror = find_mirror(&pattern).unwrap();
-    let mirrors = pattern
+fn part2_pattern(pattern: &Pattern) -> Option<usize> {
+    let rows = &pattern.rows;
+
+    let original_mirror = find_mirror(rows)?;
+    let mirrors = rows
         .iter()
         .enumerate()
         .flat_map(|(row_idx, row)| {
-            let pattern = &pattern;
             row.iter().enumerate().filter_map(move |(col_idx, _)| {
                 let canidate_pos = (row_idx, col_idx);
-                find_mirror_with_smudge(pattern, canidate_pos, original_mirror)
+                find_mirror_with_smudge(rows, canidate_pos, original_mirror)
             })
         })
         .collect::<HashSet<_>>();
 
-    mirrors
-        .into_iter()
-        .exactly_one()
-        .expect(format!("{:?}", pattern).as_str())
-        .clone()
+    mirrors.into_iter().exactly_one().ok()
 }
 
 fn find_mirror(pattern: &Vec<Vec<char>>) -> Option<usize> {
@@ -239,6 +255,7 @@ mod test {
         5
     )]
     fn test_part1(#[case] input: &str, #[case] expected: usize) {
-        assert_eq!(part2_pattern(input), expected);
+        let pattern = parse::parse_pattern(input, input).unwrap();
+        assert_eq!(part2_pattern(&pattern), Some(expected));
     }
 }
@@ -0,0 +1,32 @@
+use aoc_generate::{rand::Rng, rng};
+
+/// `size` x `size` platform where a fifth of the tiles are rounded rocks and a sixth are cube
+/// rocks.
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = rng(seed);
+
+    (0..size)
+        .map(|_| {
+            (0..size)
+                .map(|_| match rng.gen_range(0..30) {
+                    0..=5 => 'O',
+                    6..=10 => '#',
+                    _ => '.',
+                })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::SOLUTIONS;
+    use aoc_common::testing;
+
+    #[test]
+    fn test_generated_input_is_solved() {
+        testing::check_generated(SOLUTIONS, generate, &[(1, 0, &["0", "0"]), (40, 1, &[])]);
+    }
+}
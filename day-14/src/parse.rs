@@ -0,0 +1,34 @@
+use aoc_grid::{Grid, GridError};
+use std::fmt::{self, Display};
+
+pub type ParseError = aoc_common::ParseError<ParseErrorKind>;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    Grid(GridError),
+}
+
+impl Display for ParseErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseErrorKind::Grid(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Rock {
+    Sphere,
+    Cube,
+    Empty,
+}
+
+pub fn parse_platform(input: &str) -> Result<Grid<Rock>, ParseError> {
+    Grid::parse(input, |c| match c {
+        'O' => Some(Rock::Sphere),
+        '#' => Some(Rock::Cube),
+        '.' => Some(Rock::Empty),
+        _ => None,
+    })
+    .map_err(|err| err.map_kind(ParseErrorKind::Grid))
+}
@@ -1,37 +1,52 @@
-use itertools::Itertools;
-
-pub fn part1(input: &str) -> usize {
-    let platform = input
-        .lines()
-        .map(|line| line.chars().collect_vec())
-        .collect_vec();
-
-    (0..platform[0].len())
-        .map(|col_idx| {
-            let mut next_available_spot = 0;
-            let mut total_weight = 0;
-            for (row_idx, row) in platform.iter().enumerate() {
-                let rock = row[col_idx];
-                match rock {
-                    'O' => {
-                        total_weight += platform.len() - next_available_spot;
-                        next_available_spot += 1;
-                    }
-                    '#' => {
-                        next_available_spot = row_idx + 1;
+use crate::parse::{self, ParseError, Rock};
+use aoc_common::Solution;
+use aoc_grid::Grid;
+
+pub struct Part1;
+
+impl Solution for Part1 {
+    const PART: u8 = 1;
+
+    type Model<'a> = Grid<Rock>;
+    type Answer = usize;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Model<'_>, Self::Error> {
+        parse::parse_platform(input)
+    }
+
+    fn solve(platform: Self::Model<'_>) -> Result<Self::Answer, Self::Error> {
+        Ok((0..platform.width())
+            .map(|col_idx| {
+                let mut next_available_spot = 0;
+                let mut total_weight = 0;
+                for (row_idx, rock) in platform.column(col_idx).enumerate() {
+                    match rock {
+                        Rock::Sphere => {
+                            total_weight += platform.height() - next_available_spot;
+                            next_available_spot += 1;
+                        }
+                        Rock::Cube => {
+                            next_available_spot = row_idx + 1;
+                        }
+                        Rock::Empty => {}
                     }
-                    '.' => {}
-                    c => panic!("Unknown char found: {}", c),
                 }
-            }
-            total_weight
-        })
-        .sum()
+                total_weight
+            })
+            .sum())
+    }
+}
+
+pub fn part1(input: &str) -> Result<usize, ParseError> {
+    Part1::run(input)
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::parse::ParseErrorKind;
+    use aoc_grid::GridError;
 
     #[test]
     fn test_part1() {
@@ -46,6 +61,19 @@ O.#..O.#.#
 #....###..
 #OO..#....";
 
-        assert_eq!(part1(input), 136);
+        assert_eq!(part1(input), Ok(136));
+    }
+
+    #[test]
+    fn test_part1_unknown_rock() {
+        let input = "O..#
+.@.O";
+        let err = part1(input).unwrap_err();
+
+        assert_eq!(
+            err.kind,
+            ParseErrorKind::Grid(GridError::UnexpectedChar('@'))
+        );
+        assert_eq!((err.line, err.column), (2, 2));
     }
 }
@@ -1,17 +1,13 @@
+use crate::parse::{self, ParseError, Rock};
+use aoc_common::Solution;
+use aoc_grid::Grid;
 use std::{
-    collections::{BTreeMap, BTreeSet, HashMap},
+    collections::{BTreeSet, HashMap},
     ops::Range,
 };
 
 use itertools::Itertools;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-enum Rock {
-    Sphere,
-    Cube,
-    Empty,
-}
-
 #[derive(Debug, Clone, PartialEq, Eq)]
 struct VerticalBin {
     column: usize,
@@ -34,41 +30,41 @@ impl PartialOrd for VerticalBin {
     }
 }
 
-pub fn part2(input: &str) -> usize {
-    let platform = input
-        .lines()
-        .enumerate()
-        .flat_map(|(row_idx, line)| {
-            line.chars()
-                .enumerate()
-                .filter_map(move |(col_idx, c)| match c {
-                    'O' => Some(((row_idx, col_idx), Rock::Sphere)),
-                    '#' => Some(((row_idx, col_idx), Rock::Cube)),
-                    '.' => Some(((row_idx, col_idx), Rock::Empty)),
-                    c => panic!("Unknown char found: {}", c),
-                })
-        })
-        .collect::<BTreeMap<_, _>>();
+pub struct Part2;
+
+impl Solution for Part2 {
+    const PART: u8 = 2;
+
+    type Model<'a> = Grid<Rock>;
+    type Answer = usize;
+    type Error = ParseError;
 
-    let max_row = input.lines().count();
-    let max_col = input.lines().next().unwrap().len();
-    let spheres = get_new_platform(platform, max_row, max_col);
+    fn parse(input: &str) -> Result<Self::Model<'_>, Self::Error> {
+        parse::parse_platform(input)
+    }
+
+    fn solve(platform: Self::Model<'_>) -> Result<Self::Answer, Self::Error> {
+        let max_row = platform.height();
+        let spheres = get_new_platform(platform);
 
-    spheres.iter().map(|pos| max_row - pos.0).sum()
+        Ok(spheres.iter().map(|pos| max_row - pos.0).sum())
+    }
 }
 
-fn get_new_platform(
-    mut platform: BTreeMap<(usize, usize), Rock>,
-    max_row: usize,
-    max_col: usize,
-) -> BTreeSet<(usize, usize)> {
+pub fn part2(input: &str) -> Result<usize, ParseError> {
+    Part2::run(input)
+}
+
+#[cfg_attr(feature = "trace", tracing::instrument(level = "debug", skip_all))]
+fn get_new_platform(mut platform: Grid<Rock>) -> BTreeSet<(usize, usize)> {
+    let max_row = platform.height();
+    let max_col = platform.width();
     let mut cycle_mem: HashMap<Vec<usize>, BTreeSet<(usize, usize)>> = HashMap::new();
 
     let cube_rocks = platform
         .iter()
         .filter(|(_, &rock)| rock == Rock::Cube)
         .map(|(pos, _)| pos)
-        .cloned()
         .collect_vec();
 
     let cube_rocks_with_bottom_border = cube_rocks
@@ -108,7 +104,6 @@ fn get_new_platform(
         .iter()
         .filter(|(_, &rock)| rock == Rock::Sphere)
         .map(|(pos, _)| pos)
-        .cloned()
         .collect::<BTreeSet<_>>();
 
     let mut cycles_completed = 0;
@@ -136,25 +131,28 @@ fn get_new_platform(
                     // found cycle
                     cycles_completed = cycle;
                     cycle_length = Some(cycle - start_cycle_num);
+                    #[cfg(feature = "trace")]
+                    tracing::debug!(cycle, cycle_length, "found period");
                     break;
                 }
             }
+            #[cfg(feature = "trace")]
+            tracing::trace!(cycle, "tilt cycle seen before");
             // otherwise set spheres to cycle_result
             spheres = cycle_result.clone();
             continue;
         }
 
-        platform = cube_rocks
-            .iter()
-            .map(|&pos| (pos, Rock::Cube))
-            .chain(spheres.iter().map(|&pos| (pos, Rock::Sphere)))
-            .collect::<BTreeMap<_, _>>();
+        #[cfg(feature = "trace")]
+        let _tilt = tracing::trace_span!("tilt cycle", cycle).entered();
+        platform = build_platform(&cube_rocks, &spheres, max_row, max_col);
         spheres = (0..max_col)
             .map(|col_idx| {
                 let mut next_available_spot = 0;
-                platform.iter().filter(|(pos, _)| pos.1 == col_idx).fold(
-                    vec![],
-                    |mut acc, ((row_idx, _), rock)| {
+                platform
+                    .column(col_idx)
+                    .enumerate()
+                    .fold(vec![], |mut acc, (row_idx, rock)| {
                         match rock {
                             Rock::Sphere => {
                                 acc.push((next_available_spot, col_idx));
@@ -166,23 +164,20 @@ fn get_new_platform(
                             Rock::Empty => {}
                         }
                         acc
-                    },
-                )
+                    })
             })
             .flatten()
             .collect::<BTreeSet<_>>();
 
-        platform = cube_rocks
-            .iter()
-            .map(|&pos| (pos, Rock::Cube))
-            .chain(spheres.iter().map(|&pos| (pos, Rock::Sphere)))
-            .collect::<BTreeMap<_, _>>();
+        platform = build_platform(&cube_rocks, &spheres, max_row, max_col);
         spheres = (0..max_row)
             .map(|row_idx| {
                 let mut next_available_spot = 0;
-                platform.iter().filter(|(pos, _)| pos.0 == row_idx).fold(
-                    vec![],
-                    |mut acc, ((_, col_idx), rock)| {
+                platform
+                    .row(row_idx)
+                    .iter()
+                    .enumerate()
+                    .fold(vec![], |mut acc, (col_idx, rock)| {
                         match rock {
                             Rock::Sphere => {
                                 acc.push((row_idx, next_available_spot));
@@ -194,72 +189,63 @@ fn get_new_platform(
                             Rock::Empty => {}
                         }
                         acc
-                    },
-                )
+                    })
             })
             .flatten()
             .collect::<BTreeSet<_>>();
 
-        platform = cube_rocks
-            .iter()
-            .map(|&pos| (pos, Rock::Cube))
-            .chain(spheres.iter().map(|&pos| (pos, Rock::Sphere)))
-            .collect::<BTreeMap<_, _>>();
+        platform = build_platform(&cube_rocks, &spheres, max_row, max_col);
         spheres = (0..max_col)
             .rev()
             .map(|col_idx| {
                 let mut next_available_spot = max_row;
-                platform
-                    .iter()
-                    .filter(|(pos, _)| pos.1 == col_idx)
-                    .rev()
-                    .fold(vec![], |mut acc, ((row_idx, _), rock)| {
+                platform.column(col_idx).enumerate().rev().fold(
+                    vec![],
+                    |mut acc, (row_idx, rock)| {
                         match rock {
                             Rock::Sphere => {
                                 acc.push((next_available_spot - 1, col_idx));
                                 next_available_spot -= 1;
                             }
                             Rock::Cube => {
-                                next_available_spot = *row_idx;
+                                next_available_spot = row_idx;
                             }
                             Rock::Empty => {}
                         }
                         acc
-                    })
+                    },
+                )
             })
             .flatten()
             .collect::<BTreeSet<_>>();
 
-        platform = cube_rocks
-            .iter()
-            .map(|&pos| (pos, Rock::Cube))
-            .chain(spheres.iter().map(|&pos| (pos, Rock::Sphere)))
-            .collect::<BTreeMap<_, _>>();
+        platform = build_platform(&cube_rocks, &spheres, max_row, max_col);
         spheres = (0..max_row)
             .rev()
             .map(|row_idx| {
                 let mut next_available_spot = max_col;
-                platform
-                    .iter()
-                    .filter(|(pos, _)| pos.0 == row_idx)
-                    .rev()
-                    .fold(vec![], |mut acc, ((_, col_idx), rock)| {
+                platform.row(row_idx).iter().enumerate().rev().fold(
+                    vec![],
+                    |mut acc, (col_idx, rock)| {
                         match rock {
                             Rock::Sphere => {
                                 acc.push((row_idx, next_available_spot - 1));
                                 next_available_spot -= 1;
                             }
                             Rock::Cube => {
-                                next_available_spot = *col_idx;
+                                next_available_spot = col_idx;
                             }
                             Rock::Empty => {}
                         }
                         acc
-                    })
+                    },
+                )
             })
             .flatten()
             .collect::<BTreeSet<_>>();
 
+        #[cfg(feature = "trace")]
+        tracing::trace!(load = spheres.iter().map(|pos| max_row - pos.0).sum::<usize>());
         start_bin_counts = Some((cycle, next_bin_counts.clone()));
         cycle_mem.insert(next_bin_counts, spheres.clone());
     }
@@ -286,6 +272,22 @@ fn get_new_platform(
     spheres
 }
 
+fn build_platform(
+    cube_rocks: &[(usize, usize)],
+    spheres: &BTreeSet<(usize, usize)>,
+    max_row: usize,
+    max_col: usize,
+) -> Grid<Rock> {
+    let mut platform = Grid::filled(max_col, max_row, Rock::Empty);
+    for &pos in cube_rocks {
+        platform[pos] = Rock::Cube;
+    }
+    for &pos in spheres {
+        platform[pos] = Rock::Sphere;
+    }
+    platform
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -303,6 +305,6 @@ O.#..O.#.#
 #....###..
 #OO..#....";
 
-        assert_eq!(part2(input), 64);
+        assert_eq!(part2(input), Ok(64));
     }
 }
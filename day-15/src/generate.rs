@@ -0,0 +1,39 @@
+use aoc_generate::{rand::Rng, rng, LOWERCASE};
+
+/// `size` comma separated steps. Labels of 2 to 6 letters are reused across steps so that lenses
+/// get replaced and removed.
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = rng(seed);
+    let letters = LOWERCASE.as_bytes();
+    let labels = (0..size / 4 + 1)
+        .map(|_| {
+            (0..rng.gen_range(2..=6))
+                .map(|_| letters[rng.gen_range(0..letters.len())] as char)
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>();
+
+    (0..size)
+        .map(|_| {
+            let label = &labels[rng.gen_range(0..labels.len())];
+            if rng.gen_ratio(1, 3) {
+                format!("{}-", label)
+            } else {
+                format!("{}={}", label, rng.gen_range(1..=9))
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::SOLUTIONS;
+    use aoc_common::testing;
+
+    #[test]
+    fn test_generated_input_is_solved() {
+        testing::check_generated(SOLUTIONS, generate, &[(1, 0, &["52", "0"]), (1000, 1, &[])]);
+    }
+}
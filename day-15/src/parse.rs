@@ -0,0 +1,34 @@
+use std::fmt::{self, Display};
+
+pub type ParseError = aoc_common::ParseError<ParseErrorKind>;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    MissingLabel,
+    UnknownOperation,
+    InvalidFocalLength,
+}
+
+impl Display for ParseErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseErrorKind::MissingLabel => write!(f, "expected a step starting with a label"),
+            ParseErrorKind::UnknownOperation => write!(f, "expected `-` or `=`"),
+            ParseErrorKind::InvalidFocalLength => write!(f, "expected a focal length"),
+        }
+    }
+}
+
+/// Points at the text nom stopped at, or at the end of `input` if it ran out.
+pub fn nom_error<'a>(
+    input: &'a str,
+    kind: ParseErrorKind,
+) -> impl FnOnce(nom::Err<nom::error::Error<&str>>) -> ParseError + 'a {
+    move |err| match err {
+        nom::Err::Error(err) | nom::Err::Failure(err) => {
+            let len = err.input.find([',', '\n']).unwrap_or(err.input.len());
+            ParseError::new(input, &err.input[..len], kind)
+        }
+        nom::Err::Incomplete(_) => ParseError::new(input, &input[input.len()..], kind),
+    }
+}
@@ -1,3 +1,25 @@
+use aoc_common::Solution;
+use std::convert::Infallible;
+
+pub struct Part1;
+
+impl Solution for Part1 {
+    const PART: u8 = 1;
+
+    // The hash is folded over the characters as they come in, so there is nothing to parse.
+    type Model<'a> = &'a str;
+    type Answer = u32;
+    type Error = Infallible;
+
+    fn parse(input: &str) -> Result<Self::Model<'_>, Self::Error> {
+        Ok(input)
+    }
+
+    fn solve(input: Self::Model<'_>) -> Result<Self::Answer, Self::Error> {
+        Ok(part1(input))
+    }
+}
+
 pub fn part1(input: &str) -> u32 {
     sum(input
         .chars()
@@ -1,21 +1,35 @@
-use itertools::Itertools;
-use nom::{
-    bytes::complete::tag,
-    character::complete::{self, alpha1},
-    sequence::preceded,
-    IResult, Parser,
-};
-
-pub fn part2(input: &str) -> usize {
+use crate::parse::{self, ParseError, ParseErrorKind};
+use aoc_common::Solution;
+use nom::character::complete::{self, alpha1, one_of};
+
+pub struct Part2;
+
+impl Solution for Part2 {
+    const PART: u8 = 2;
+
+    type Model<'a> = Vec<Sequence<'a>>;
+    type Answer = usize;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Model<'_>, Self::Error> {
+        input
+            .split(',')
+            .map(|seq| parse_seqence(input, seq))
+            .collect()
+    }
+
+    fn solve(sequences: Self::Model<'_>) -> Result<Self::Answer, Self::Error> {
+        Ok(focusing_power(&sequences))
+    }
+}
+
+pub fn part2(input: &str) -> Result<usize, ParseError> {
+    Part2::run(input)
+}
+
+fn focusing_power(sequences: &[Sequence]) -> usize {
     const EMPTY_BOX: Vec<(&str, u32)> = Vec::new();
     let mut boxes = vec![EMPTY_BOX; 256];
-    let sequences = input
-        .split(',')
-        .map(|seq| {
-            let (_, seq) = parse_seqence(seq).expect("seqence parsed incorrectly");
-            seq
-        })
-        .collect_vec();
 
     sequences.iter().for_each(|sequence| {
         let selected_box = &mut boxes[sequence.hash as usize];
@@ -59,28 +73,30 @@ enum Operation {
 }
 
 #[derive(Debug, Clone, Copy)]
-struct Sequence<'a> {
+pub struct Sequence<'a> {
     ident: &'a str,
     hash: u8,
     operation: Operation,
 }
 
-fn parse_seqence<'a>(input: &'a str) -> IResult<&'a str, Sequence<'a>> {
-    let (input, (ident, hash)) =
-        alpha1(input).map(|(input, ident)| (input, (ident, calculate_hash(ident))))?;
-    let (input, operation) = tag("-")
-        .map(|_| Operation::Remove)
-        .or(preceded(tag("="), complete::u32).map(|value| Operation::Set(value)))
-        .parse(input)?;
-
-    IResult::Ok((
-        input,
-        Sequence {
-            ident,
-            hash,
-            operation,
-        },
-    ))
+fn parse_seqence<'a>(input: &str, seq: &'a str) -> Result<Sequence<'a>, ParseError> {
+    let (seq, ident) =
+        alpha1(seq).map_err(parse::nom_error(input, ParseErrorKind::MissingLabel))?;
+    let (value, op) =
+        one_of("-=")(seq).map_err(parse::nom_error(input, ParseErrorKind::UnknownOperation))?;
+    let operation = if op == '-' {
+        Operation::Remove
+    } else {
+        let (_, value) = complete::u32(value)
+            .map_err(parse::nom_error(input, ParseErrorKind::InvalidFocalLength))?;
+        Operation::Set(value)
+    };
+
+    Ok(Sequence {
+        ident,
+        hash: calculate_hash(ident),
+        operation,
+    })
 }
 
 pub fn calculate_hash(input: &str) -> u8 {
@@ -105,7 +121,7 @@ mod test {
     fn test_part2() {
         let input = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
 
-        assert_eq!(part2(input), 145);
+        assert_eq!(part2(input), Ok(145));
     }
 
     #[test]
@@ -114,4 +130,13 @@ mod test {
 
         assert_eq!(calculate_hash(input), 52);
     }
+
+    #[test]
+    fn test_part2_invalid_focal_length() {
+        let input = "rn=1,cm-,qp=x,cm=2";
+        let err = part2(input).unwrap_err();
+
+        assert_eq!(err.kind, ParseErrorKind::InvalidFocalLength);
+        assert_eq!((err.line, err.column), (1, 13));
+    }
 }
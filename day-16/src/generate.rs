@@ -0,0 +1,35 @@
+use aoc_generate::{rand::Rng, rng};
+
+static DEVICES: &[u8] = b"/\\|-";
+
+/// `size` x `size` contraption where about one tile in ten is a mirror or a splitter.
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = rng(seed);
+
+    (0..size)
+        .map(|_| {
+            (0..size)
+                .map(|_| {
+                    if rng.gen_ratio(1, 10) {
+                        DEVICES[rng.gen_range(0..DEVICES.len())] as char
+                    } else {
+                        '.'
+                    }
+                })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::SOLUTIONS;
+    use aoc_common::testing;
+
+    #[test]
+    fn test_generated_input_is_solved() {
+        testing::check_generated(SOLUTIONS, generate, &[(1, 0, &["1", "1"]), (40, 1, &[])]);
+    }
+}
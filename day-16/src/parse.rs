@@ -0,0 +1,38 @@
+use aoc_grid::{Grid, GridError};
+use std::fmt::{self, Display};
+
+pub type ParseError = aoc_common::ParseError<ParseErrorKind>;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    Grid(GridError),
+}
+
+impl Display for ParseErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseErrorKind::Grid(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TileType {
+    Empty,
+    HorizontalSplitter,
+    VerticalSplitter,
+    PositiveMirror,
+    NegativeMirror,
+}
+
+pub fn parse_contraption(input: &str) -> Result<Grid<TileType>, ParseError> {
+    Grid::parse(input, |c| match c {
+        '.' => Some(TileType::Empty),
+        '-' => Some(TileType::HorizontalSplitter),
+        '|' => Some(TileType::VerticalSplitter),
+        '/' => Some(TileType::PositiveMirror),
+        '\\' => Some(TileType::NegativeMirror),
+        _ => None,
+    })
+    .map_err(|err| err.map_kind(ParseErrorKind::Grid))
+}
@@ -1,35 +1,40 @@
-use std::collections::{BTreeMap, HashSet, VecDeque};
-
-pub fn part1(input: &str) -> usize {
-    let mut map = input
-        .lines()
-        .enumerate()
-        .flat_map(|(row_idx, line)| {
-            line.chars().enumerate().map(move |(col_idx, c)| {
-                let pos = (row_idx as i64, col_idx as i64);
-                let tile_type = match c {
-                    '.' => TileType::Empty,
-                    '-' => TileType::HorizontalSplitter,
-                    '|' => TileType::VerticalSplitter,
-                    '/' => TileType::PositiveMirror,
-                    '\\' => TileType::NegativeMirror,
-                    c => unreachable!("Input provided has illegal char: {}", c),
-                };
-                (
-                    pos,
-                    Tile {
-                        tile_type,
-                        incoming_light: HashSet::new(),
-                    },
-                )
-            })
-        })
-        .collect::<BTreeMap<_, _>>();
+use crate::parse::{self, ParseError, TileType};
+use aoc_common::Solution;
+use aoc_grid::{Direction, Grid};
+use std::collections::{HashSet, VecDeque};
+
+pub struct Part1;
+
+impl Solution for Part1 {
+    const PART: u8 = 1;
+
+    type Model<'a> = Grid<TileType>;
+    type Answer = usize;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Model<'_>, Self::Error> {
+        parse::parse_contraption(input)
+    }
+
+    fn solve(contraption: Self::Model<'_>) -> Result<Self::Answer, Self::Error> {
+        Ok(energized_tiles(&contraption))
+    }
+}
+
+pub fn part1(input: &str) -> Result<usize, ParseError> {
+    Part1::run(input)
+}
+
+fn energized_tiles(contraption: &Grid<TileType>) -> usize {
+    let mut map = contraption.map(|&tile_type| Tile {
+        tile_type,
+        incoming_light: HashSet::new(),
+    });
 
     let mut queue = VecDeque::from([((0, 0), Direction::East)]);
 
     while let Some((pos, incoming_direction)) = queue.pop_front() {
-        if let Some(tile) = map.get_mut(&pos) {
+        if let Some(tile) = map.checked_position(pos).and_then(|pos| map.get_mut(pos)) {
             if tile.incoming_light.contains(&incoming_direction) {
                 // path has already been evaluated, skip
                 continue;
@@ -37,116 +42,53 @@ pub fn part1(input: &str) -> usize {
             tile.incoming_light.insert(incoming_direction);
             match tile.tile_type {
                 TileType::Empty => {
-                    let offset = incoming_direction.get_offset();
-                    queue.push_back(((pos.0 + offset.0, pos.1 + offset.1), incoming_direction));
+                    queue.push_back((incoming_direction.offset_by(pos, 1), incoming_direction));
                 }
                 TileType::HorizontalSplitter => match incoming_direction {
                     Direction::North | Direction::South => {
                         let next_directions = [Direction::East, Direction::West];
                         for direction in next_directions {
-                            let offset = direction.get_offset();
-                            queue.push_back(((pos.0 + offset.0, pos.1 + offset.1), direction));
+                            queue.push_back((direction.offset_by(pos, 1), direction));
                         }
                     }
                     direction => {
-                        let offset = direction.get_offset();
-                        queue.push_back(((pos.0 + offset.0, pos.1 + offset.1), direction));
+                        queue.push_back((direction.offset_by(pos, 1), direction));
                     }
                 },
                 TileType::VerticalSplitter => match incoming_direction {
                     Direction::East | Direction::West => {
                         let next_directions = [Direction::North, Direction::South];
                         for direction in next_directions {
-                            let offset = direction.get_offset();
-                            queue.push_back(((pos.0 + offset.0, pos.1 + offset.1), direction));
+                            queue.push_back((direction.offset_by(pos, 1), direction));
                         }
                     }
                     direction => {
-                        let offset = direction.get_offset();
-                        queue.push_back(((pos.0 + offset.0, pos.1 + offset.1), direction));
-                    }
-                },
-                TileType::PositiveMirror => match incoming_direction {
-                    Direction::North => {
-                        let next_direction = Direction::East;
-                        let offset = next_direction.get_offset();
-                        queue.push_back(((pos.0 + offset.0, pos.1 + offset.1), next_direction));
-                    }
-                    Direction::South => {
-                        let next_direction = Direction::West;
-                        let offset = next_direction.get_offset();
-                        queue.push_back(((pos.0 + offset.0, pos.1 + offset.1), next_direction));
-                    }
-                    Direction::East => {
-                        let next_direction = Direction::North;
-                        let offset = next_direction.get_offset();
-                        queue.push_back(((pos.0 + offset.0, pos.1 + offset.1), next_direction));
-                    }
-                    Direction::West => {
-                        let next_direction = Direction::South;
-                        let offset = next_direction.get_offset();
-                        queue.push_back(((pos.0 + offset.0, pos.1 + offset.1), next_direction));
-                    }
-                },
-                TileType::NegativeMirror => match incoming_direction {
-                    Direction::North => {
-                        let next_direction = Direction::West;
-                        let offset = next_direction.get_offset();
-                        queue.push_back(((pos.0 + offset.0, pos.1 + offset.1), next_direction));
-                    }
-                    Direction::South => {
-                        let next_direction = Direction::East;
-                        let offset = next_direction.get_offset();
-                        queue.push_back(((pos.0 + offset.0, pos.1 + offset.1), next_direction));
-                    }
-                    Direction::East => {
-                        let next_direction = Direction::South;
-                        let offset = next_direction.get_offset();
-                        queue.push_back(((pos.0 + offset.0, pos.1 + offset.1), next_direction));
-                    }
-                    Direction::West => {
-                        let next_direction = Direction::North;
-                        let offset = next_direction.get_offset();
-                        queue.push_back(((pos.0 + offset.0, pos.1 + offset.1), next_direction));
+                        queue.push_back((direction.offset_by(pos, 1), direction));
                     }
                 },
+                TileType::PositiveMirror => {
+                    let next_direction = match incoming_direction {
+                        Direction::North | Direction::South => incoming_direction.turn_right(),
+                        Direction::East | Direction::West => incoming_direction.turn_left(),
+                    };
+                    queue.push_back((next_direction.offset_by(pos, 1), next_direction));
+                }
+                TileType::NegativeMirror => {
+                    let next_direction = match incoming_direction {
+                        Direction::North | Direction::South => incoming_direction.turn_left(),
+                        Direction::East | Direction::West => incoming_direction.turn_right(),
+                    };
+                    queue.push_back((next_direction.offset_by(pos, 1), next_direction));
+                }
             }
         }
     }
 
-    map.iter()
-        .filter(|(_, tile)| tile.incoming_light.len() > 0)
+    map.values()
+        .filter(|tile| tile.incoming_light.len() > 0)
         .count()
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum TileType {
-    Empty,
-    HorizontalSplitter,
-    VerticalSplitter,
-    PositiveMirror,
-    NegativeMirror,
-}
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-enum Direction {
-    North,
-    South,
-    East,
-    West,
-}
-
-impl Direction {
-    fn get_offset(&self) -> (i64, i64) {
-        match &self {
-            Self::North => (-1, 0),
-            Self::South => (1, 0),
-            Self::East => (0, 1),
-            Self::West => (0, -1),
-        }
-    }
-}
-
 #[derive(Debug, Clone)]
 struct Tile {
     tile_type: TileType,
@@ -156,6 +98,8 @@ struct Tile {
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::parse::ParseErrorKind;
+    use aoc_grid::GridError;
 
     #[test]
     fn test_part1() {
@@ -170,6 +114,23 @@ mod test {
 .|....-|.\\
 ..//.|....";
 
-        assert_eq!(part1(input), 46);
+        assert_eq!(part1(input), Ok(46));
+    }
+
+    #[test]
+    fn test_part1_ragged_line() {
+        let input = ".|..
+|.-.\\
+....";
+        let err = part1(input).unwrap_err();
+
+        assert_eq!(
+            err.kind,
+            ParseErrorKind::Grid(GridError::RaggedLine {
+                expected: 4,
+                found: 5
+            })
+        );
+        assert_eq!((err.line, err.column), (2, 1));
     }
 }
@@ -0,0 +1,34 @@
+//! Allocation budgets, so that a change that makes the beams allocate a lot more fails instead of
+//! going unnoticed. See [`testing::heap_profiler`] for how they are checked.
+
+use aoc_common::testing;
+use day_16::{generate, part1, part2};
+
+#[global_allocator]
+static ALLOC: dhat::Alloc = dhat::Alloc;
+
+const KIB: u64 = 1024;
+const MIB: u64 = 1024 * KIB;
+
+#[test]
+fn test_heap_budgets() {
+    let input = generate::generate(55, 0);
+
+    {
+        let _profiler = testing::heap_profiler();
+        part1::part1(&input).unwrap();
+        let stats = dhat::HeapStats::get();
+
+        dhat::assert!((stats.max_bytes as u64) < 512 * KIB, "part 1: {:?}", stats);
+        dhat::assert!(stats.total_bytes < MIB, "part 1: {:?}", stats);
+    }
+    {
+        let _profiler = testing::heap_profiler();
+        part2::part2(&input).unwrap();
+        let stats = dhat::HeapStats::get();
+
+        // Each of the 218 starts gets its own copy of the map. How many of them are alive at once
+        // depends on the number of rayon threads, so only the total is checked.
+        dhat::assert!(stats.total_bytes < 64 * MIB, "part 2: {:?}", stats);
+    }
+}
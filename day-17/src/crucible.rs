@@ -0,0 +1,134 @@
+use crate::parse::{ParseError, ParseErrorKind};
+use aoc_grid::{Direction, Grid, Point};
+
+pub fn parse_heat_loss_map(input: &str) -> Result<Grid<u8>, ParseError> {
+    Grid::parse(input, |c| c.to_digit(10).map(|digit| digit as u8))
+        .map_err(|err| err.map_kind(ParseErrorKind::Grid))
+}
+
+pub fn factory(map: &Grid<u8>) -> Point {
+    (map.height() - 1, map.width() - 1)
+}
+
+pub fn manhattan_distance(src: Point, dest: Point) -> u64 {
+    (src.0.abs_diff(dest.0) + src.1.abs_diff(dest.1)) as u64
+}
+
+/// How many blocks a crucible has to and may move in a straight line before turning.
+#[derive(Debug, Clone, Copy)]
+pub struct Crucible {
+    pub min_straight: usize,
+    pub max_straight: usize,
+}
+
+impl Crucible {
+    pub const REGULAR: Crucible = Crucible {
+        min_straight: 1,
+        max_straight: 3,
+    };
+    pub const ULTRA: Crucible = Crucible {
+        min_straight: 4,
+        max_straight: 10,
+    };
+
+    /// Moves one block at a time, so the search has to track how long it has gone straight for.
+    pub fn steps<'a>(
+        self,
+        map: &'a Grid<u8>,
+        state: Step,
+    ) -> impl Iterator<Item = (Step, u64)> + 'a {
+        Direction::ALL
+            .into_iter()
+            .filter(move |&d| match state.direction {
+                None => true,
+                Some(going) if d == going => state.straight_for < self.max_straight,
+                Some(going) => d != going.reverse() && self.can_stop(&state),
+            })
+            .filter_map(move |d| {
+                let pos = d
+                    .checked_offset(state.pos, 1)
+                    .filter(|&pos| map.contains(pos))?;
+                let straight_for = if state.direction == Some(d) {
+                    state.straight_for + 1
+                } else {
+                    1
+                };
+                let next = Step {
+                    pos,
+                    direction: Some(d),
+                    straight_for,
+                };
+                Some((next, map[pos] as u64))
+            })
+    }
+
+    /// A crucible that has not moved yet can stop too, for maps where the lava pool is the factory.
+    pub fn can_stop(self, state: &Step) -> bool {
+        state.direction.is_none() || state.straight_for >= self.min_straight
+    }
+
+    /// Turns and then skips ahead to every block it may turn at next, so only the direction it
+    /// is going in needs to be tracked.
+    pub fn turns<'a>(
+        self,
+        map: &'a Grid<u8>,
+        state: Turn,
+    ) -> impl Iterator<Item = (Turn, u64)> + 'a {
+        Direction::ALL
+            .into_iter()
+            .filter(move |&d| {
+                state
+                    .direction
+                    .is_none_or(|going| d != going && d != going.reverse())
+            })
+            .flat_map(move |d| {
+                (1..=self.max_straight)
+                    .map_while(move |by| {
+                        d.checked_offset(state.pos, by)
+                            .filter(|&pos| map.contains(pos))
+                    })
+                    .scan(0, |cost, pos| {
+                        *cost += map[pos] as u64;
+                        Some((pos, *cost))
+                    })
+                    .skip(self.min_straight - 1)
+                    .map(move |(pos, cost)| {
+                        let next = Turn {
+                            pos,
+                            direction: Some(d),
+                        };
+                        (next, cost)
+                    })
+            })
+    }
+}
+
+/// `direction` is only `None` at the lava pool, where the crucible has not moved yet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Step {
+    pub pos: Point,
+    pub direction: Option<Direction>,
+    pub straight_for: usize,
+}
+
+impl Step {
+    pub const START: Step = Step {
+        pos: (0, 0),
+        direction: None,
+        straight_for: 0,
+    };
+}
+
+/// `direction` is only `None` at the lava pool, where the crucible has not moved yet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Turn {
+    pub pos: Point,
+    pub direction: Option<Direction>,
+}
+
+impl Turn {
+    pub const START: Turn = Turn {
+        pos: (0, 0),
+        direction: None,
+    };
+}
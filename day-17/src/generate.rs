@@ -0,0 +1,33 @@
+use aoc_generate::{rand::Rng, rng};
+
+/// `size` x `size` map with a heat loss of 1 to 9 per block. The ultra crucible needs at least
+/// 5x5 blocks to reach the factory.
+pub fn generate(size: usize, seed: u64) -> String {
+    assert!(
+        size >= 5,
+        "the ultra crucible cannot reach the factory on a {0}x{0} map",
+        size
+    );
+    let mut rng = rng(seed);
+
+    (0..size)
+        .map(|_| {
+            (0..size)
+                .map(|_| char::from_digit(rng.gen_range(1..=9), 10).unwrap())
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::SOLUTIONS;
+    use aoc_common::testing;
+
+    #[test]
+    fn test_generated_input_is_solved() {
+        testing::check_generated(SOLUTIONS, generate, &[(5, 0, &["23", "38"]), (30, 1, &[])]);
+    }
+}
@@ -1,3 +1,6 @@
+mod crucible;
+pub mod generate;
+pub mod parse;
 pub mod part1;
 pub mod part1_astar;
 pub mod part1_astar_with_skips;
@@ -5,3 +8,63 @@ pub mod part1_vector_astar_with_skips;
 pub mod part2;
 pub mod part2_astar_with_skips;
 pub mod part2_vector_astar_with_skips;
+
+aoc_common::solutions!(17 =>
+    part1::Part1,
+    part1_astar::Part1,
+    part1_astar_with_skips::Part1,
+    part1_vector_astar_with_skips::Part1,
+    part2::Part2,
+    part2_astar_with_skips::Part2,
+    part2_vector_astar_with_skips::Part2,
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use proptest::prelude::*;
+    use rstest::rstest;
+
+    fn answers(part: u8, input: &str) -> Vec<(&'static str, Result<String, String>)> {
+        SOLUTIONS
+            .iter()
+            .filter(|solution| solution.part == part)
+            .map(|solution| {
+                let answer = (solution.run)(input).map_err(|err| err.to_string());
+                (solution.variant, answer)
+            })
+            .collect()
+    }
+
+    fn heat_loss_map() -> impl Strategy<Value = String> {
+        (1..=15usize, 1..=15usize).prop_flat_map(|(width, height)| {
+            let row = prop::collection::vec(1..=9u8, width)
+                .prop_map(|row| row.iter().map(|heat_loss| heat_loss.to_string()).collect());
+            prop::collection::vec(row, height).prop_map(|rows: Vec<String>| rows.join("\n"))
+        })
+    }
+
+    /// A single block is both the lava pool and the factory, so the crucible gets there without
+    /// losing any heat.
+    #[rstest]
+    #[case("1")]
+    #[case("5")]
+    fn test_lava_pool_is_factory(#[case] input: &str, #[values(1, 2)] part: u8) {
+        for (variant, answer) in answers(part, input) {
+            assert_eq!(answer, Ok("0".to_string()), "variant {} disagrees", variant);
+        }
+    }
+
+    proptest! {
+        // Small maps might not have a path for the ultra crucible, in which case every variant
+        // has to report that instead.
+        #[test]
+        fn test_variants_agree(input in heat_loss_map(), part in 1..=2u8) {
+            let answers = answers(part, &input);
+            let (_, expected) = &answers[0];
+            for (variant, answer) in &answers[1..] {
+                prop_assert_eq!(answer, expected, "variant {} disagrees", variant);
+            }
+        }
+    }
+}
@@ -0,0 +1,47 @@
+use aoc_generate::{rng, Outline};
+use aoc_grid::Direction;
+
+/// Largest distance that fits in the 5 hex digits of a color.
+const MAX_HEX_DISTANCE: usize = 0xfffff;
+
+/// Dig plan around a lagoon of roughly `size` x `size` trenches. The colors encode the same
+/// shape stretched to distances of up to `0xfffff`, so neither plan crosses itself.
+pub fn generate(size: usize, seed: u64) -> String {
+    assert!(size > 0, "the lagoon needs at least one trench");
+    let mut rng = rng(seed);
+
+    let outline = Outline::random(&mut rng, size, size);
+    let plan = outline.stretch(&mut rng, 10);
+    let colors = outline.stretch(&mut rng, MAX_HEX_DISTANCE / (size + 1));
+
+    plan.moves
+        .iter()
+        .zip(&colors.moves)
+        .map(|(&(direction, distance), &(_, color_distance))| {
+            let (letter, digit) = match direction {
+                Direction::East => ('R', 0),
+                Direction::South => ('D', 1),
+                Direction::West => ('L', 2),
+                Direction::North => ('U', 3),
+            };
+            format!("{} {} (#{:05x}{})", letter, distance, color_distance, digit)
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::SOLUTIONS;
+    use aoc_common::testing;
+
+    #[test]
+    fn test_generated_input_is_solved() {
+        testing::check_generated(
+            SOLUTIONS,
+            generate,
+            &[(1, 0, &["27", "6254495777"]), (100, 1, &[])],
+        );
+    }
+}
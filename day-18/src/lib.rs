@@ -1,3 +1,7 @@
 #![feature(iter_map_windows)]
+pub mod generate;
+pub mod parse;
 pub mod part1;
 pub mod part2;
+
+aoc_common::solutions!(18 => part1::Part1, part2::Part2);
@@ -1,31 +1,44 @@
+use crate::parse::{self, ParseError, ParseErrorKind};
+use aoc_common::Solution;
+use aoc_grid::Direction;
 use nom::{
     character::complete::{self, alpha1, space1},
     sequence::tuple,
-    IResult,
 };
 
-pub fn part1(input: &str) -> i64 {
+pub struct Part1;
+
+impl Solution for Part1 {
+    const PART: u8 = 1;
+
+    type Model<'a> = Vec<DigStep>;
+    type Answer = i64;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Model<'_>, Self::Error> {
+        input.lines().map(|line| parse_line(input, line)).collect()
+    }
+
+    fn solve(steps: Self::Model<'_>) -> Result<Self::Answer, Self::Error> {
+        Ok(lagoon_size(&steps))
+    }
+}
+
+pub fn part1(input: &str) -> Result<i64, ParseError> {
+    Part1::run(input)
+}
+
+fn lagoon_size(steps: &[DigStep]) -> i64 {
     const STARTING_POS: (i64, i64) = (0, 0);
-    let (map, _) = input
-        .lines()
-        .map(|line| {
-            let (_, step) = parse_line(line).expect("Line did not parse correctly");
-            step
-        })
-        .fold(
-            (vec![Boundary { pos: STARTING_POS }], STARTING_POS),
-            |(mut acc, curr_pos), step| {
-                let next_pos = match step.direction {
-                    Direction::Up(length) => (curr_pos.0 - length as i64, curr_pos.1),
-                    Direction::Down(length) => (curr_pos.0 + length as i64, curr_pos.1),
-                    Direction::Left(length) => (curr_pos.0, curr_pos.1 - length as i64),
-                    Direction::Right(length) => (curr_pos.0, curr_pos.1 + length as i64),
-                };
-
-                acc.push(Boundary { pos: next_pos });
-                (acc, next_pos)
-            },
-        );
+    let (map, _) = steps.iter().fold(
+        (vec![Boundary { pos: STARTING_POS }], STARTING_POS),
+        |(mut acc, curr_pos), step| {
+            let next_pos = step.direction.offset_by(curr_pos, step.length as i64);
+
+            acc.push(Boundary { pos: next_pos });
+            (acc, next_pos)
+        },
+    );
 
     let area = map
         .iter()
@@ -63,29 +76,37 @@ impl PartialOrd for Boundary {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum Direction {
-    Up(u64),
-    Down(u64),
-    Left(u64),
-    Right(u64),
-}
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-struct DigStep {
+pub struct DigStep {
     direction: Direction,
+    length: u64,
 }
 
-fn parse_line(input: &str) -> IResult<&str, DigStep> {
-    let (input, (direction, _, amount, _)) = tuple((alpha1, space1, complete::u64, space1))(input)?;
+fn parse_line(input: &str, line: &str) -> Result<DigStep, ParseError> {
+    let (_, (direction, _, amount, _)) = tuple((
+        alpha1::<_, nom::error::Error<_>>,
+        space1,
+        complete::u64,
+        space1,
+    ))(line)
+    .map_err(parse::nom_error(input, ParseErrorKind::InvalidStep))?;
     let direction = match direction {
-        "R" => Direction::Right(amount),
-        "L" => Direction::Left(amount),
-        "U" => Direction::Up(amount),
-        "D" => Direction::Down(amount),
-        c => panic!("unknown character found: {}", c),
+        "R" => Direction::East,
+        "L" => Direction::West,
+        "U" => Direction::North,
+        "D" => Direction::South,
+        _ => {
+            return Err(ParseError::new(
+                input,
+                direction,
+                ParseErrorKind::UnknownDirection,
+            ))
+        }
     };
 
-    IResult::Ok((input, DigStep { direction }))
+    Ok(DigStep {
+        direction,
+        length: amount,
+    })
 }
 
 #[cfg(test)]
@@ -109,6 +130,16 @@ U 3 (#a77fa3)
 L 2 (#015232)
 U 2 (#7a21e3)";
 
-        assert_eq!(part1(input), 62);
+        assert_eq!(part1(input), Ok(62));
+    }
+
+    #[test]
+    fn test_part1_unknown_direction() {
+        let input = "R 6 (#70c710)
+X 5 (#0dc571)";
+        let err = part1(input).unwrap_err();
+
+        assert_eq!(err.kind, ParseErrorKind::UnknownDirection);
+        assert_eq!((err.line, err.column), (2, 1));
     }
 }
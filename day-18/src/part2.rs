@@ -1,3 +1,6 @@
+use crate::parse::{self, ParseError, ParseErrorKind};
+use aoc_common::Solution;
+use aoc_grid::Direction;
 use nom::{
     bytes::complete::{tag, take_while_m_n},
     character::complete::{self, alpha1, space1},
@@ -6,28 +9,39 @@ use nom::{
     IResult,
 };
 
-pub fn part2(input: &str) -> i64 {
+pub struct Part2;
+
+impl Solution for Part2 {
+    const PART: u8 = 2;
+
+    type Model<'a> = Vec<DigStep>;
+    type Answer = i64;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Model<'_>, Self::Error> {
+        input.lines().map(|line| parse_line(input, line)).collect()
+    }
+
+    fn solve(steps: Self::Model<'_>) -> Result<Self::Answer, Self::Error> {
+        Ok(lagoon_size(&steps))
+    }
+}
+
+pub fn part2(input: &str) -> Result<i64, ParseError> {
+    Part2::run(input)
+}
+
+fn lagoon_size(steps: &[DigStep]) -> i64 {
     let starting_pos = (0, 0);
-    let (map, _) = input
-        .lines()
-        .map(|line| {
-            let (_, step) = parse_line(line).expect("Line did not parse correctly");
-            step
-        })
-        .fold(
-            (vec![Boundary { pos: starting_pos }], starting_pos),
-            |(mut acc, curr_pos), step| {
-                let next_pos = match step.direction {
-                    Direction::Up(length) => (curr_pos.0 - length as i64, curr_pos.1),
-                    Direction::Down(length) => (curr_pos.0 + length as i64, curr_pos.1),
-                    Direction::Left(length) => (curr_pos.0, curr_pos.1 - length as i64),
-                    Direction::Right(length) => (curr_pos.0, curr_pos.1 + length as i64),
-                };
-
-                acc.push(Boundary { pos: next_pos });
-                (acc, next_pos)
-            },
-        );
+    let (map, _) = steps.iter().fold(
+        (vec![Boundary { pos: starting_pos }], starting_pos),
+        |(mut acc, curr_pos), step| {
+            let next_pos = step.direction.offset_by(curr_pos, step.length as i64);
+
+            acc.push(Boundary { pos: next_pos });
+            (acc, next_pos)
+        },
+    );
 
     let area = map
         .iter()
@@ -78,14 +92,6 @@ impl PartialOrd for DiagonalOrder {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum Direction {
-    Up(u64),
-    Down(u64),
-    Left(u64),
-    Right(u64),
-}
-
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 struct Color {
     red: u8,
@@ -94,8 +100,9 @@ struct Color {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-struct DigStep {
+pub struct DigStep {
     direction: Direction,
+    length: u64,
 }
 
 fn from_hex(input: &str) -> Result<u64, std::num::ParseIntError> {
@@ -111,28 +118,38 @@ fn hex_primary(input: &str, length: usize) -> IResult<&str, u64> {
 }
 
 // modified version of the hex color parser from the nom docs
-fn hex_color(input: &str) -> IResult<&str, DigStep> {
+fn hex_color(input: &str) -> IResult<&str, (u64, &str)> {
     let (input, _) = tag("#")(input)?;
     let (input, amount) = hex_primary(input, 5)?;
-    let (input, direction) = hex_primary(input, 1)?;
+    let (input, direction) = take_while_m_n(1, 1, is_hex_digit)(input)?;
 
-    let direction = match direction {
-        0 => Direction::Right(amount),
-        1 => Direction::Down(amount),
-        2 => Direction::Left(amount),
-        3 => Direction::Up(amount),
-        val => panic!("unknown direction value found: {}", val),
-    };
-    Ok((input, DigStep { direction }))
+    Ok((input, (amount, direction)))
 }
 
-fn parse_line(input: &str) -> IResult<&str, DigStep> {
-    let (input, (_, dig_stap, _)) = preceded(
+fn parse_line(input: &str, line: &str) -> Result<DigStep, ParseError> {
+    let (_, (_, (amount, direction), _)) = preceded(
         tuple((alpha1, space1, complete::u64, space1)),
         tuple((tag("("), hex_color, tag(")"))),
-    )(input)?;
+    )(line)
+    .map_err(parse::nom_error(input, ParseErrorKind::InvalidStep))?;
 
-    IResult::Ok((input, dig_stap))
+    let direction = match direction {
+        "0" => Direction::East,
+        "1" => Direction::South,
+        "2" => Direction::West,
+        "3" => Direction::North,
+        _ => {
+            return Err(ParseError::new(
+                input,
+                direction,
+                ParseErrorKind::UnknownDirection,
+            ))
+        }
+    };
+    Ok(DigStep {
+        direction,
+        length: amount,
+    })
 }
 
 #[cfg(test)]
@@ -156,6 +173,6 @@ U 3 (#a77fa3)
 L 2 (#015232)
 U 2 (#7a21e3)";
 
-        assert_eq!(part2(input), 952408144115);
+        assert_eq!(part2(input), Ok(952408144115));
     }
 }
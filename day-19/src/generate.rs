@@ -0,0 +1,81 @@
+use aoc_generate::{
+    rand::{seq::index, Rng},
+    rng, unique_names, LOWERCASE,
+};
+use std::collections::VecDeque;
+
+static CATEGORIES: &[u8] = b"xmas";
+
+/// `size` workflows and `size` parts.
+///
+/// Workflows form a tree below `in`, so every part ends up accepted or rejected without going
+/// around in circles.
+pub fn generate(size: usize, seed: u64) -> String {
+    assert!(size > 0, "there has to be an `in` workflow");
+    let mut rng = rng(seed);
+
+    let mut names = vec!["in".to_string()];
+    names.extend(unique_names(&mut rng, size - 1, 3, LOWERCASE));
+
+    let mut workflows = vec![];
+    let mut queue = VecDeque::from([0]);
+    let mut next_unused = 1;
+    while let Some(workflow) = queue.pop_front() {
+        let rules = rng.gen_range(1..=3);
+        let mut targets = (0..=rules)
+            .map(|_| {
+                if rng.gen_bool(0.5) {
+                    "A".to_string()
+                } else {
+                    "R".to_string()
+                }
+            })
+            .collect::<Vec<_>>();
+        // Enough workflows have to be handed out to reach every one of them.
+        let children = rng.gen_range(1..=rules + 1).min(size - next_unused);
+        for target in index::sample(&mut rng, rules + 1, children) {
+            targets[target] = names[next_unused].clone();
+            queue.push_back(next_unused);
+            next_unused += 1;
+        }
+
+        let fallback = targets.pop().unwrap();
+        let rules = targets
+            .iter()
+            .map(|target| {
+                let category = CATEGORIES[rng.gen_range(0..CATEGORIES.len())] as char;
+                let comparison = if rng.gen_bool(0.5) { '<' } else { '>' };
+                let value = rng.gen_range(1..=4000);
+                format!("{}{}{}:{}", category, comparison, value, target)
+            })
+            .chain([fallback])
+            .collect::<Vec<_>>();
+        workflows.push(format!("{}{{{}}}", names[workflow], rules.join(",")));
+    }
+
+    let parts = (0..size).map(|_| {
+        let [x, m, a, s] = [(); 4].map(|_| rng.gen_range(1..=4000));
+        format!("{{x={},m={},a={},s={}}}", x, m, a, s)
+    });
+    format!(
+        "{}\n\n{}",
+        workflows.join("\n"),
+        parts.collect::<Vec<_>>().join("\n")
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::SOLUTIONS;
+    use aoc_common::testing;
+
+    #[test]
+    fn test_generated_input_is_solved() {
+        testing::check_generated(
+            SOLUTIONS,
+            generate,
+            &[(1, 0, &["0", "56267776000000"]), (100, 1, &[])],
+        );
+    }
+}
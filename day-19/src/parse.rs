@@ -0,0 +1,265 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::{self, Display},
+};
+
+use nom::{
+    bytes::complete::{is_not, tag, take_until1},
+    character::complete::{self, char, one_of},
+    sequence::{delimited, pair, tuple},
+};
+
+pub type ParseError = aoc_common::ParseError<ParseErrorKind>;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    MissingParts,
+    InvalidWorkflow,
+    InvalidCondition,
+    UnknownCategory,
+    MissingFallback,
+    UnknownWorkflow,
+    MissingStartWorkflow,
+    InvalidPart,
+}
+
+impl Display for ParseErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseErrorKind::MissingParts => {
+                write!(f, "expected a blank line between workflows and parts")
+            }
+            ParseErrorKind::InvalidWorkflow => {
+                write!(f, "expected a workflow as `<name>{{<rules>}}`")
+            }
+            ParseErrorKind::InvalidCondition => {
+                write!(
+                    f,
+                    "expected a condition as `<category><`<` or `>`><rating>`"
+                )
+            }
+            ParseErrorKind::UnknownCategory => write!(f, "expected `x`, `m`, `a` or `s`"),
+            ParseErrorKind::MissingFallback => {
+                write!(f, "last rule of a workflow must not have a condition")
+            }
+            ParseErrorKind::UnknownWorkflow => write!(f, "no workflow with this name"),
+            ParseErrorKind::MissingStartWorkflow => write!(f, "no workflow named `in`"),
+            ParseErrorKind::InvalidPart => write!(f, "expected a part as `{{x=<rating>,...}}`"),
+        }
+    }
+}
+
+/// Points at the rest of the text nom stopped at.
+pub fn nom_error<'a>(
+    input: &'a str,
+    kind: ParseErrorKind,
+) -> impl FnOnce(nom::Err<nom::error::Error<&str>>) -> ParseError + 'a {
+    move |err| match err {
+        nom::Err::Error(err) | nom::Err::Failure(err) => {
+            let len = err.input.find('\n').unwrap_or(err.input.len());
+            ParseError::new(input, &err.input[..len], kind)
+        }
+        nom::Err::Incomplete(_) => ParseError::new(input, &input[input.len()..], kind),
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct System<'a> {
+    /// Every workflow by name, each one that is sent to is known to exist and `in` is among them.
+    pub rules: HashMap<&'a str, Rule<'a>>,
+    pub parts: Vec<Part>,
+}
+
+pub fn parse_system(input: &str) -> Result<System<'_>, ParseError> {
+    let (rules, parts) = input.split_once("\n\n").ok_or_else(|| {
+        ParseError::new(input, &input[input.len()..], ParseErrorKind::MissingParts)
+    })?;
+    let rules = parse_rules(input, rules)?;
+    let parts = parts
+        .lines()
+        .map(|line| parse_part(input, line))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(System { rules, parts })
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Part {
+    pub x_cool: u64,
+    pub musical: u64,
+    pub aerodynamic: u64,
+    pub shiny: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Property {
+    XCool(u64),
+    Musical(u64),
+    Aerodynamic(u64),
+    Shiny(u64),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Condition {
+    GreaterThan(Property),
+    LessThan(Property),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operation<'a> {
+    Goto(&'a str),
+    Accept,
+    Reject,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Case<'a> {
+    /// `None` for the fallback, the last case of every workflow.
+    pub conditional: Option<Condition>,
+    pub operation: Operation<'a>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rule<'a> {
+    pub name: &'a str,
+    pub cases: Vec<Case<'a>>,
+}
+
+fn parse_property(input: &str, property: &str, amount: u64) -> Result<Property, ParseError> {
+    match property {
+        "x" => Ok(Property::XCool(amount)),
+        "m" => Ok(Property::Musical(amount)),
+        "a" => Ok(Property::Aerodynamic(amount)),
+        "s" => Ok(Property::Shiny(amount)),
+        _ => Err(ParseError::new(
+            input,
+            property,
+            ParseErrorKind::UnknownCategory,
+        )),
+    }
+}
+
+fn parse_conditional(input: &str, conditional: &str) -> Result<Condition, ParseError> {
+    let (_, (property_initial, inequality, property_amount)) =
+        tuple((is_not("<>"), one_of("<>"), complete::u64))(conditional)
+            .map_err(nom_error(input, ParseErrorKind::InvalidCondition))?;
+    let property = parse_property(input, property_initial, property_amount)?;
+    match inequality {
+        '>' => Ok(Condition::GreaterThan(property)),
+        _ => Ok(Condition::LessThan(property)),
+    }
+}
+
+fn parse_operation(input: &str) -> Operation<'_> {
+    let operation = match input {
+        "A" => Operation::Accept,
+        "R" => Operation::Reject,
+        name => Operation::Goto(name),
+    };
+    operation
+}
+
+fn parse_case<'a>(input: &str, case: &'a str) -> Result<Case<'a>, ParseError> {
+    if let Some((conditional, operation)) = case.split_once(':') {
+        let conditional = parse_conditional(input, conditional)?;
+        let operation = parse_operation(operation);
+        Ok(Case {
+            conditional: Some(conditional),
+            operation,
+        })
+    } else {
+        let operation = parse_operation(case);
+        Ok(Case {
+            conditional: None,
+            operation,
+        })
+    }
+}
+
+fn parse_rule<'a>(input: &str, line: &'a str) -> Result<Rule<'a>, ParseError> {
+    let (_, (name, rules_str)) =
+        pair(take_until1("{"), delimited(tag("{"), is_not("}"), tag("}")))(line)
+            .map_err(nom_error(input, ParseErrorKind::InvalidWorkflow))?;
+    let cases = rules_str
+        .split(',')
+        .map(|case| parse_case(input, case))
+        .collect::<Result<Vec<_>, _>>()?;
+    if cases.last().is_some_and(|case| case.conditional.is_some()) {
+        let last_case = rules_str.rsplit(',').next().unwrap_or(rules_str);
+        return Err(ParseError::new(
+            input,
+            last_case,
+            ParseErrorKind::MissingFallback,
+        ));
+    }
+
+    Ok(Rule { name, cases })
+}
+
+/// Every workflow by name, checking that each one that is sent to exists.
+fn parse_rules<'a>(input: &str, rules: &'a str) -> Result<HashMap<&'a str, Rule<'a>>, ParseError> {
+    let rules_list = rules
+        .lines()
+        .map(|line| parse_rule(input, line))
+        .collect::<Result<Vec<_>, _>>()?;
+    let names = rules_list
+        .iter()
+        .map(|rule| rule.name)
+        .collect::<HashSet<_>>();
+
+    if !names.contains("in") {
+        return Err(ParseError::new(
+            input,
+            &rules[rules.len()..],
+            ParseErrorKind::MissingStartWorkflow,
+        ));
+    }
+    for case in rules_list.iter().flat_map(|rule| &rule.cases) {
+        if let Operation::Goto(next_rule) = case.operation {
+            if !names.contains(next_rule) {
+                return Err(ParseError::new(
+                    input,
+                    next_rule,
+                    ParseErrorKind::UnknownWorkflow,
+                ));
+            }
+        }
+    }
+
+    Ok(rules_list
+        .into_iter()
+        .map(|rule| (rule.name, rule))
+        .collect())
+}
+
+fn parse_property_assignment(input: &str, property: &str) -> Result<Property, ParseError> {
+    let (_, (property_initial, _, property_amount)) =
+        tuple((is_not("="), char('='), complete::u64))(property)
+            .map_err(nom_error(input, ParseErrorKind::InvalidPart))?;
+    parse_property(input, property_initial, property_amount)
+}
+
+fn parse_part(input: &str, line: &str) -> Result<Part, ParseError> {
+    let (_, property_str) = delimited(tag("{"), is_not("}"), tag("}"))(line)
+        .map_err(nom_error(input, ParseErrorKind::InvalidPart))?;
+    property_str
+        .split(',')
+        .map(|property| parse_property_assignment(input, property))
+        .try_fold(
+            Part {
+                x_cool: 0,
+                musical: 0,
+                aerodynamic: 0,
+                shiny: 0,
+            },
+            |mut acc, property| {
+                match property? {
+                    Property::XCool(amount) => acc.x_cool = amount,
+                    Property::Musical(amount) => acc.musical = amount,
+                    Property::Aerodynamic(amount) => acc.aerodynamic = amount,
+                    Property::Shiny(amount) => acc.shiny = amount,
+                }
+                Ok(acc)
+            },
+        )
+}
@@ -1,38 +1,35 @@
-use std::collections::HashMap;
+use crate::parse::{self, Condition, Operation, ParseError, Property, System};
+use aoc_common::Solution;
 
-use itertools::Itertools;
-use nom::{
-    bytes::complete::{is_not, tag, take_until1},
-    character::complete::{self, one_of},
-    sequence::delimited,
-    IResult,
-};
+pub struct Part1;
 
-pub fn part1(input: &str) -> u64 {
-    let (rules, parts) = input
-        .split_once("\n\n")
-        .expect("Input did not have double newlines");
+impl Solution for Part1 {
+    const PART: u8 = 1;
 
-    let rules = rules
-        .lines()
-        .map(|line| {
-            let (_, rule) = parse_rule(line).expect("Line did not parse correctly");
-            (rule.name, rule)
-        })
-        .collect::<HashMap<_, _>>();
+    type Model<'a> = System<'a>;
+    type Answer = u64;
+    type Error = ParseError;
 
-    let parts = parts
-        .lines()
-        .map(|line| {
-            let (_, part) = parse_part(line).expect("Could not parse part line");
-            part
-        })
-        .collect_vec();
+    fn parse(input: &str) -> Result<Self::Model<'_>, Self::Error> {
+        parse::parse_system(input)
+    }
+
+    fn solve(system: Self::Model<'_>) -> Result<Self::Answer, Self::Error> {
+        Ok(accepted_ratings(&system))
+    }
+}
 
-    parts
+pub fn part1(input: &str) -> Result<u64, ParseError> {
+    Part1::run(input)
+}
+
+fn accepted_ratings(system: &System) -> u64 {
+    let rules = &system.rules;
+    system
+        .parts
         .iter()
         .filter(|part| {
-            let mut curr = rules.get("in").expect("no start node");
+            let mut curr = &rules["in"];
 
             loop {
                 let satisfied_case = curr
@@ -77,11 +74,9 @@ pub fn part1(input: &str) -> u64 {
                             true
                         }
                     })
-                    .expect("case not found");
+                    .expect("last case of a rule is unconditional");
                 match satisfied_case.operation {
-                    Operation::Goto(next_rule) => {
-                        curr = rules.get(next_rule).expect("no start node")
-                    }
+                    Operation::Goto(next_rule) => curr = &rules[next_rule],
                     Operation::Accept => return true,
                     Operation::Reject => return false,
                 }
@@ -95,153 +90,12 @@ pub fn part1(input: &str) -> u64 {
         })
 }
 
-struct Part {
-    x_cool: u64,
-    musical: u64,
-    aerodynamic: u64,
-    shiny: u64,
-}
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum Property {
-    XCool(u64),
-    Musical(u64),
-    Aerodynamic(u64),
-    Shiny(u64),
-}
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum Condition {
-    GreaterThan(Property),
-    LessThan(Property),
-}
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum Operation<'a> {
-    Goto(&'a str),
-    Accept,
-    Reject,
-}
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-struct Case<'a> {
-    conditional: Option<Condition>,
-    operation: Operation<'a>,
-}
-#[derive(Debug, Clone, PartialEq, Eq)]
-struct Rule<'a> {
-    name: &'a str,
-    cases: Vec<Case<'a>>,
-}
-
-fn parse_property(property: &str, amount: u64) -> Result<Property, &'static str> {
-    match property {
-        "x" => Ok(Property::XCool(amount)),
-        "m" => Ok(Property::Musical(amount)),
-        "a" => Ok(Property::Aerodynamic(amount)),
-        "s" => Ok(Property::Shiny(amount)),
-        _ => Err("Not a valid property"),
-    }
-}
-
-fn parse_conditional(input: &str) -> IResult<&str, Condition> {
-    let (input, property_initial) = is_not("<>")(input)?;
-    let (input, inequality) = one_of("<>")(input)?;
-    let (input, property_amount) = complete::u64(input)?;
-    let property =
-        parse_property(property_initial, property_amount).expect("property was not valid");
-    match inequality {
-        '>' => Ok((input, Condition::GreaterThan(property))),
-        '<' => Ok((input, Condition::LessThan(property))),
-        _ => panic!("idk how to create nom error lmao"),
-    }
-}
-
-fn parse_operation(input: &str) -> Operation {
-    let operation = match input {
-        "A" => Operation::Accept,
-        "R" => Operation::Reject,
-        name => Operation::Goto(name),
-    };
-    operation
-}
-
-fn parse_case(input: &str) -> IResult<&str, Case> {
-    if let Some((conditional, operation)) = input.split_once(':') {
-        let (_, conditional) = parse_conditional(conditional)?;
-        let operation = parse_operation(operation);
-        Ok((
-            "",
-            Case {
-                conditional: Some(conditional),
-                operation,
-            },
-        ))
-    } else {
-        let operation = parse_operation(input);
-        Ok((
-            "",
-            Case {
-                conditional: None,
-                operation,
-            },
-        ))
-    }
-}
-
-fn parse_rule(input: &str) -> IResult<&str, Rule> {
-    let (input, name) = take_until1("{")(input)?;
-    let (input, rules_str) = delimited(tag("{"), is_not("}"), tag("}"))(input)?;
-    let mut cases = vec![];
-    for case in rules_str.split(',') {
-        let (_, case) = parse_case(case)?;
-        cases.push(case);
-    }
-
-    Ok((input, Rule { name, cases }))
-}
-
-fn parse_property_assignment(input: &str) -> IResult<&str, Property> {
-    let (input, property_initial) = is_not("=")(input)?;
-    let (input, _) = one_of("=")(input)?;
-    let (input, property_amount) = complete::u64(input)?;
-    let property =
-        parse_property(property_initial, property_amount).expect("Property could not be parsed");
-    Ok((input, property))
-}
-
-fn parse_part(input: &str) -> IResult<&str, Part> {
-    let (input, property_str) = delimited(tag("{"), is_not("}"), tag("}"))(input)?;
-    let part = property_str
-        .split(',')
-        .map(|property| {
-            let (_, property) =
-                parse_property_assignment(property).expect("Property could not be parsed");
-            property
-        })
-        .fold(
-            Part {
-                x_cool: 0,
-                musical: 0,
-                aerodynamic: 0,
-                shiny: 0,
-            },
-            |mut acc, property| {
-                match property {
-                    Property::XCool(amount) => acc.x_cool = amount,
-                    Property::Musical(amount) => acc.musical = amount,
-                    Property::Aerodynamic(amount) => acc.aerodynamic = amount,
-                    Property::Shiny(amount) => acc.shiny = amount,
-                }
-                acc
-            },
-        );
-    Ok((input, part))
-}
-
 #[cfg(test)]
 mod test {
+    use rstest::rstest;
+
     use super::*;
+    use crate::parse::ParseErrorKind;
 
     #[test]
     fn test_part1() {
@@ -263,6 +117,21 @@ hdj{m>838:A,pv}
 {x=2461,m=1339,a=466,s=291}
 {x=2127,m=1623,a=2188,s=1013}";
 
-        assert_eq!(part1(input), 19114);
+        assert_eq!(part1(input), Ok(19114));
+    }
+
+    #[rstest]
+    #[case("in{s<1351:px,A}\n\n{x=787,m=2655,a=1222,s=2876}", ParseErrorKind::UnknownWorkflow, (1, 11))]
+    #[case("in{s<1351:A,R}\n\n{x=787,q=2655,a=1222,s=2876}", ParseErrorKind::UnknownCategory, (3, 8))]
+    #[case("in{s<1351:A,x>2:R}\n\n{x=787,m=2655,a=1222,s=2876}", ParseErrorKind::MissingFallback, (1, 13))]
+    fn test_part1_failure(
+        #[case] input: &str,
+        #[case] expected: ParseErrorKind,
+        #[case] position: (usize, usize),
+    ) {
+        let err = part1(input).unwrap_err();
+
+        assert_eq!(err.kind, expected);
+        assert_eq!((err.line, err.column), position);
     }
 }
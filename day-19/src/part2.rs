@@ -1,27 +1,31 @@
-use std::{
-    collections::{HashMap, VecDeque},
-    u16,
-};
+use crate::parse::{self, Condition, Operation, ParseError, Property, System};
+use aoc_common::Solution;
+use std::collections::VecDeque;
 
-use nom::{
-    bytes::complete::{is_not, tag, take_until1},
-    character::complete::{self, one_of},
-    sequence::delimited,
-    IResult,
-};
+pub struct Part2;
 
-pub fn part2(input: &str) -> u64 {
-    let (rules, _) = input
-        .split_once("\n\n")
-        .expect("Input did not have double newlines");
+impl Solution for Part2 {
+    const PART: u8 = 2;
 
-    let rules = rules
-        .lines()
-        .map(|line| {
-            let (_, rule) = parse_rule(line).expect("Line did not parse correctly");
-            (rule.name, rule)
-        })
-        .collect::<HashMap<_, _>>();
+    type Model<'a> = System<'a>;
+    type Answer = u64;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Model<'_>, Self::Error> {
+        parse::parse_system(input)
+    }
+
+    fn solve(system: Self::Model<'_>) -> Result<Self::Answer, Self::Error> {
+        Ok(accepted_combinations(&system))
+    }
+}
+
+pub fn part2(input: &str) -> Result<u64, ParseError> {
+    Part2::run(input)
+}
+
+fn accepted_combinations(system: &System) -> u64 {
+    let rules = &system.rules;
 
     let mut queue = VecDeque::from([(
         "in",
@@ -35,9 +39,7 @@ pub fn part2(input: &str) -> u64 {
     let mut accepted_combination_count: u64 = 0;
 
     while let Some((rule_name, mut property_ranges)) = queue.pop_front() {
-        let curr = rules
-            .get(rule_name)
-            .expect("Rule name was not found in rule map");
+        let curr = &rules[rule_name];
         let accepted = curr.cases.iter().filter_map(|case| {
             if let Some(conditional) = case.conditional {
                 match conditional {
@@ -173,10 +175,10 @@ pub fn part2(input: &str) -> u64 {
 
         accepted_combination_count += accepted
             .map(|part_range| {
-                (part_range.x_cool.1 - part_range.x_cool.0 + 1) as u64
-                    * (part_range.musical.1 - part_range.musical.0 + 1) as u64
-                    * (part_range.aerodynamic.1 - part_range.aerodynamic.0 + 1) as u64
-                    * (part_range.shiny.1 - part_range.shiny.0 + 1) as u64
+                (part_range.x_cool.1 + 1 - part_range.x_cool.0)
+                    * (part_range.musical.1 + 1 - part_range.musical.0)
+                    * (part_range.aerodynamic.1 + 1 - part_range.aerodynamic.0)
+                    * (part_range.shiny.1 + 1 - part_range.shiny.0)
             })
             .sum::<u64>();
     }
@@ -184,26 +186,30 @@ pub fn part2(input: &str) -> u64 {
     accepted_combination_count
 }
 
+// A range that lies entirely on one side of the boundary leaves an empty range `(n, n - 1)` on the
+// other side, which then counts as zero combinations.
 fn split_range_greater_than(
-    range_to_split: (u16, u16),
-    boundary: u16,
-) -> Option<((u16, u16), (u16, u16))> {
-    (range_to_split.1 > boundary).then_some({
+    range_to_split: (u64, u64),
+    boundary: u64,
+) -> Option<((u64, u64), (u64, u64))> {
+    let (start, end) = range_to_split;
+    (end > boundary).then_some({
         (
-            (range_to_split.0, boundary),
-            ((boundary + 1), range_to_split.1),
+            (start, boundary.max(start - 1).min(end)),
+            (start.max(boundary + 1), end),
         )
     })
 }
 
 fn split_range_less_than(
-    range_to_split: (u16, u16),
-    boundary: u16,
-) -> Option<((u16, u16), (u16, u16))> {
-    (range_to_split.0 < boundary).then_some({
+    range_to_split: (u64, u64),
+    boundary: u64,
+) -> Option<((u64, u64), (u64, u64))> {
+    let (start, end) = range_to_split;
+    (start < boundary).then_some({
         (
-            (boundary, range_to_split.1),
-            (range_to_split.0, boundary - 1),
+            (boundary.max(start).min(end + 1), end),
+            (start, end.min(boundary - 1)),
         )
     })
 }
@@ -226,114 +232,16 @@ fn follow_operation<'a>(
 // Each range is considered inclusive
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 struct PartRange {
-    x_cool: (u16, u16),
-    musical: (u16, u16),
-    aerodynamic: (u16, u16),
-    shiny: (u16, u16),
-}
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum Property {
-    XCool(u16),
-    Musical(u16),
-    Aerodynamic(u16),
-    Shiny(u16),
-}
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum Condition {
-    GreaterThan(Property),
-    LessThan(Property),
-}
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum Operation<'a> {
-    Goto(&'a str),
-    Accept,
-    Reject,
-}
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-struct Case<'a> {
-    conditional: Option<Condition>,
-    operation: Operation<'a>,
-}
-#[derive(Debug, Clone, PartialEq, Eq)]
-struct Rule<'a> {
-    name: &'a str,
-    cases: Vec<Case<'a>>,
-}
-
-fn parse_property(property: &str, amount: u16) -> Result<Property, &'static str> {
-    match property {
-        "x" => Ok(Property::XCool(amount)),
-        "m" => Ok(Property::Musical(amount)),
-        "a" => Ok(Property::Aerodynamic(amount)),
-        "s" => Ok(Property::Shiny(amount)),
-        _ => Err("Not a valid property"),
-    }
-}
-
-fn parse_conditional(input: &str) -> IResult<&str, Condition> {
-    let (input, property_initial) = is_not("<>")(input)?;
-    let (input, inequality) = one_of("<>")(input)?;
-    let (input, property_amount) = complete::u16(input)?;
-    let property =
-        parse_property(property_initial, property_amount).expect("property was not valid");
-    match inequality {
-        '>' => Ok((input, Condition::GreaterThan(property))),
-        '<' => Ok((input, Condition::LessThan(property))),
-        _ => panic!("idk how to create nom error lmao"),
-    }
-}
-
-fn parse_operation(input: &str) -> Operation {
-    let operation = match input {
-        "A" => Operation::Accept,
-        "R" => Operation::Reject,
-        name => Operation::Goto(name),
-    };
-    operation
-}
-
-fn parse_case(input: &str) -> IResult<&str, Case> {
-    if let Some((conditional, operation)) = input.split_once(':') {
-        let (_, conditional) = parse_conditional(conditional)?;
-        let operation = parse_operation(operation);
-        Ok((
-            "",
-            Case {
-                conditional: Some(conditional),
-                operation,
-            },
-        ))
-    } else {
-        let operation = parse_operation(input);
-        Ok((
-            "",
-            Case {
-                conditional: None,
-                operation,
-            },
-        ))
-    }
-}
-
-fn parse_rule(input: &str) -> IResult<&str, Rule> {
-    let (input, name) = take_until1("{")(input)?;
-    let (input, rules_str) = delimited(tag("{"), is_not("}"), tag("}"))(input)?;
-    let mut cases = vec![];
-    for case in rules_str.split(',') {
-        let (_, case) = parse_case(case)?;
-        cases.push(case);
-    }
-
-    Ok((input, Rule { name, cases }))
+    x_cool: (u64, u64),
+    musical: (u64, u64),
+    aerodynamic: (u64, u64),
+    shiny: (u64, u64),
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use rstest::rstest;
 
     #[test]
     fn test_part2() {
@@ -355,6 +263,15 @@ hdj{m>838:A,pv}
 {x=2461,m=1339,a=466,s=291}
 {x=2127,m=1623,a=2188,s=1013}";
 
-        assert_eq!(part2(input), 167409079868000);
+        assert_eq!(part2(input), Ok(167409079868000));
+    }
+
+    #[rstest]
+    #[case("in{x<2000:ab,R}\nab{x<3000:A,R}", 1999)]
+    #[case("in{x>2000:ab,R}\nab{x>1000:A,m>5:A,R}", 2000)]
+    fn test_part2_range_on_one_side(#[case] rules: &str, #[case] accepted_x: u64) {
+        let input = format!("{}\n\n{{x=1,m=1,a=1,s=1}}", rules);
+
+        assert_eq!(part2(&input), Ok(accepted_x * 4000 * 4000 * 4000));
     }
 }
@@ -0,0 +1,84 @@
+use aoc_generate::{rand::seq::SliceRandom, rng, unique_names, LOWERCASE};
+
+const COUNTERS: usize = 4;
+
+/// Machine built like the puzzle input: the broadcaster drives 4 binary counters of `size`
+/// flip-flops each, and `rx` gets a low pulse once all of them overflow on the same press.
+///
+/// Every counter resets at a different prime between `2^(size - 1)` and `2^size`. Part 2 has to
+/// press the button about `2^size` times, and `size` can't go past 16 before the answer no longer
+/// fits in a `u64`.
+pub fn generate(size: usize, seed: u64) -> String {
+    assert!(
+        (5..=16).contains(&size),
+        "counters need 5 to 16 bits, not {}",
+        size
+    );
+    let mut rng = rng(seed);
+
+    let mut periods = ((1 << (size - 1)) + 1..1 << size)
+        .filter(|&n| is_prime(n))
+        .collect::<Vec<u64>>();
+    periods.shuffle(&mut rng);
+
+    let mut names = unique_names(&mut rng, COUNTERS * (size + 2) + 2, 2, LOWERCASE);
+    names.retain(|name| name != "rx");
+    let output = names.pop().unwrap();
+
+    let mut modules = vec![];
+    let mut broadcasts = vec![];
+    for &period in &periods[..COUNTERS] {
+        let flip_flops = names.split_off(names.len() - size);
+        let hub = names.pop().unwrap();
+        let inverter = names.pop().unwrap();
+
+        let mut hub_outputs = vec![inverter.clone()];
+        for (bit, flip_flop) in flip_flops.iter().enumerate() {
+            let mut outputs = flip_flops
+                .get(bit + 1)
+                .into_iter()
+                .cloned()
+                .collect::<Vec<_>>();
+            if period & (1 << bit) != 0 {
+                outputs.push(hub.clone());
+            }
+            if bit == 0 || period & (1 << bit) == 0 {
+                hub_outputs.push(flip_flop.clone());
+            }
+            outputs.shuffle(&mut rng);
+            modules.push(format!("%{} -> {}", flip_flop, outputs.join(", ")));
+        }
+        hub_outputs.shuffle(&mut rng);
+        modules.push(format!("&{} -> {}", hub, hub_outputs.join(", ")));
+        modules.push(format!("&{} -> {}", inverter, output));
+        broadcasts.push(flip_flops[0].clone());
+    }
+    modules.push(format!("&{} -> rx", output));
+    modules.push(format!("broadcaster -> {}", broadcasts.join(", ")));
+    modules.shuffle(&mut rng);
+
+    modules.join("\n")
+}
+
+fn is_prime(n: u64) -> bool {
+    n >= 2
+        && (2..)
+            .take_while(|d| d * d <= n)
+            .all(|d| !n.is_multiple_of(d))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::SOLUTIONS;
+    use aoc_common::testing;
+
+    #[test]
+    fn test_generated_input_is_solved() {
+        testing::check_generated(
+            SOLUTIONS,
+            generate,
+            &[(5, 0, &["686644406", "215441"]), (12, 1, &[])],
+        );
+    }
+}
@@ -0,0 +1,73 @@
+use aoc_common::Cancelled;
+use std::fmt::{self, Display};
+
+pub type ParseError = aoc_common::ParseError<ParseErrorKind>;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    InvalidModule,
+    MissingModule(&'static str),
+}
+
+impl Display for ParseErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseErrorKind::InvalidModule => {
+                write!(f, "expected a module as `<type><name> -> <destinations>`")
+            }
+            ParseErrorKind::MissingModule(name) => write!(f, "no module sends to `{}`", name),
+        }
+    }
+}
+
+/// Modules that could not be parsed, or a search for the first low pulse to `rx` that got
+/// cancelled.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    Parse(ParseError),
+    Cancelled(Cancelled),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Parse(err) => err.fmt(f),
+            Error::Cancelled(err) => err.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Cancelled(err) => Some(err),
+            Error::Parse(_) => None,
+        }
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(err: ParseError) -> Self {
+        Error::Parse(err)
+    }
+}
+
+impl From<Cancelled> for Error {
+    fn from(err: Cancelled) -> Self {
+        Error::Cancelled(err)
+    }
+}
+
+/// Points at the rest of the line nom stopped at.
+pub fn nom_error<'a>(
+    input: &'a str,
+    kind: ParseErrorKind,
+) -> impl FnOnce(nom::Err<nom::error::Error<&str>>) -> ParseError + 'a {
+    move |err| match err {
+        nom::Err::Error(err) | nom::Err::Failure(err) => {
+            let len = err.input.find('\n').unwrap_or(err.input.len());
+            ParseError::new(input, &err.input[..len], kind)
+        }
+        nom::Err::Incomplete(_) => ParseError::new(input, &input[input.len()..], kind),
+    }
+}
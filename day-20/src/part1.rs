@@ -1,3 +1,5 @@
+use crate::parse::{self, ParseError, ParseErrorKind};
+use aoc_common::Solution;
 use std::collections::{HashMap, HashSet, VecDeque};
 
 use itertools::Itertools;
@@ -5,17 +7,33 @@ use nom::branch::alt;
 use nom::bytes::complete::tag;
 use nom::character::complete::alpha1;
 use nom::combinator::map;
-use nom::IResult;
-
-pub fn part1(input: &str) -> u64 {
-    let mut modules = input
-        .lines()
-        .map(|line| {
-            let (_, module) = parse_module(line).expect("Module line could not be parsed");
-            (module.name, module)
-        })
-        .collect::<HashMap<_, _>>();
 
+pub struct Part1;
+
+impl Solution for Part1 {
+    const PART: u8 = 1;
+
+    type Model<'a> = HashMap<&'a str, Module<'a>>;
+    type Answer = u64;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Model<'_>, Self::Error> {
+        input
+            .lines()
+            .map(|line| parse_module(input, line).map(|module| (module.name, module)))
+            .collect()
+    }
+
+    fn solve(modules: Self::Model<'_>) -> Result<Self::Answer, Self::Error> {
+        Ok(pulse_product(modules))
+    }
+}
+
+pub fn part1(input: &str) -> Result<u64, ParseError> {
+    Part1::run(input)
+}
+
+fn pulse_product(mut modules: HashMap<&str, Module>) -> u64 {
     let inputs_to_modules = modules
         .values()
         .flat_map(|module| {
@@ -49,13 +67,22 @@ pub fn part1(input: &str) -> u64 {
     const TOTAL_BUTTON_PRESSES: usize = 1000;
     let mut total_low_signals = 0;
     let mut total_high_signals = 0;
-    for _ in 0..TOTAL_BUTTON_PRESSES {
+    for _button_press in 0..TOTAL_BUTTON_PRESSES {
         let mut queue = VecDeque::from([SignalStep {
             source: "button", // just to match the examples, but it will never be referenced
             destination: "broadcaster",
             signal: Signal::Low,
         }]);
+        #[cfg(feature = "trace")]
+        let _press = tracing::debug_span!("press", button_press = _button_press + 1).entered();
         while let Some(signal_step) = queue.pop_front() {
+            #[cfg(feature = "trace")]
+            tracing::trace!(
+                source = signal_step.source,
+                destination = signal_step.destination,
+                signal = ?signal_step.signal,
+                "pulse"
+            );
             match signal_step.signal {
                 Signal::Low => total_low_signals += 1,
                 Signal::High => total_high_signals += 1,
@@ -134,37 +161,36 @@ enum ModuleType<'a> {
     Conjunction(HashMap<&'a str, Signal>),
 }
 #[derive(Debug, Clone)]
-struct Module<'a> {
+pub struct Module<'a> {
     module_type: ModuleType<'a>,
     name: &'a str,
     destinations: Vec<&'a str>,
 }
 
-fn parse_module(input: &str) -> IResult<&str, Module> {
-    let (input, (module_type, name)) = alt((
+fn parse_module<'a>(input: &str, line: &'a str) -> Result<Module<'a>, ParseError> {
+    let (rest, (module_type, name)) = alt::<_, _, nom::error::Error<_>, _>((
         map(tag("%"), |_| (ModuleType::FlipFlop(Signal::Low), "")),
         map(tag("&"), |_| (ModuleType::Conjunction(HashMap::new()), "")),
         map(tag("broadcaster"), |name| (ModuleType::Broadcaster, name)),
-    ))(input)?;
-    let (input, name) = if module_type != ModuleType::Broadcaster {
-        alpha1(input)?
+    ))(line)
+    .map_err(parse::nom_error(input, ParseErrorKind::InvalidModule))?;
+    let (rest, name) = if module_type != ModuleType::Broadcaster {
+        alpha1(rest).map_err(parse::nom_error(input, ParseErrorKind::InvalidModule))?
     } else {
-        (input, name)
+        (rest, name)
     };
 
-    let (input, _) = tag(" -> ")(input)?;
-    let destinations = input
+    let (destinations, _) = tag::<_, _, nom::error::Error<_>>(" -> ")(rest)
+        .map_err(parse::nom_error(input, ParseErrorKind::InvalidModule))?;
+    let destinations = destinations
         .split(",")
         .map(|module_dest| module_dest.trim())
         .collect_vec();
-    Ok((
-        "",
-        Module {
-            module_type,
-            name,
-            destinations,
-        },
-    ))
+    Ok(Module {
+        module_type,
+        name,
+        destinations,
+    })
 }
 
 #[cfg(test)]
@@ -179,7 +205,7 @@ mod test {
 %c -> inv
 &inv -> a";
 
-        assert_eq!(part1(input), 32000000);
+        assert_eq!(part1(input), Ok(32000000));
     }
 
     #[test]
@@ -190,6 +216,17 @@ mod test {
 %b -> con
 &con -> output";
 
-        assert_eq!(part1(input), 11687500);
+        assert_eq!(part1(input), Ok(11687500));
+    }
+
+    #[test]
+    fn test_part1_invalid_module() {
+        let input = "broadcaster -> a
+%a -> inv
+&inv => b";
+        let err = part1(input).unwrap_err();
+
+        assert_eq!(err.kind, ParseErrorKind::InvalidModule);
+        assert_eq!((err.line, err.column), (3, 5));
     }
 }
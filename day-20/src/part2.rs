@@ -1,3 +1,5 @@
+use crate::parse::{self, Error, ParseError, ParseErrorKind};
+use aoc_common::{Progress, Solution};
 use std::collections::{HashMap, HashSet, VecDeque};
 
 use itertools::Itertools;
@@ -5,18 +7,53 @@ use nom::branch::alt;
 use nom::bytes::complete::tag;
 use nom::character::complete::alpha1;
 use nom::combinator::map;
-use nom::IResult;
 use num::integer::lcm;
 
-pub fn part2(input: &str) -> u64 {
-    let mut modules = input
-        .lines()
-        .map(|line| {
-            let (_, module) = parse_module(line).expect("Module line could not be parsed");
-            (module.name, module)
-        })
-        .collect::<HashMap<_, _>>();
+pub struct Part2;
+
+impl Solution for Part2 {
+    const PART: u8 = 2;
+
+    type Model<'a> = HashMap<&'a str, Module<'a>>;
+    type Answer = u64;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Self::Model<'_>, Self::Error> {
+        let modules = input
+            .lines()
+            .map(|line| parse_module(input, line).map(|module| (module.name, module)))
+            .collect::<Result<HashMap<_, _>, _>>()?;
+        if !modules
+            .values()
+            .any(|module| module.destinations.contains(&DESTINATION_MODULE))
+        {
+            return Err(ParseError::new(
+                input,
+                &input[input.len()..],
+                ParseErrorKind::MissingModule(DESTINATION_MODULE),
+            )
+            .into());
+        }
+        Ok(modules)
+    }
+
+    fn solve(modules: Self::Model<'_>) -> Result<Self::Answer, Self::Error> {
+        button_presses(modules, &Progress::hidden())
+    }
 
+    fn solve_with(modules: Self::Model<'_>, progress: &Progress) -> Result<u64, Error> {
+        button_presses(modules, progress)
+    }
+}
+
+pub fn part2(input: &str) -> Result<u64, Error> {
+    Part2::run(input)
+}
+
+static DESTINATION_MODULE: &str = "rx";
+
+/// Counts button presses on `progress`, with the modules whose cycle is known as its message.
+fn button_presses(mut modules: HashMap<&str, Module>, progress: &Progress) -> Result<u64, Error> {
     let inputs_to_modules = modules
         .values()
         .flat_map(|module| {
@@ -47,26 +84,35 @@ pub fn part2(input: &str) -> u64 {
         }
     });
 
-    static DESTINATION_MODULE: &str = "rx";
     let mut button_press = 0;
+    let mut cycles_found = vec![];
     loop {
+        progress.check()?;
         button_press += 1;
+        progress.inc(1);
         let mut queue = VecDeque::from([SignalStep {
             source: "button", // just to match the examples, but it will never be referenced
             destination: "broadcaster",
             signal: Signal::Low,
             parent_cycle: Some(1),
         }]);
+        #[cfg(feature = "trace")]
+        let _press = tracing::debug_span!("press", button_press).entered();
         while let Some(signal_step) = queue.pop_front() {
-            // if signal_step.source == "vr" {
-            //     dbg!(button_press, &signal_step);
-            // }
+            #[cfg(feature = "trace")]
+            tracing::trace!(
+                source = signal_step.source,
+                destination = signal_step.destination,
+                signal = ?signal_step.signal,
+                parent_cycle = signal_step.parent_cycle,
+                "pulse"
+            );
             if signal_step.destination == DESTINATION_MODULE {
                 if signal_step.parent_cycle.is_some() {
-                    return signal_step.parent_cycle.unwrap();
+                    return Ok(signal_step.parent_cycle.unwrap());
                 }
                 if signal_step.signal == Signal::Low {
-                    return button_press;
+                    return Ok(button_press);
                 }
             }
             if let Some(module) = modules.get_mut(signal_step.destination) {
@@ -125,7 +171,15 @@ pub fn part2(input: &str) -> u64 {
                                 module.cycle = Some(
                                     state.values().map(|(_, cycle)| cycle.unwrap()).fold(1, lcm),
                                 );
-                                dbg!(module.name, module.cycle.unwrap());
+                            }
+                            if let Some(cycle) = module.cycle {
+                                #[cfg(feature = "trace")]
+                                tracing::debug!(module = module.name, cycle, "found cycle");
+                                cycles_found.push(format!("{} every {}", module.name, cycle));
+                                progress.set_message(format!(
+                                    "cycles found: {}",
+                                    cycles_found.join(", ")
+                                ));
                             }
                         }
 
@@ -183,37 +237,36 @@ enum ModuleType<'a> {
     Conjunction(HashMap<&'a str, (Signal, Option<u64>)>),
 }
 #[derive(Debug, Clone)]
-struct Module<'a> {
+pub struct Module<'a> {
     module_type: ModuleType<'a>,
     name: &'a str,
     destinations: Vec<&'a str>,
     cycle: Option<u64>,
 }
 
-fn parse_module(input: &str) -> IResult<&str, Module> {
-    let (input, (module_type, name)) = alt((
+fn parse_module<'a>(input: &str, line: &'a str) -> Result<Module<'a>, ParseError> {
+    let (rest, (module_type, name)) = alt::<_, _, nom::error::Error<_>, _>((
         map(tag("%"), |_| (ModuleType::FlipFlop(Signal::Low), "")),
         map(tag("&"), |_| (ModuleType::Conjunction(HashMap::new()), "")),
         map(tag("broadcaster"), |name| (ModuleType::Broadcaster, name)),
-    ))(input)?;
-    let (input, name) = if module_type != ModuleType::Broadcaster {
-        alpha1(input)?
+    ))(line)
+    .map_err(parse::nom_error(input, ParseErrorKind::InvalidModule))?;
+    let (rest, name) = if module_type != ModuleType::Broadcaster {
+        alpha1(rest).map_err(parse::nom_error(input, ParseErrorKind::InvalidModule))?
     } else {
-        (input, name)
+        (rest, name)
     };
 
-    let (input, _) = tag(" -> ")(input)?;
-    let destinations = input
+    let (destinations, _) = tag::<_, _, nom::error::Error<_>>(" -> ")(rest)
+        .map_err(parse::nom_error(input, ParseErrorKind::InvalidModule))?;
+    let destinations = destinations
         .split(",")
         .map(|module_dest| module_dest.trim())
         .collect_vec();
-    Ok((
-        "",
-        Module {
-            module_type,
-            name,
-            destinations,
-            cycle: None,
-        },
-    ))
+    Ok(Module {
+        module_type,
+        name,
+        destinations,
+        cycle: None,
+    })
 }
@@ -0,0 +1,45 @@
+use aoc_generate::{rand::Rng, rng};
+
+/// Garden of `2 * size + 1` x `2 * size + 1` plots with `S` in the middle and rocks on about one
+/// in ten plots.
+///
+/// Like in the puzzle input, the edges as well as the row and column of `S` are kept clear of
+/// rocks, which is what part 2 relies on.
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = rng(seed);
+    let side = 2 * size + 1;
+
+    (0..side)
+        .map(|row| {
+            (0..side)
+                .map(|col| {
+                    if row == size && col == size {
+                        'S'
+                    } else if [0, size, side - 1].contains(&row)
+                        || [0, size, side - 1].contains(&col)
+                        || !rng.gen_ratio(1, 10)
+                    {
+                        '.'
+                    } else {
+                        '#'
+                    }
+                })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::SOLUTIONS;
+    use aoc_common::testing;
+
+    // Part 2 only gets the puzzle's answer for gardens as wide as the puzzle input's, so only its
+    // variants are compared.
+    #[test]
+    fn test_generated_input_is_solved() {
+        testing::check_generated(SOLUTIONS, generate, &[(2, 0, &["12"]), (30, 1, &[])]);
+    }
+}
@@ -0,0 +1,86 @@
+use aoc_common::Cancelled;
+use aoc_grid::{Grid, GridError, Point};
+use std::fmt::{self, Display};
+
+pub type ParseError = aoc_common::ParseError<ParseErrorKind>;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    Grid(GridError),
+    MissingStart,
+    NotSquare { width: usize, height: usize },
+}
+
+impl Display for ParseErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseErrorKind::Grid(err) => write!(f, "{}", err),
+            ParseErrorKind::MissingStart => write!(f, "no starting position `S`"),
+            ParseErrorKind::NotSquare { width, height } => write!(
+                f,
+                "expected a square map, found {} columns and {} rows",
+                width, height
+            ),
+        }
+    }
+}
+
+/// A map that could not be parsed, or a count of the reachable plots that got cancelled.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    Parse(ParseError),
+    Cancelled(Cancelled),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Parse(err) => err.fmt(f),
+            Error::Cancelled(err) => err.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Cancelled(err) => Some(err),
+            Error::Parse(_) => None,
+        }
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(err: ParseError) -> Self {
+        Error::Parse(err)
+    }
+}
+
+impl From<Cancelled> for Error {
+    fn from(err: Cancelled) -> Self {
+        Error::Cancelled(err)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tile {
+    GardenPlot,
+    Rock,
+    Start,
+}
+
+/// The garden map along with where the elf starts.
+pub fn parse_garden(input: &str) -> Result<(Grid<Tile>, Point), ParseError> {
+    let map = Grid::parse(input, |c| match c {
+        '.' => Some(Tile::GardenPlot),
+        '#' => Some(Tile::Rock),
+        'S' => Some(Tile::Start),
+        _ => None,
+    })
+    .map_err(|err| err.map_kind(ParseErrorKind::Grid))?;
+    let start_pos = map.position(|tile| *tile == Tile::Start).ok_or_else(|| {
+        ParseError::new(input, &input[input.len()..], ParseErrorKind::MissingStart)
+    })?;
+
+    Ok((map, start_pos))
+}
@@ -1,92 +1,62 @@
-use std::collections::{HashMap, VecDeque};
+use crate::parse::{self, ParseError, Tile};
+use aoc_common::Solution;
+use aoc_grid::{Grid, Point};
+use std::collections::VecDeque;
 
-pub fn part1(input: &str) -> usize {
-    part1_steps(input, 64)
+pub struct Part1;
+
+impl Solution for Part1 {
+    const PART: u8 = 1;
+
+    type Model<'a> = (Grid<Tile>, Point);
+    type Answer = usize;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Model<'_>, Self::Error> {
+        parse::parse_garden(input)
+    }
+
+    fn solve((map, start_pos): Self::Model<'_>) -> Result<Self::Answer, Self::Error> {
+        Ok(reachable_plots(&map, start_pos, 64))
+    }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum Tile {
-    GardenPlot,
-    Rock,
-    Start,
+pub fn part1(input: &str) -> Result<usize, ParseError> {
+    Part1::run(input)
 }
-fn part1_steps(input: &str, steps: usize) -> usize {
-    let (map, start_pos) = input
-        .lines()
-        .enumerate()
-        .map(|(row_idx, line)| {
-            line.chars()
-                .enumerate()
-                .map(|(col_idx, c)| match c {
-                    '.' => (Tile::GardenPlot, None),
-                    '#' => (Tile::Rock, None),
-                    'S' => (Tile::Start, Some((row_idx, col_idx))),
-                    c => panic!("Encountered unexpected char: {}", c),
-                })
-                .fold(
-                    (vec![], None),
-                    |(mut acc, start), (tile, possible_start)| {
-                        acc.push(tile);
-                        match start {
-                            None => (acc, possible_start),
-                            Some(pos) => (acc, Some(pos)),
-                        }
-                    },
-                )
-        })
-        .fold((vec![], None), |(mut acc, start), (row, possible_start)| {
-            acc.push(row);
-            match start {
-                None => (acc, possible_start),
-                Some(pos) => (acc, Some(pos)),
-            }
-        });
-    let start_pos = start_pos.unwrap();
-    // let max_rows = input.lines().count();
-    // let max_cols = input.lines().next().unwrap().len();
 
+fn reachable_plots(map: &Grid<Tile>, start_pos: Point, steps: usize) -> usize {
     let mut queue = VecDeque::from([(start_pos, 0_usize)]);
-    let mut visited_distance: HashMap<(usize, usize), usize> = HashMap::new();
+    let mut visited_distance = Grid::filled(map.width(), map.height(), None);
     while let Some((curr_pos, curr_distance)) = queue.pop_front() {
         if curr_distance > steps {
             break;
         }
-        if visited_distance.contains_key(&curr_pos) {
+        if visited_distance[curr_pos].is_some() {
             continue;
         }
-        visited_distance.insert(curr_pos, curr_distance);
-        let offset_by = 1;
-        let curr_possible_directions = [
-            (offset_by <= curr_pos.0).then(|| (curr_pos.0 - offset_by, curr_pos.1)),
-            Some((curr_pos.0 + offset_by, curr_pos.1)),
-            Some((curr_pos.0, curr_pos.1 + offset_by)),
-            (offset_by <= curr_pos.1).then(|| (curr_pos.0, curr_pos.1 - offset_by)),
-        ];
-        for next_pos in curr_possible_directions {
-            if let Some(next_pos) = next_pos {
-                if let Some(row) = map.get(next_pos.0) {
-                    if let Some(tile) = row.get(next_pos.1) {
-                        match tile {
-                            Tile::Rock => {}
-                            Tile::GardenPlot | Tile::Start => {
-                                queue.push_back(((next_pos), curr_distance + 1));
-                            }
-                        }
-                    }
+        visited_distance[curr_pos] = Some(curr_distance);
+        for next_pos in map.neighbours(curr_pos) {
+            match map[next_pos] {
+                Tile::Rock => {}
+                Tile::GardenPlot | Tile::Start => {
+                    queue.push_back((next_pos, curr_distance + 1));
                 }
             }
         }
     }
 
     visited_distance
-        .iter()
-        .filter(|(_, distance)| *distance % 2 == steps % 2)
+        .values()
+        .flatten()
+        .filter(|distance| *distance % 2 == steps % 2)
         .count()
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::parse::ParseErrorKind;
 
     #[test]
     fn test_part1() {
@@ -102,6 +72,19 @@ mod test {
 .##..##.##.
 ...........";
 
-        assert_eq!(part1_steps(input, 6), 16);
+        let (map, start_pos) = parse::parse_garden(input).unwrap();
+
+        assert_eq!(reachable_plots(&map, start_pos, 6), 16);
+    }
+
+    #[test]
+    fn test_part1_missing_start() {
+        let input = "...
+.#.
+...";
+        let err = part1(input).unwrap_err();
+
+        assert_eq!(err.kind, ParseErrorKind::MissingStart);
+        assert_eq!((err.line, err.column), (3, 4));
     }
 }
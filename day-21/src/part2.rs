@@ -1,3 +1,6 @@
+use crate::parse::{self, Error, ParseError, ParseErrorKind, Tile};
+use aoc_common::{Progress, Solution};
+use aoc_grid::{Grid, Point};
 use std::{
     collections::{HashMap, VecDeque},
     usize,
@@ -5,102 +8,89 @@ use std::{
 
 use itertools::Itertools;
 
-pub fn part2(input: &str) -> usize {
-    part2_steps(input, 26501365)
+pub struct Part2;
+
+impl Solution for Part2 {
+    const PART: u8 = 2;
+
+    type Model<'a> = (Grid<Tile>, Point);
+    type Answer = usize;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Self::Model<'_>, Self::Error> {
+        let (map, start_pos) = parse::parse_garden(input)?;
+        if map.height() != map.width() {
+            return Err(ParseError::new(
+                input,
+                &input[..0],
+                ParseErrorKind::NotSquare {
+                    width: map.width(),
+                    height: map.height(),
+                },
+            )
+            .into());
+        }
+        Ok((map, start_pos))
+    }
+
+    fn solve(garden: Self::Model<'_>) -> Result<Self::Answer, Self::Error> {
+        Self::solve_with(garden, &Progress::hidden())
+    }
+
+    /// Counts the distance maps computed from the border tiles on `progress`.
+    fn solve_with((map, start_pos): Self::Model<'_>, progress: &Progress) -> Result<usize, Error> {
+        part2_steps(&map, start_pos, 26501365, progress)
+    }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum Tile {
-    GardenPlot,
-    Rock,
-    Start,
+pub fn part2(input: &str) -> Result<usize, Error> {
+    Part2::run(input)
 }
 
-fn part2_steps(input: &str, steps: usize) -> usize {
-    let (map, start_pos) = input
-        .lines()
-        .enumerate()
-        .map(|(row_idx, line)| {
-            line.chars()
-                .enumerate()
-                .map(|(col_idx, c)| match c {
-                    '.' => (Tile::GardenPlot, None),
-                    '#' => (Tile::Rock, None),
-                    'S' => (Tile::Start, Some((row_idx, col_idx))),
-                    c => panic!("Encountered unexpected char: {}", c),
-                })
-                .fold(
-                    (vec![], None),
-                    |(mut acc, start), (tile, possible_start)| {
-                        acc.push(tile);
-                        match start {
-                            None => (acc, possible_start),
-                            Some(pos) => (acc, Some(pos)),
-                        }
-                    },
-                )
-        })
-        .fold((vec![], None), |(mut acc, start), (row, possible_start)| {
-            acc.push(row);
-            match start {
-                None => (acc, possible_start),
-                Some(pos) => (acc, Some(pos)),
-            }
-        });
-    let start_pos = start_pos.unwrap();
-    let max_rows = input.lines().count();
-    let max_cols = input.lines().next().unwrap().len();
-    assert_eq!(max_rows, max_cols);
+fn part2_steps(
+    map: &Grid<Tile>,
+    start_pos: Point,
+    steps: usize,
+    progress: &Progress,
+) -> Result<usize, Error> {
+    let max_rows = map.height();
+    let max_cols = map.width();
 
-    let origin_plot_distances = get_distances(&map, start_pos);
+    let origin_plot_distances = get_distances(map, start_pos);
 
     let black_tiles = origin_plot_distances
-        .iter()
-        .filter(|(_, distance)| *distance % 2 == 0)
+        .values()
+        .flatten()
+        .filter(|distance| *distance % 2 == 0)
         .count();
     let red_tiles = origin_plot_distances
-        .iter()
-        .filter(|(_, distance)| *distance % 2 == 1)
+        .values()
+        .flatten()
+        .filter(|distance| *distance % 2 == 1)
         .count();
 
-    let mut borders = HashMap::new();
-    borders.extend((0..max_rows).map(|r| {
-        let pos = (r, 0);
-        (pos, origin_plot_distances.get(&pos).cloned().unwrap())
-    }));
-    borders.extend((0..max_rows).map(|r| {
-        let pos = (r, max_cols - 1);
-        (pos, origin_plot_distances.get(&pos).cloned().unwrap())
-    }));
-    borders.extend((1..max_cols - 1).map(|c| {
-        let pos = (0, c);
-        (pos, origin_plot_distances.get(&pos).cloned().unwrap())
-    }));
-    borders.extend((1..max_cols - 1).map(|c| {
-        let pos = (max_rows - 1, c);
-        (pos, origin_plot_distances.get(&pos).cloned().unwrap())
-    }));
+    let border_positions = (0..max_rows)
+        .map(|r| (r, 0))
+        .chain((0..max_rows).map(|r| (r, max_cols - 1)))
+        .chain((1..max_cols - 1).map(|c| (0, c)))
+        .chain((1..max_cols - 1).map(|c| (max_rows - 1, c)))
+        .collect_vec();
+
+    let borders = border_positions
+        .iter()
+        .map(|&pos| (pos, origin_plot_distances[pos].unwrap()))
+        .collect::<HashMap<_, _>>();
 
     let a = borders.iter().min_set_by(|a, b| a.1.cmp(b.1));
 
     // (border pos) -> HashMap<(usize, distance)>
     let mut border_distance_maps = HashMap::new();
-    border_distance_maps.extend((0..max_rows).map(|r| {
-        let pos = (r, 0);
-        (pos, get_distances(&map, pos))
-    }));
-    border_distance_maps.extend((0..max_rows).map(|r| {
-        let pos = (r, max_cols - 1);
-        (pos, get_distances(&map, pos))
-    }));
-    border_distance_maps.extend((1..max_cols - 1).map(|c| {
-        let pos = (0, c);
-        (pos, get_distances(&map, pos))
-    }));
-    border_distance_maps.extend((1..max_cols - 1).map(|c| {
-        let pos = (max_rows - 1, c);
-        (pos, get_distances(&map, pos))
-    }));
+    progress.set_length(border_positions.len() as u64);
+    for pos in border_positions {
+        progress.check()?;
+        border_distance_maps.insert(pos, get_distances(map, pos));
+        progress.inc(1);
+    }
 
     let small_triangle = [
         (0, 0),
@@ -113,19 +103,21 @@ fn part2_steps(input: &str, steps: usize) -> usize {
         let valid_distances = border_distance_maps
             .get(&start)
             .unwrap()
-            .iter()
-            .filter(|(_, &distance)| distance < max_rows / 2)
+            .values()
+            .flatten()
+            .copied()
+            .filter(|&distance| distance < max_rows / 2)
             .collect_vec();
         (
             start,
             (
                 valid_distances
                     .iter()
-                    .filter(|(_, &distance)| distance % 2 == 0)
+                    .filter(|&&distance| distance % 2 == 0)
                     .count(),
                 valid_distances
                     .iter()
-                    .filter(|(_, &distance)| distance % 2 == 1)
+                    .filter(|&&distance| distance % 2 == 1)
                     .count(),
             ),
         )
@@ -143,19 +135,21 @@ fn part2_steps(input: &str, steps: usize) -> usize {
         let valid_distances = border_distance_maps
             .get(&start)
             .unwrap()
-            .iter()
-            .filter(|(_, &distance)| distance < max_rows + max_rows / 2)
+            .values()
+            .flatten()
+            .copied()
+            .filter(|&distance| distance < max_rows + max_rows / 2)
             .collect_vec();
         (
             start,
             (
                 valid_distances
                     .iter()
-                    .filter(|(_, &distance)| distance % 2 == 0)
+                    .filter(|&&distance| distance % 2 == 0)
                     .count(),
                 valid_distances
                     .iter()
-                    .filter(|(_, &distance)| distance % 2 == 1)
+                    .filter(|&&distance| distance % 2 == 1)
                     .count(),
             ),
         )
@@ -168,19 +162,21 @@ fn part2_steps(input: &str, steps: usize) -> usize {
             let valid_distances = border_distance_maps
                 .get(pos)
                 .unwrap()
-                .iter()
-                .filter(|(_, &distance)| distance < max_rows)
+                .values()
+                .flatten()
+                .copied()
+                .filter(|&distance| distance < max_rows)
                 .collect_vec();
             (
                 pos,
                 (
                     valid_distances
                         .iter()
-                        .filter(|(_, &distance)| distance % 2 == 0)
+                        .filter(|&&distance| distance % 2 == 0)
                         .count(),
                     valid_distances
                         .iter()
-                        .filter(|(_, &distance)| distance % 2 == 1)
+                        .filter(|&&distance| distance % 2 == 1)
                         .count(),
                 ),
             )
@@ -200,10 +196,10 @@ fn part2_steps(input: &str, steps: usize) -> usize {
     let red_internal_plots = plot_repetition.pow(2);
     let black_internal_plots = (plot_repetition - 1).pow(2);
 
-    small_triangle
+    Ok(small_triangle
         .iter()
         .map(|(pos, (black, red))| {
-            if origin_plot_distances.get(pos).unwrap() % 2 == steps % 2 {
+            if origin_plot_distances[*pos].unwrap() % 2 == steps % 2 {
                 // println!(
                 //     "small triangle counted as red {:?} x {}",
                 //     pos, plot_repetition
@@ -221,7 +217,7 @@ fn part2_steps(input: &str, steps: usize) -> usize {
         + chopped_triangle
             .iter()
             .map(|(pos, (black, red))| {
-                if origin_plot_distances.get(pos).unwrap() % 2 == steps % 2 {
+                if origin_plot_distances[*pos].unwrap() % 2 == steps % 2 {
                     // println!(
                     //     "chopped triangle counted as black {:?} x {}",
                     //     pos, plot_repetition
@@ -239,7 +235,7 @@ fn part2_steps(input: &str, steps: usize) -> usize {
         + side_plots
             .iter()
             .map(|(pos, (black, red))| {
-                if origin_plot_distances.get(pos).unwrap() % 2 == steps % 2 {
+                if origin_plot_distances[***pos].unwrap() % 2 == steps % 2 {
                     // println!("side plot counted as black {:?} x 1", pos,);
                     black
                 } else {
@@ -257,35 +253,22 @@ fn part2_steps(input: &str, steps: usize) -> usize {
             red_tiles
         } else {
             black_tiles
-        } * red_internal_plots
+        } * red_internal_plots)
 }
 
-fn get_distances(map: &Vec<Vec<Tile>>, start: (usize, usize)) -> HashMap<(usize, usize), usize> {
+fn get_distances(map: &Grid<Tile>, start: (usize, usize)) -> Grid<Option<usize>> {
     let mut queue = VecDeque::from([(start, 0_usize)]);
-    let mut visited_distance: HashMap<(usize, usize), usize> = HashMap::new();
+    let mut visited_distance = Grid::filled(map.width(), map.height(), None);
     while let Some((curr_pos, curr_distance)) = queue.pop_front() {
-        if visited_distance.contains_key(&curr_pos) {
+        if visited_distance[curr_pos].is_some() {
             continue;
         }
-        visited_distance.insert(curr_pos, curr_distance);
-        let offset_by = 1;
-        let curr_possible_directions = [
-            (offset_by <= curr_pos.0).then(|| (curr_pos.0 - offset_by, curr_pos.1)),
-            Some((curr_pos.0 + offset_by, curr_pos.1)),
-            Some((curr_pos.0, curr_pos.1 + offset_by)),
-            (offset_by <= curr_pos.1).then(|| (curr_pos.0, curr_pos.1 - offset_by)),
-        ];
-        for next_pos in curr_possible_directions {
-            if let Some(next_pos) = next_pos {
-                if let Some(row) = map.get(next_pos.0) {
-                    if let Some(tile) = row.get(next_pos.1) {
-                        match tile {
-                            Tile::Rock => {}
-                            Tile::GardenPlot | Tile::Start => {
-                                queue.push_back(((next_pos), curr_distance + 1));
-                            }
-                        }
-                    }
+        visited_distance[curr_pos] = Some(curr_distance);
+        for next_pos in map.neighbours(curr_pos) {
+            match map[next_pos] {
+                Tile::Rock => {}
+                Tile::GardenPlot | Tile::Start => {
+                    queue.push_back((next_pos, curr_distance + 1));
                 }
             }
         }
@@ -320,6 +303,11 @@ mod test {
 .##..##.##.
 ...........";
 
-        assert_eq!(part2_steps(input, steps), garden_plots_reached);
+        let (map, start_pos) = Part2::parse(input).unwrap();
+
+        assert_eq!(
+            part2_steps(&map, start_pos, steps, &Progress::hidden()),
+            Ok(garden_plots_reached)
+        );
     }
 }
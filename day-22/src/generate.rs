@@ -0,0 +1,43 @@
+use aoc_generate::{rand::Rng, rng};
+
+/// Snapshot of `size` bricks falling onto a 10 x 10 area, each up to 4 cubes long in one of the
+/// three directions.
+///
+/// Every brick starts above the highest cube of the bricks before it, so that none of them
+/// overlap.
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = rng(seed);
+    let mut z = 1;
+
+    (0..size)
+        .map(|_| {
+            let mut start = [rng.gen_range(0..10), rng.gen_range(0..10), z];
+            let mut end = start;
+            let axis = rng.gen_range(0..3);
+            end[axis] += rng.gen_range(0..4);
+            if axis < 2 && end[axis] > 9 {
+                let overflow = end[axis] - 9;
+                start[axis] -= overflow;
+                end[axis] -= overflow;
+            }
+            z = end[2] + rng.gen_range(1..=3);
+
+            let [x1, y1, z1] = start;
+            let [x2, y2, z2] = end;
+            format!("{},{},{}~{},{},{}", x1, y1, z1, x2, y2, z2)
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::SOLUTIONS;
+    use aoc_common::testing;
+
+    #[test]
+    fn test_generated_input_is_solved() {
+        testing::check_generated(SOLUTIONS, generate, &[(1, 0, &["1", "0"]), (200, 1, &[])]);
+    }
+}
@@ -0,0 +1,106 @@
+use itertools::Itertools;
+use std::{
+    collections::BTreeSet,
+    fmt::{self, Display},
+    num::ParseIntError,
+    ops::RangeInclusive,
+};
+
+pub type ParseError = aoc_common::ParseError<ParseErrorKind>;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    MissingEnd,
+    InvalidNumber(ParseIntError),
+    WrongCoordinateCount(usize),
+}
+
+impl Display for ParseErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseErrorKind::MissingEnd => {
+                write!(f, "expected a brick as `<x>,<y>,<z>~<x>,<y>,<z>`")
+            }
+            ParseErrorKind::InvalidNumber(err) => write!(f, "invalid number ({})", err),
+            ParseErrorKind::WrongCoordinateCount(count) => {
+                write!(f, "expected 3 coordinates, found {}", count)
+            }
+        }
+    }
+}
+
+pub type Position = (usize, usize, usize);
+
+/// Both ends of the brick on `line` as `(x, y, z)`.
+fn parse_ends(input: &str, line: &str) -> Result<(Position, Position), ParseError> {
+    let (start, end) = line
+        .split_once('~')
+        .ok_or_else(|| ParseError::new(input, line, ParseErrorKind::MissingEnd))?;
+    Ok((parse_position(input, start)?, parse_position(input, end)?))
+}
+
+fn parse_position(input: &str, position: &str) -> Result<Position, ParseError> {
+    let coords = position
+        .split(',')
+        .map(|coord| ParseError::try_parse(input, coord, ParseErrorKind::InvalidNumber))
+        .collect::<Result<Vec<_>, _>>()?;
+    let count = coords.len();
+    coords.into_iter().collect_tuple().ok_or_else(|| {
+        ParseError::new(input, position, ParseErrorKind::WrongCoordinateCount(count))
+    })
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FallingBrick {
+    pub ends: [(usize, usize, usize); 2],
+    pub x_range: RangeInclusive<usize>,
+    pub y_range: RangeInclusive<usize>,
+    pub top_most_z: usize,
+    pub height: usize,
+}
+
+impl Ord for FallingBrick {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        let min_z = self.ends.iter().min_by(|a, b| a.2.cmp(&b.2)).unwrap();
+        let other_min_z = other.ends.iter().min_by(|a, b| a.2.cmp(&b.2)).unwrap();
+        min_z.2.cmp(&other_min_z.2)
+    }
+}
+
+impl PartialOrd for FallingBrick {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Bricks in the order they come to rest, lowest first.
+pub fn parse_bricks(input: &str) -> Result<BTreeSet<FallingBrick>, ParseError> {
+    input.lines().map(|line| parse_brick(input, line)).collect()
+}
+
+fn parse_brick(input: &str, line: &str) -> Result<FallingBrick, ParseError> {
+    let (start, end) = parse_ends(input, line)?;
+    let x_range = if start.0 < end.0 {
+        start.0..=end.0
+    } else {
+        end.0..=start.0
+    };
+    let y_range = if start.1 < end.1 {
+        start.1..=end.1
+    } else {
+        end.1..=start.1
+    };
+    let top_most_z = start.2.max(end.2);
+    let height = if start.2 < end.2 {
+        end.2 - start.2 + 1
+    } else {
+        start.2 - end.2 + 1
+    };
+    Ok(FallingBrick {
+        ends: [start, end],
+        x_range,
+        y_range,
+        top_most_z,
+        height,
+    })
+}
@@ -1,26 +1,34 @@
-use std::{
-    collections::{BTreeSet, HashMap},
-    ops::RangeInclusive,
-};
+use crate::parse::{self, FallingBrick, ParseError};
+use aoc_common::Solution;
+use std::collections::{BTreeSet, HashMap};
 
 use itertools::Itertools;
-use nom::{
-    bytes::complete::{is_not, tag},
-    multi::separated_list1,
-    IResult,
-};
 
 type BrickId = usize;
 
-pub fn part1(input: &str) -> usize {
-    let bricks = input
-        .lines()
-        .map(|line| {
-            let (_, brick) = parse_brick(line).expect("Line did not parse to valid brick");
-            brick
-        })
-        .collect::<BTreeSet<_>>();
+pub struct Part1;
+
+impl Solution for Part1 {
+    const PART: u8 = 1;
+
+    type Model<'a> = BTreeSet<FallingBrick>;
+    type Answer = usize;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Model<'_>, Self::Error> {
+        parse::parse_bricks(input)
+    }
+
+    fn solve(bricks: Self::Model<'_>) -> Result<Self::Answer, Self::Error> {
+        Ok(removable_bricks(&bricks))
+    }
+}
+
+pub fn part1(input: &str) -> Result<usize, ParseError> {
+    Part1::run(input)
+}
 
+fn removable_bricks(bricks: &BTreeSet<FallingBrick>) -> usize {
     let mut stationary_bricks = vec![];
     let mut current_heights = HashMap::<(usize, usize), (usize, BrickId)>::new();
     bricks.iter().for_each(|brick| {
@@ -86,15 +94,6 @@ pub fn part1(input: &str) -> usize {
         .count()
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
-struct FallingBrick {
-    ends: [(usize, usize, usize); 2],
-    x_range: RangeInclusive<usize>,
-    y_range: RangeInclusive<usize>,
-    top_most_z: usize,
-    height: usize,
-}
-
 #[derive(Debug, Clone)]
 struct StationaryBrick {
     bricks_directly_above: Vec<BrickId>,
@@ -102,67 +101,10 @@ struct StationaryBrick {
     support_count: usize,
 }
 
-impl Ord for FallingBrick {
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        let min_z = self.ends.iter().min_by(|a, b| a.2.cmp(&b.2)).unwrap();
-        let other_min_z = other.ends.iter().min_by(|a, b| a.2.cmp(&b.2)).unwrap();
-        min_z.2.cmp(&other_min_z.2)
-    }
-}
-
-impl PartialOrd for FallingBrick {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        Some(self.cmp(&other))
-    }
-}
-
-fn parse_brick(input: &str) -> IResult<&str, FallingBrick> {
-    let (input, start) = separated_list1(tag(","), is_not("~,"))(input)?;
-    let (input, _) = tag("~")(input)?;
-    let (input, end) = separated_list1(tag(","), is_not("~,"))(input)?;
-    let start = start
-        .iter()
-        .map(|coord| coord.parse::<usize>().expect("could not parse number"))
-        .tuples::<(_, _, _)>()
-        .exactly_one()
-        .expect("not exactly one");
-    let end = end
-        .iter()
-        .map(|coord| coord.parse::<usize>().expect("could not parse number"))
-        .tuples::<(_, _, _)>()
-        .exactly_one()
-        .expect("not exactly one");
-    let x_range = if start.0 < end.0 {
-        start.0..=end.0
-    } else {
-        end.0..=start.0
-    };
-    let y_range = if start.1 < end.1 {
-        start.1..=end.1
-    } else {
-        end.1..=start.1
-    };
-    let top_most_z = start.2.max(end.2);
-    let height = if start.2 < end.2 {
-        end.2 - start.2 + 1
-    } else {
-        start.2 - end.2 + 1
-    };
-    Ok((
-        input,
-        FallingBrick {
-            ends: [start, end],
-            x_range,
-            y_range,
-            top_most_z,
-            height,
-        },
-    ))
-}
-
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::parse::ParseErrorKind;
 
     #[test]
     fn test_part1() {
@@ -174,6 +116,16 @@ mod test {
 0,1,6~2,1,6
 1,1,8~1,1,9";
 
-        assert_eq!(part1(input), 5);
+        assert_eq!(part1(input), Ok(5));
+    }
+
+    #[test]
+    fn test_part1_wrong_coordinate_count() {
+        let input = "1,0,1~1,2,1
+0,0,2~2,0";
+        let err = part1(input).unwrap_err();
+
+        assert_eq!(err.kind, ParseErrorKind::WrongCoordinateCount(2));
+        assert_eq!((err.line, err.column), (2, 7));
     }
 }
@@ -0,0 +1,65 @@
+use aoc_generate::{rand::Rng, rng};
+use aoc_grid::Grid;
+
+/// Distance between two neighbouring junctions.
+const SPACING: usize = 4;
+
+/// Map of `size` x `size` junctions connected by trails, with the start above the top left one
+/// and the end below the bottom right one.
+///
+/// Slopes next to the junctions only lead right and down, which keeps the hike of part 1 from
+/// going in circles. About a third of the trails between the junctions in the middle rows are
+/// left out, while the first and last row along with every column stay connected so that every
+/// junction can still be reached and left. The longest hike of part 2 gets a lot harder to find
+/// with every junction added.
+pub fn generate(size: usize, seed: u64) -> String {
+    assert!(size > 0, "the map needs at least one junction");
+    let mut rng = rng(seed);
+    let side = SPACING * (size - 1) + 3;
+    let junction = |idx: usize| 1 + SPACING * idx;
+
+    let mut map = Grid::filled(side, side, '#');
+    map[(0, 1)] = '.';
+    map[(side - 1, side - 2)] = '.';
+    for row in 0..size {
+        for col in 0..size {
+            map[(junction(row), junction(col))] = '.';
+
+            let keep = row == 0 || row == size - 1 || !rng.gen_ratio(1, 3);
+            if col + 1 < size && keep {
+                for offset in 1..SPACING {
+                    let slope = if offset == 1 || offset == SPACING - 1 {
+                        '>'
+                    } else {
+                        '.'
+                    };
+                    map[(junction(row), junction(col) + offset)] = slope;
+                }
+            }
+            if row + 1 < size {
+                for offset in 1..SPACING {
+                    let slope = if offset == 1 || offset == SPACING - 1 {
+                        'v'
+                    } else {
+                        '.'
+                    };
+                    map[(junction(row) + offset, junction(col))] = slope;
+                }
+            }
+        }
+    }
+
+    map.to_string()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::SOLUTIONS;
+    use aoc_common::testing;
+
+    #[test]
+    fn test_generated_input_is_solved() {
+        testing::check_generated(SOLUTIONS, generate, &[(1, 0, &["2", "2"]), (4, 1, &[])]);
+    }
+}
@@ -0,0 +1,94 @@
+use aoc_common::Cancelled;
+use aoc_grid::{Direction, Grid, GridError, Point};
+use std::fmt::{self, Display};
+
+pub type ParseError = aoc_common::ParseError<ParseErrorKind>;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    Grid(GridError),
+    MissingTrail,
+}
+
+impl Display for ParseErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseErrorKind::Grid(err) => write!(f, "{}", err),
+            ParseErrorKind::MissingTrail => write!(f, "map has no trail tiles"),
+        }
+    }
+}
+
+/// A map that could not be parsed, one without a hike to the destination, or a search that got
+/// cancelled before finding the longest hike.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    Parse(ParseError),
+    NoHike,
+    Cancelled(Cancelled),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Parse(err) => err.fmt(f),
+            Error::NoHike => write!(f, "no hike reaches the destination"),
+            Error::Cancelled(err) => err.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Cancelled(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(err: ParseError) -> Self {
+        Error::Parse(err)
+    }
+}
+
+impl From<Cancelled> for Error {
+    fn from(err: Cancelled) -> Self {
+        Error::Cancelled(err)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Tile {
+    Forest,
+    Open,
+    SingleDirection(Direction),
+}
+
+/// The hiking map along with the destination, the last trail tile of the bottom row.
+pub fn parse_map(input: &str) -> Result<(Grid<Tile>, Point), ParseError> {
+    let map = Grid::parse(input, |c| match c {
+        '#' => Some(Tile::Forest),
+        '.' => Some(Tile::Open),
+        '^' => Some(Tile::SingleDirection(Direction::North)),
+        'v' => Some(Tile::SingleDirection(Direction::South)),
+        '>' => Some(Tile::SingleDirection(Direction::East)),
+        '<' => Some(Tile::SingleDirection(Direction::West)),
+        _ => None,
+    })
+    .map_err(|err| err.map_kind(ParseErrorKind::Grid))?;
+
+    let dest_pos = map
+        .iter()
+        .filter(|(_, tile)| !matches!(tile, Tile::Forest))
+        .map(|(pos, _)| pos)
+        .max()
+        .ok_or_else(|| ParseError::new(input, &input[..0], ParseErrorKind::MissingTrail))?;
+
+    Ok((map, dest_pos))
+}
+
+/// Number of paths popped or steps taken between updates of the progress, as checking the clock
+/// on every one of them slows the search down noticeably.
+pub const PROGRESS_INTERVAL: u64 = 1 << 12;
@@ -1,138 +1,91 @@
-use std::collections::{BTreeMap, BinaryHeap, HashMap, HashSet, VecDeque};
-
-pub fn part1(input: &str) -> usize {
-    let map = input
-        .lines()
-        .enumerate()
-        .flat_map(|(row_idx, line)| {
-            line.chars()
-                .enumerate()
-                .filter_map(move |(col_idx, c)| match c {
-                    '#' => None,
-                    '.' => Some(((row_idx, col_idx), Tile::Open)),
-                    '^' => Some(((row_idx, col_idx), Tile::SingleDirection(Direction::North))),
-                    'v' => Some(((row_idx, col_idx), Tile::SingleDirection(Direction::South))),
-                    '>' => Some(((row_idx, col_idx), Tile::SingleDirection(Direction::East))),
-                    '<' => Some(((row_idx, col_idx), Tile::SingleDirection(Direction::West))),
-                    c => panic!("Encountered unexpected character: {}", c),
-                })
-        })
-        .collect::<BTreeMap<_, _>>();
+use crate::parse::{self, Error, Tile, PROGRESS_INTERVAL};
+use aoc_common::{Progress, Solution};
+use aoc_grid::{Direction, Grid, Point};
 
-    let start_pos = (0, 1);
-    let dest_pos = map.keys().max().cloned().unwrap();
-
-    let mut queue = BinaryHeap::from([HikingPath {
-        curr_pos: start_pos,
-        current_distance: 0,
-        visited_nodes: HashSet::new(),
-    }]);
-    let mut distances = vec![];
-
-    while let Some(mut path) = queue.pop() {
-        if path.curr_pos == dest_pos {
-            distances.push(path.current_distance);
-            continue;
-        }
-        path.visited_nodes.insert(path.curr_pos);
-
-        if let Some(path_type) = map.get(&path.curr_pos) {
-            match path_type {
-                Tile::Open => {
-                    // attempt to move in every direction
-                    let directions = [
-                        Direction::North,
-                        Direction::South,
-                        Direction::East,
-                        Direction::West,
-                    ];
-                    directions.iter().for_each(|direction| {
-                        if let Some(next_pos) =
-                            direction.calculate_offset_position(path.curr_pos, 1)
-                        {
-                            if !path.visited_nodes.contains(&next_pos) {
-                                queue.push(HikingPath {
-                                    curr_pos: next_pos,
-                                    current_distance: path.current_distance + 1,
-                                    visited_nodes: path.visited_nodes.clone(),
-                                })
-                            }
-                        }
-                    });
-                }
-                Tile::SingleDirection(direction) => {
-                    if let Some(next_pos) = direction.calculate_offset_position(path.curr_pos, 1) {
-                        if !path.visited_nodes.contains(&next_pos) {
-                            queue.push(HikingPath {
-                                curr_pos: next_pos,
-                                current_distance: path.current_distance + 1,
-                                visited_nodes: path.visited_nodes.clone(),
-                            })
-                        }
-                    }
-                }
-            }
-        }
-    }
+pub struct Part1;
 
-    distances.iter().max().cloned().unwrap()
-}
+impl Solution for Part1 {
+    const PART: u8 = 1;
 
-#[derive(Debug, Clone, PartialEq, Eq)]
-struct HikingPath {
-    curr_pos: (usize, usize),
-    current_distance: usize,
-    visited_nodes: HashSet<(usize, usize)>,
-}
+    type Model<'a> = (Grid<Tile>, Point);
+    type Answer = usize;
+    type Error = Error;
 
-impl Ord for HikingPath {
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        self.current_distance.cmp(&other.current_distance)
+    fn parse(input: &str) -> Result<Self::Model<'_>, Self::Error> {
+        Ok(parse::parse_map(input)?)
+    }
+
+    fn solve(map: Self::Model<'_>) -> Result<Self::Answer, Self::Error> {
+        find_longest_hike(map, &Progress::hidden())
     }
-}
 
-impl PartialOrd for HikingPath {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        Some(self.cmp(&other))
+    fn solve_with(map: Self::Model<'_>, progress: &Progress) -> Result<usize, Error> {
+        find_longest_hike(map, progress)
     }
 }
 
-#[derive(Debug, Clone, Copy)]
-enum Direction {
-    North,
-    South,
-    East,
-    West,
+pub fn part1(input: &str) -> Result<usize, Error> {
+    Part1::run(input)
 }
 
-impl Direction {
-    fn calculate_offset_position(
-        &self,
-        pos: (usize, usize),
-        offset_by: usize,
-    ) -> Option<(usize, usize)> {
-        match &self {
-            Self::North => (offset_by <= pos.0).then(|| (pos.0 - offset_by, pos.1)),
-            Self::South => Some((pos.0 + offset_by, pos.1)),
-            Self::East => Some((pos.0, pos.1 + offset_by)),
-            Self::West => (offset_by <= pos.1).then(|| (pos.0, pos.1 - offset_by)),
-        }
-    }
+/// Counts the steps taken on `progress`, with the longest hike so far as its message.
+fn find_longest_hike(
+    (map, dest_pos): (Grid<Tile>, Point),
+    progress: &Progress,
+) -> Result<usize, Error> {
+    let start_pos = (0, 1);
 
-    fn opposite_direction(&self) -> Direction {
-        match &self {
-            Self::North => Self::South,
-            Self::South => Self::North,
-            Self::East => Self::West,
-            Self::West => Self::East,
+    // the hike walked so far, each tile along with the directions not tried yet from it, and the
+    // tiles on it, which are left again when backtracking
+    let mut path = vec![(start_pos, exits(&map[start_pos]).iter())];
+    let mut visited = Grid::filled(map.width(), map.height(), false);
+    visited[start_pos] = true;
+    let mut longest_hike = None;
+
+    let mut steps = 0_u64;
+    while let Some((curr_pos, directions)) = path.last_mut() {
+        let Some(direction) = directions.next() else {
+            visited[*curr_pos] = false;
+            path.pop();
+            continue;
+        };
+        let Some(next_pos) = direction
+            .checked_offset(*curr_pos, 1)
+            .filter(|&next_pos| !matches!(map.get(next_pos), None | Some(Tile::Forest)))
+            .filter(|&next_pos| !visited[next_pos])
+        else {
+            continue;
+        };
+
+        if steps.is_multiple_of(PROGRESS_INTERVAL) {
+            progress.set_position(steps);
+            progress.check()?;
         }
+        steps += 1;
+        // the path holds every tile from the start on, so its length is the distance to the next
+        let distance = path.len();
+        if next_pos == dest_pos {
+            if longest_hike < Some(distance) {
+                longest_hike = Some(distance);
+                progress.set_message(format!("longest hike so far {}", distance));
+            }
+            continue;
+        }
+
+        visited[next_pos] = true;
+        path.push((next_pos, exits(&map[next_pos]).iter()));
     }
+
+    longest_hike.ok_or(Error::NoHike)
 }
 
-#[derive(Debug, Clone, Copy)]
-enum Tile {
-    Open,
-    SingleDirection(Direction),
+/// Directions a hike may leave `tile` in.
+fn exits(tile: &Tile) -> &[Direction] {
+    match tile {
+        Tile::Forest => &[],
+        Tile::Open => &Direction::ALL,
+        Tile::SingleDirection(direction) => std::slice::from_ref(direction),
+    }
 }
 
 #[cfg(test)]
@@ -165,6 +118,15 @@ mod test {
 #.....###...###...#...#
 #####################.#";
 
-        assert_eq!(part1(input), 94);
+        assert_eq!(part1(input), Ok(94));
+    }
+
+    #[test]
+    fn test_part1_no_hike() {
+        let input = "#.#
+###
+#.#";
+
+        assert_eq!(part1(input), Err(Error::NoHike));
     }
 }
@@ -1,26 +1,41 @@
-use std::collections::{BTreeMap, BinaryHeap, HashMap, HashSet, VecDeque};
-
-pub fn part2(input: &str) -> usize {
-    let map = input
-        .lines()
-        .enumerate()
-        .flat_map(|(row_idx, line)| {
-            line.chars()
-                .enumerate()
-                .filter_map(move |(col_idx, c)| match c {
-                    '#' => None,
-                    '.' => Some(((row_idx, col_idx), Tile::Open)),
-                    '^' => Some(((row_idx, col_idx), Tile::SingleDirection(Direction::North))),
-                    'v' => Some(((row_idx, col_idx), Tile::SingleDirection(Direction::South))),
-                    '>' => Some(((row_idx, col_idx), Tile::SingleDirection(Direction::East))),
-                    '<' => Some(((row_idx, col_idx), Tile::SingleDirection(Direction::West))),
-                    c => panic!("Encountered unexpected character: {}", c),
-                })
-        })
-        .collect::<BTreeMap<_, _>>();
+use crate::parse::{self, Error, Tile, PROGRESS_INTERVAL};
+use aoc_common::{Progress, Solution};
+use aoc_grid::{Direction, Grid, Point};
+use std::collections::{BTreeMap, BinaryHeap, HashSet, VecDeque};
+
+pub struct Part2;
+
+impl Solution for Part2 {
+    const PART: u8 = 2;
 
+    type Model<'a> = (Grid<Tile>, Point);
+    type Answer = usize;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Self::Model<'_>, Self::Error> {
+        Ok(parse::parse_map(input)?)
+    }
+
+    fn solve(map: Self::Model<'_>) -> Result<Self::Answer, Self::Error> {
+        find_longest_hike(map, &Progress::hidden())
+    }
+
+    fn solve_with(map: Self::Model<'_>, progress: &Progress) -> Result<usize, Error> {
+        find_longest_hike(map, progress)
+    }
+}
+
+pub fn part2(input: &str) -> Result<usize, Error> {
+    Part2::run(input)
+}
+
+/// Counts the paths popped off the queue on `progress`, with the longest hike so far as its
+/// message.
+fn find_longest_hike(
+    (map, dest_pos): (Grid<Tile>, Point),
+    progress: &Progress,
+) -> Result<usize, Error> {
     let start_pos = (0, 1);
-    let dest_pos = map.keys().max().cloned().unwrap();
 
     let mut stack = VecDeque::from([PreprocessingPath {
         curr_pos: start_pos,
@@ -44,21 +59,14 @@ pub fn part2(input: &str) -> usize {
         }
         visited.insert(path.curr_pos);
 
-        let directions = [
-            Direction::North,
-            Direction::South,
-            Direction::East,
-            Direction::West,
-        ];
+        let directions = Direction::ALL;
         let (last_junction_pos, distance) = {
             let is_junction = path.curr_pos == dest_pos
                 || (path.curr_pos != start_pos
                     && directions.iter().all(|direction| {
-                        if let Some(next_pos) =
-                            direction.calculate_offset_position(path.curr_pos, 1)
-                        {
-                            if let Some(next_tile) = map.get(&next_pos) {
-                                return matches!(next_tile, Tile::SingleDirection(_));
+                        if let Some(next_pos) = direction.checked_offset(path.curr_pos, 1) {
+                            if let Some(next_tile) = map.get(next_pos) {
+                                return !matches!(next_tile, Tile::Open);
                             }
                         }
                         true
@@ -96,8 +104,11 @@ pub fn part2(input: &str) -> usize {
         };
 
         directions.iter().for_each(|direction| {
-            if let Some(next_pos) = direction.calculate_offset_position(path.curr_pos, 1) {
-                if map.contains_key(&next_pos) {
+            if let Some(next_pos) = direction.checked_offset(path.curr_pos, 1) {
+                if map
+                    .get(next_pos)
+                    .is_some_and(|tile| !matches!(tile, Tile::Forest))
+                {
                     stack.push_back(PreprocessingPath {
                         curr_pos: next_pos,
                         current_distance: distance,
@@ -115,11 +126,20 @@ pub fn part2(input: &str) -> usize {
         current_distance: 0,
         visited_nodes: HashSet::new(),
     }]);
-    let mut distances = vec![];
+    let mut longest_hike = None;
 
+    let mut pops = 0_u64;
     while let Some(mut path) = queue.pop() {
+        if pops.is_multiple_of(PROGRESS_INTERVAL) {
+            progress.set_position(pops);
+            progress.check()?;
+        }
+        pops += 1;
         if path.curr_pos == dest_pos {
-            distances.push(path.current_distance);
+            if longest_hike < Some(path.current_distance) {
+                longest_hike = Some(path.current_distance);
+                progress.set_message(format!("longest hike so far {}", path.current_distance));
+            }
             continue;
         }
         let node = junctions
@@ -137,7 +157,7 @@ pub fn part2(input: &str) -> usize {
         });
     }
 
-    distances.iter().max().cloned().unwrap()
+    longest_hike.ok_or(Error::NoHike)
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -178,38 +198,10 @@ struct Edge {
     to: (usize, usize),
 }
 
-#[derive(Debug, Clone, Copy)]
-enum Direction {
-    North,
-    South,
-    East,
-    West,
-}
-
-impl Direction {
-    fn calculate_offset_position(
-        &self,
-        pos: (usize, usize),
-        offset_by: usize,
-    ) -> Option<(usize, usize)> {
-        match &self {
-            Self::North => (offset_by <= pos.0).then(|| (pos.0 - offset_by, pos.1)),
-            Self::South => Some((pos.0 + offset_by, pos.1)),
-            Self::East => Some((pos.0, pos.1 + offset_by)),
-            Self::West => (offset_by <= pos.1).then(|| (pos.0, pos.1 - offset_by)),
-        }
-    }
-}
-
-#[derive(Debug, Clone, Copy)]
-enum Tile {
-    Open,
-    SingleDirection(Direction),
-}
-
 #[cfg(test)]
 mod test {
     use super::*;
+    use aoc_common::Cancelled;
 
     #[test]
     fn test_part1() {
@@ -237,6 +229,22 @@ mod test {
 #.....###...###...#...#
 #####################.#";
 
-        assert_eq!(part2(input), 154);
+        assert_eq!(part2(input), Ok(154));
+    }
+
+    #[test]
+    fn test_part2_cancelled() {
+        let input = "#.#
+#.#
+#.#";
+        let progress = Progress::hidden();
+        progress.cancel();
+
+        let map = Part2::parse(input).unwrap();
+
+        assert_eq!(
+            Part2::solve_with(map, &progress),
+            Err(Error::Cancelled(Cancelled))
+        );
     }
 }
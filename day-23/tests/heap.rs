@@ -0,0 +1,35 @@
+//! Allocation budgets, so that a change that makes the search hold on to a lot more memory fails
+//! instead of going unnoticed. See [`testing::heap_profiler`] for how they are checked.
+
+use aoc_common::testing;
+use day_23::{generate, part1, part2};
+
+#[global_allocator]
+static ALLOC: dhat::Alloc = dhat::Alloc;
+
+const KIB: u64 = 1024;
+const MIB: u64 = 1024 * KIB;
+
+#[test]
+fn test_heap_budgets() {
+    // 5x5 junctions, the largest size whose part 2 still runs in a second in a debug build.
+    let input = generate::generate(5, 0);
+
+    {
+        let _profiler = testing::heap_profiler();
+        part1::part1(&input).unwrap();
+        let stats = dhat::HeapStats::get();
+
+        // Every path on the heap carries its own copy of the visited tiles.
+        dhat::assert!((stats.max_bytes as u64) < 64 * KIB, "part 1: {:?}", stats);
+        dhat::assert!(stats.total_bytes < MIB, "part 1: {:?}", stats);
+    }
+    {
+        let _profiler = testing::heap_profiler();
+        part2::part2(&input).unwrap();
+        let stats = dhat::HeapStats::get();
+
+        dhat::assert!((stats.max_bytes as u64) < 64 * KIB, "part 2: {:?}", stats);
+        dhat::assert!(stats.total_bytes < 48 * MIB, "part 2: {:?}", stats);
+    }
+}
@@ -0,0 +1,53 @@
+use aoc_generate::{rand::Rng, rng};
+
+/// `size` hailstones, all of which get hit by a rock thrown from somewhere around the test area
+/// of part 1.
+///
+/// Each hailstone is placed where it will be when the rock hits it at a random time, so the rock
+/// has a single trajectory that part 2 can find.
+pub fn generate(size: usize, seed: u64) -> String {
+    assert!(
+        size >= 3,
+        "part 2 needs at least 3 hailstones, not {}",
+        size
+    );
+    let mut rng = rng(seed);
+
+    let rock_pos = [(); 3].map(|_| rng.gen_range(200_000_000_000_000..=300_000_000_000_000_i64));
+    let rock_vel = [(); 3].map(|_| rng.gen_range(-300..=300_i64));
+
+    (0..size)
+        .map(|_| {
+            let time = rng.gen_range(1_000_000_000..=500_000_000_000_i64);
+            // Hailstones never stand still or fly alongside the rock.
+            let vel = rock_vel.map(|rock| loop {
+                let vel = rng.gen_range(-300..=300);
+                if vel != 0 && vel != rock {
+                    break vel;
+                }
+            });
+            let pos = [0, 1, 2].map(|axis| rock_pos[axis] + time * (rock_vel[axis] - vel[axis]));
+            format!(
+                "{}, {}, {} @ {}, {}, {}",
+                pos[0], pos[1], pos[2], vel[0], vel[1], vel[2]
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::SOLUTIONS;
+    use aoc_common::testing;
+
+    #[test]
+    fn test_generated_input_is_solved() {
+        testing::check_generated(
+            SOLUTIONS,
+            generate,
+            &[(3, 0, &["2", "753041823445030"]), (100, 1, &[])],
+        );
+    }
+}
@@ -0,0 +1,90 @@
+use nom::{
+    bytes::complete::tag,
+    character::complete::{self, space0},
+    sequence::{preceded, separated_pair, tuple},
+    Parser,
+};
+use std::fmt::{self, Display};
+
+pub type ParseError = aoc_common::ParseError<ParseErrorKind>;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    InvalidHailstone,
+    TooFewHailstones { expected: usize, found: usize },
+}
+
+impl Display for ParseErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseErrorKind::InvalidHailstone => {
+                write!(
+                    f,
+                    "expected a hailstone as `<x>, <y>, <z> @ <vx>, <vy>, <vz>`"
+                )
+            }
+            ParseErrorKind::TooFewHailstones { expected, found } => write!(
+                f,
+                "expected at least {} hailstones, found {}",
+                expected, found
+            ),
+        }
+    }
+}
+
+/// Hailstones that could not be parsed, or that no rock thrown in a straight line can hit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    Parse(ParseError),
+    NoTrajectory,
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Parse(err) => err.fmt(f),
+            Error::NoTrajectory => write!(f, "no single rock trajectory hits these hailstones"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<ParseError> for Error {
+    fn from(err: ParseError) -> Self {
+        Error::Parse(err)
+    }
+}
+
+pub type Vector = (f64, f64, f64);
+
+/// Position and velocity of the hailstone on every line.
+pub fn parse_hailstones(input: &str) -> Result<Vec<(Vector, Vector)>, ParseError> {
+    input
+        .lines()
+        .map(|line| parse_hailstone(input, line))
+        .collect()
+}
+
+/// Position and velocity of the hailstone on `line`.
+pub fn parse_hailstone(input: &str, line: &str) -> Result<(Vector, Vector), ParseError> {
+    let vector = || {
+        tuple((
+            complete::i64,
+            preceded(tag(","), space0),
+            complete::i64,
+            preceded(tag(","), space0),
+            complete::i64,
+        ))
+        .map(|(x, _, y, _, z)| (x as f64, y as f64, z as f64))
+    };
+    separated_pair(vector(), tuple((space0, tag("@"), space0)), vector())(line)
+        .map(|(_, hailstone)| hailstone)
+        .map_err(|err: nom::Err<nom::error::Error<&str>>| {
+            let rest = match err {
+                nom::Err::Error(err) | nom::Err::Failure(err) => err.input,
+                nom::Err::Incomplete(_) => &line[line.len()..],
+            };
+            ParseError::new(input, rest, ParseErrorKind::InvalidHailstone)
+        })
+}
@@ -1,30 +1,42 @@
+use crate::parse::{self, ParseError, Vector};
+use aoc_common::Solution;
 use std::ops::RangeInclusive;
 
 use itertools::Itertools;
 use nalgebra::{Matrix2, Matrix2x1};
-use nom::{
-    bytes::complete::tag,
-    character::complete::{self, space0},
-    sequence::{preceded, separated_pair, tuple},
-    IResult, Parser,
-};
-
-pub fn part1(input: &str) -> u64 {
-    part1_with_bounds(input, 200000000000000f64..=400000000000000f64)
+
+pub struct Part1;
+
+impl Solution for Part1 {
+    const PART: u8 = 1;
+
+    type Model<'a> = Vec<(Vector, Vector)>;
+    type Answer = u64;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Model<'_>, Self::Error> {
+        parse::parse_hailstones(input)
+    }
+
+    fn solve(hailstones: Self::Model<'_>) -> Result<Self::Answer, Self::Error> {
+        Ok(crossings_within(
+            &hailstones,
+            200000000000000f64..=400000000000000f64,
+        ))
+    }
 }
 
-fn part1_with_bounds(input: &str, bounds: RangeInclusive<f64>) -> u64 {
-    let hailstones = input
-        .lines()
-        .map(|line| {
-            let (_, hailstone) =
-                parse_hailstone(line).expect("Line did not parse as a valid hailstone");
-            hailstone
-        })
-        .collect_vec();
+pub fn part1(input: &str) -> Result<u64, ParseError> {
+    Part1::run(input)
+}
 
+fn crossings_within(hailstones: &[(Vector, Vector)], bounds: RangeInclusive<f64>) -> u64 {
     hailstones
         .iter()
+        .map(|&(pos, vel)| Hailstone {
+            initial_pos: pos,
+            initial_vel: vel,
+        })
         .combinations(2)
         .filter_map(|hailstones| {
             let a = hailstones[0];
@@ -57,9 +69,7 @@ fn part1_with_bounds(input: &str, bounds: RangeInclusive<f64>) -> u64 {
         .filter(|collision_pos| {
             bounds.contains(&collision_pos.0) && bounds.contains(&collision_pos.1)
         })
-        .count()
-        .try_into()
-        .unwrap()
+        .count() as u64
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -68,38 +78,10 @@ struct Hailstone {
     initial_vel: (f64, f64, f64),
 }
 
-fn parse_hailstone(input: &str) -> IResult<&str, Hailstone> {
-    let pos_vector = tuple((
-        complete::i64,
-        preceded(tag(","), space0),
-        complete::i64,
-        preceded(tag(","), space0),
-        complete::i64,
-    ))
-    .map(|(x, _, y, _, z)| (x as f64, y as f64, z as f64));
-    let vel_vector = tuple((
-        complete::i64,
-        preceded(tag(","), space0),
-        complete::i64,
-        preceded(tag(","), space0),
-        complete::i64,
-    ))
-    .map(|(x, _, y, _, z)| (x as f64, y as f64, z as f64));
-    let (input, (pos, vel)) =
-        separated_pair(pos_vector, tuple((space0, tag("@"), space0)), vel_vector)(input)?;
-
-    Ok((
-        input,
-        Hailstone {
-            initial_pos: pos,
-            initial_vel: vel,
-        },
-    ))
-}
-
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::parse::ParseErrorKind;
 
     #[test]
     fn test_part1() {
@@ -109,6 +91,18 @@ mod test {
 12, 31, 28 @ -1, -2, -1
 20, 19, 15 @  1, -5, -3";
 
-        assert_eq!(part1_with_bounds(input, 7_f64..=27_f64), 2);
+        let hailstones = parse::parse_hailstones(input).unwrap();
+
+        assert_eq!(crossings_within(&hailstones, 7_f64..=27_f64), 2);
+    }
+
+    #[test]
+    fn test_part1_invalid_hailstone() {
+        let input = "19, 13, 30 @ -2,  1, -2
+18, 19 @ -1, -1, -2";
+        let err = part1(input).unwrap_err();
+
+        assert_eq!(err.kind, ParseErrorKind::InvalidHailstone);
+        assert_eq!((err.line, err.column), (2, 7));
     }
 }
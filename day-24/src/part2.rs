@@ -1,34 +1,62 @@
+use crate::parse::{self, Error, ParseError, ParseErrorKind, Vector};
+use aoc_common::Solution;
 use itertools::Itertools;
 use nalgebra::{Matrix1x6, Matrix3, Matrix3x1, Matrix6, Matrix6x1};
-use nom::{
-    bytes::complete::tag,
-    character::complete::{self, space0},
-    sequence::{preceded, separated_pair, tuple},
-    IResult, Parser,
-};
-
-pub fn part2(input: &str) -> u64 {
-    let rock_trajectory =
-        part2_get_rock_trajectory(input).expect("Rock trajectory could not be found");
-    (rock_trajectory.initial_pos.0 + rock_trajectory.initial_pos.1 + rock_trajectory.initial_pos.2)
-        .round() as u64
+
+pub struct Part2;
+
+impl Solution for Part2 {
+    const PART: u8 = 2;
+
+    type Model<'a> = Vec<(Vector, Vector)>;
+    type Answer = u64;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Self::Model<'_>, Self::Error> {
+        let hailstones = parse::parse_hailstones(input)?;
+        if hailstones.len() < HAILSTONES_NEEDED {
+            return Err(ParseError::new(
+                input,
+                &input[input.len()..],
+                ParseErrorKind::TooFewHailstones {
+                    expected: HAILSTONES_NEEDED,
+                    found: hailstones.len(),
+                },
+            )
+            .into());
+        }
+        Ok(hailstones)
+    }
+
+    fn solve(hailstones: Self::Model<'_>) -> Result<Self::Answer, Self::Error> {
+        let rock_trajectory = part2_get_rock_trajectory(&hailstones)?;
+        Ok((rock_trajectory.initial_pos.0
+            + rock_trajectory.initial_pos.1
+            + rock_trajectory.initial_pos.2)
+            .round() as u64)
+    }
 }
 
+pub fn part2(input: &str) -> Result<u64, Error> {
+    Part2::run(input)
+}
+
+const HAILSTONES_NEEDED: usize = 3;
+
 fn get_cross_product_skew_symmetric_matrix(vector: &Matrix3x1<f64>) -> Matrix3<f64> {
     let [x, y, z] = vector.data.0[0];
     Matrix3::new(0_f64, -z, y, z, 0_f64, -x, -y, x, 0_f64)
 }
 
-fn part2_get_rock_trajectory(input: &str) -> Result<Trajectory, &str> {
-    let hailstones = input
-        .lines()
-        .take(3)
-        .map(|line| {
-            let (_, hailstone) =
-                parse_hailstone(line).expect("Line did not parse as a valid hailstone");
-            hailstone
+fn part2_get_rock_trajectory(hailstones: &[(Vector, Vector)]) -> Result<Trajectory, Error> {
+    let hailstones = hailstones
+        .iter()
+        .take(HAILSTONES_NEEDED)
+        .map(|&(pos, vel)| Trajectory {
+            initial_pos: pos,
+            initial_vel: vel,
         })
-        .collect_vec();
+        .collect::<Vec<_>>();
 
     let (matrix_a, vector_b) = hailstones.iter().combinations(2).take(2).enumerate().fold(
         (Matrix6::default(), Matrix6x1::default()),
@@ -68,10 +96,7 @@ fn part2_get_rock_trajectory(input: &str) -> Result<Trajectory, &str> {
         },
     );
 
-    let inverse_a = match matrix_a.try_inverse() {
-        Some(matrix) => matrix,
-        None => return Err("Could not get inverse"),
-    };
+    let inverse_a = matrix_a.try_inverse().ok_or(Error::NoTrajectory)?;
 
     let rock_pos_and_vel = inverse_a * vector_b;
 
@@ -101,35 +126,6 @@ impl PartialEq for Trajectory {
 
 impl Eq for Trajectory {}
 
-fn parse_hailstone(input: &str) -> IResult<&str, Trajectory> {
-    let pos_vector = tuple((
-        complete::i64,
-        preceded(tag(","), space0),
-        complete::i64,
-        preceded(tag(","), space0),
-        complete::i64,
-    ))
-    .map(|(x, _, y, _, z)| (x as f64, y as f64, z as f64));
-    let vel_vector = tuple((
-        complete::i64,
-        preceded(tag(","), space0),
-        complete::i64,
-        preceded(tag(","), space0),
-        complete::i64,
-    ))
-    .map(|(x, _, y, _, z)| (x as f64, y as f64, z as f64));
-    let (input, (pos, vel)) =
-        separated_pair(pos_vector, tuple((space0, tag("@"), space0)), vel_vector)(input)?;
-
-    Ok((
-        input,
-        Trajectory {
-            initial_pos: pos,
-            initial_vel: vel,
-        },
-    ))
-}
-
 #[cfg(test)]
 mod test {
     use super::*;
@@ -142,8 +138,10 @@ mod test {
 12, 31, 28 @ -1, -2, -1
 20, 19, 15 @  1, -5, -3";
 
+        let hailstones = parse::parse_hailstones(input).unwrap();
+
         assert_eq!(
-            part2_get_rock_trajectory(input),
+            part2_get_rock_trajectory(&hailstones),
             Ok(Trajectory {
                 initial_pos: (24_f64, 13_f64, 10_f64),
                 initial_vel: (-3_f64, 1_f64, 2_f64)
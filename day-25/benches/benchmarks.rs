@@ -0,0 +1,33 @@
+use aoc_common::{bench, input, InputSource};
+use criterion::{criterion_group, criterion_main, Criterion};
+use day_25::{generate, SOLUTIONS};
+
+/// Sizes passed to [`generate::generate`], see there for what they mean for this day.
+const SIZES: [usize; 3] = [100, 500, 1500];
+
+fn benchmark(c: &mut Criterion) {
+    let input = input::load(InputSource::from_env(), include_str!("../src/input.txt"));
+
+    bench::run(c, SOLUTIONS, &input);
+    bench::phases(c, SOLUTIONS, &input);
+}
+
+/// Both min-cut algorithms in one group, so that criterion's report plots them side by side.
+fn min_cut_benchmark(c: &mut Criterion) {
+    let input = input::load(InputSource::from_env(), include_str!("../src/input.txt"));
+
+    let mut group = c.benchmark_group("day_25::min_cut");
+    for solution in SOLUTIONS {
+        group.bench_with_input(solution.variant, &*input, |b, input| {
+            b.iter(|| (solution.run)(input))
+        });
+    }
+    group.finish();
+}
+
+fn generated_benchmark(c: &mut Criterion) {
+    bench::generated(c, SOLUTIONS, &SIZES, generate::generate);
+}
+
+criterion_group!(benches, benchmark, min_cut_benchmark, generated_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,78 @@
+use aoc_generate::{
+    rand::{seq::SliceRandom, Rng},
+    rng, unique_names, LOWERCASE,
+};
+use itertools::Itertools;
+
+use crate::parse::WIRES_TO_CUT;
+
+/// `size` components split into two groups that only 3 wires run between.
+///
+/// Each group is a ring where every component is wired to the next two, plus a random wire per
+/// component, so that splitting a group takes at least 4 wires and the 3 between the groups are
+/// the only cut.
+pub fn generate(size: usize, seed: u64) -> String {
+    assert!(size >= 10, "each group needs at least 5 components");
+    let mut rng = rng(seed);
+    let names = unique_names(&mut rng, size, 3, LOWERCASE);
+
+    let split = rng.gen_range(size / 3..=size / 2).max(5);
+    let mut wires = vec![];
+    for group in [0..split, split..size] {
+        let len = group.len();
+        for (idx, component) in group.clone().enumerate() {
+            wires.push((component, group.start + (idx + 1) % len));
+            wires.push((component, group.start + (idx + 2) % len));
+            let other = rng.gen_range(group.clone());
+            if other != component {
+                wires.push((component, other));
+            }
+        }
+    }
+    for _ in 0..WIRES_TO_CUT {
+        wires.push((rng.gen_range(0..split), rng.gen_range(split..size)));
+    }
+
+    // every wire is listed under one of its two components
+    let mut lines = wires
+        .into_iter()
+        .map(|(a, b)| if rng.gen_bool(0.5) { (a, b) } else { (b, a) })
+        .into_group_map()
+        .into_iter()
+        .collect_vec();
+    lines.sort_unstable();
+    lines.shuffle(&mut rng);
+    lines
+        .into_iter()
+        .map(|(component, mut connected)| {
+            connected.shuffle(&mut rng);
+            format!(
+                "{}: {}",
+                names[component],
+                connected.iter().map(|&other| &names[other]).join(" ")
+            )
+        })
+        .join("\n")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{part1, part1_karger, SOLUTIONS};
+    use aoc_common::testing;
+    use rstest::rstest;
+
+    #[test]
+    fn test_generated_input_is_solved() {
+        testing::check_generated(SOLUTIONS, generate, &[(10, 0, &["25"]), (100, 1, &[])]);
+    }
+
+    #[rstest]
+    #[case(50, 2)]
+    #[case(500, 3)]
+    fn test_variants_agree(#[case] size: usize, #[case] seed: u64) {
+        let input = generate(size, seed);
+
+        assert_eq!(part1::part1(&input), part1_karger::part1(&input));
+    }
+}
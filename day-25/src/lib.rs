@@ -0,0 +1,6 @@
+pub mod generate;
+pub mod parse;
+pub mod part1;
+pub mod part1_karger;
+
+aoc_common::solutions!(25 => part1::Part1, part1_karger::Part1);
@@ -0,0 +1,113 @@
+use std::{
+    collections::HashMap,
+    fmt::{self, Display},
+};
+
+pub type ParseError = aoc_common::ParseError<ParseErrorKind>;
+
+/// Number of wires that have to be disconnected to split the components into two groups.
+pub const WIRES_TO_CUT: usize = 3;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    InvalidLine,
+    InvalidName,
+    TooFewComponents,
+}
+
+impl Display for ParseErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseErrorKind::InvalidLine => {
+                write!(f, "expected a line as `<component>: <components>`")
+            }
+            ParseErrorKind::InvalidName => {
+                write!(f, "expected a component name of lowercase letters")
+            }
+            ParseErrorKind::TooFewComponents => write!(f, "expected at least two components"),
+        }
+    }
+}
+
+/// Wiring that could not be parsed, or that cannot be split by cutting [`WIRES_TO_CUT`] wires.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    Parse(ParseError),
+    WrongCut(usize),
+    NoCut,
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Parse(err) => err.fmt(f),
+            Error::WrongCut(wires) => write!(
+                f,
+                "splitting the components takes {} wires instead of {}",
+                wires, WIRES_TO_CUT
+            ),
+            Error::NoCut => write!(
+                f,
+                "found no {} wires that split the components in two",
+                WIRES_TO_CUT
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<ParseError> for Error {
+    fn from(err: ParseError) -> Self {
+        Error::Parse(err)
+    }
+}
+
+/// Components numbered in order of appearance, with every wire between two of them listed once.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Wiring {
+    pub components: usize,
+    pub wires: Vec<(usize, usize)>,
+}
+
+pub fn parse_wiring(input: &str) -> Result<Wiring, ParseError> {
+    let mut ids = HashMap::new();
+    let mut wires = vec![];
+
+    for line in input.lines().filter(|line| !line.trim().is_empty()) {
+        let (component, connected) = line
+            .split_once(':')
+            .ok_or_else(|| ParseError::new(input, line, ParseErrorKind::InvalidLine))?;
+        let component = parse_name(input, component.trim(), &mut ids)?;
+        for other in connected.split_whitespace() {
+            let other = parse_name(input, other, &mut ids)?;
+            if other != component {
+                wires.push((component, other));
+            }
+        }
+    }
+
+    if ids.len() < 2 {
+        return Err(ParseError::new(
+            input,
+            &input[input.len()..],
+            ParseErrorKind::TooFewComponents,
+        ));
+    }
+    Ok(Wiring {
+        components: ids.len(),
+        wires,
+    })
+}
+
+fn parse_name<'a>(
+    input: &str,
+    name: &'a str,
+    ids: &mut HashMap<&'a str, usize>,
+) -> Result<usize, ParseError> {
+    if name.is_empty() || !name.bytes().all(|b| b.is_ascii_lowercase()) {
+        return Err(ParseError::new(input, name, ParseErrorKind::InvalidName));
+    }
+    let next_id = ids.len();
+    Ok(*ids.entry(name).or_insert(next_id))
+}
@@ -0,0 +1,151 @@
+use crate::parse::{self, Error, Wiring, WIRES_TO_CUT};
+use aoc_common::Solution;
+use std::collections::{BinaryHeap, HashMap};
+
+pub struct Part1;
+
+impl Solution for Part1 {
+    const PART: u8 = 1;
+
+    type Model<'a> = Wiring;
+    type Answer = usize;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Self::Model<'_>, Self::Error> {
+        Ok(parse::parse_wiring(input)?)
+    }
+
+    fn solve(wiring: Self::Model<'_>) -> Result<Self::Answer, Self::Error> {
+        let (wires, group) = minimum_cut(&wiring);
+        if wires != WIRES_TO_CUT {
+            return Err(Error::WrongCut(wires));
+        }
+
+        Ok(group * (wiring.components - group))
+    }
+}
+
+pub fn part1(input: &str) -> Result<usize, Error> {
+    Part1::run(input)
+}
+
+/// Stoer–Wagner: the fewest wires that split the components in two, along with the size of one of
+/// the groups.
+///
+/// Every phase orders the remaining nodes by how strongly they are connected to the ones before
+/// them. The last node is only connected to the rest by the cut of the phase, which is the smallest
+/// cut between it and the node before, so the two are merged for the next phase.
+fn minimum_cut(wiring: &Wiring) -> (usize, usize) {
+    let mut adjacency = vec![HashMap::<usize, usize>::new(); wiring.components];
+    for &(a, b) in &wiring.wires {
+        *adjacency[a].entry(b).or_default() += 1;
+        *adjacency[b].entry(a).or_default() += 1;
+    }
+    // number of components merged into each node
+    let mut merged = vec![1; wiring.components];
+    let mut nodes = (0..wiring.components).collect::<Vec<_>>();
+
+    let mut connectivity = vec![0; wiring.components];
+    let mut added = vec![false; wiring.components];
+    let mut best = (usize::MAX, 0);
+    while nodes.len() > 1 {
+        for &node in &nodes {
+            connectivity[node] = 0;
+            added[node] = false;
+        }
+
+        let mut queue = BinaryHeap::from([(0, nodes[0])]);
+        let (mut prev, mut last) = (nodes[0], nodes[0]);
+        let mut added_count = 0;
+        while let Some((weight, node)) = queue.pop() {
+            if added[node] || weight != connectivity[node] {
+                // stale entry, the node has been pushed again with a higher weight
+                continue;
+            }
+            added[node] = true;
+            added_count += 1;
+            (prev, last) = (last, node);
+            for (&next, &wires) in &adjacency[node] {
+                if !added[next] {
+                    connectivity[next] += wires;
+                    queue.push((connectivity[next], next));
+                }
+            }
+        }
+
+        if added_count < nodes.len() {
+            // the components already fall apart without cutting anything
+            let group = nodes
+                .iter()
+                .filter(|&&node| added[node])
+                .map(|&node| merged[node])
+                .sum();
+            return (0, group);
+        }
+        if connectivity[last] < best.0 {
+            best = (connectivity[last], merged[last]);
+        }
+
+        for (next, wires) in std::mem::take(&mut adjacency[last]) {
+            adjacency[next].remove(&last);
+            if next != prev {
+                *adjacency[prev].entry(next).or_default() += wires;
+                *adjacency[next].entry(prev).or_default() += wires;
+            }
+        }
+        merged[prev] += merged[last];
+        nodes.retain(|&node| node != last);
+    }
+
+    best
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::parse::ParseErrorKind;
+    use rstest::rstest;
+
+    #[test]
+    fn test_part1() {
+        let input = "jqt: rhn xhk nvd
+rsh: frs pzl lsr
+xhk: hfx
+cmg: qnr nvd lhk bvb
+rhn: xhk bvb hfx
+bvb: xhk hfx
+pzl: lsr hfx nvd
+qnr: nvd
+ntq: jqt hfx bvb xhk
+nvd: lhk
+lsr: lhk
+rzs: qnr cmg lsr rsh
+frs: qnr lhk lsr";
+
+        assert_eq!(part1(input), Ok(54));
+    }
+
+    #[rstest]
+    #[case("jqt rhn", ParseErrorKind::InvalidLine, (1, 1))]
+    #[case("jqt: rhn\nxhk: Bvb", ParseErrorKind::InvalidName, (2, 6))]
+    #[case("jqt:", ParseErrorKind::TooFewComponents, (1, 5))]
+    fn test_part1_parse_error(
+        #[case] input: &str,
+        #[case] kind: ParseErrorKind,
+        #[case] position: (usize, usize),
+    ) {
+        let Err(Error::Parse(err)) = part1(input) else {
+            panic!("{:?} should not parse", input);
+        };
+
+        assert_eq!(err.kind, kind);
+        assert_eq!((err.line, err.column), position);
+    }
+
+    #[rstest]
+    #[case("jqt: rhn xhk\nrhn: xhk", 2)]
+    #[case("jqt: rhn\nxhk: bvb", 0)]
+    fn test_part1_wrong_cut(#[case] input: &str, #[case] wires: usize) {
+        assert_eq!(part1(input), Err(Error::WrongCut(wires)));
+    }
+}
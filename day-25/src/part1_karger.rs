@@ -0,0 +1,141 @@
+use crate::parse::{self, Error, Wiring, WIRES_TO_CUT};
+use aoc_common::Solution;
+use rand::{rngs::StdRng, seq::SliceRandom, SeedableRng};
+
+/// Gives up after this many contractions, which each find the cut with a chance of at least
+/// 2 / n², though far better than that for the puzzle input.
+const MAX_TRIALS: usize = 10_000;
+/// Fixed so that runs and benches are reproducible.
+const SEED: u64 = 25;
+
+pub struct Part1;
+
+impl Solution for Part1 {
+    const PART: u8 = 1;
+    const VARIANT: &'static str = "karger";
+
+    type Model<'a> = Wiring;
+    type Answer = usize;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Self::Model<'_>, Self::Error> {
+        Ok(parse::parse_wiring(input)?)
+    }
+
+    fn solve(wiring: Self::Model<'_>) -> Result<Self::Answer, Self::Error> {
+        let mut rng = StdRng::seed_from_u64(SEED);
+        let mut wires = wiring.wires.clone();
+
+        for _ in 0..MAX_TRIALS {
+            wires.shuffle(&mut rng);
+            let Some(groups) = contract(&wiring, &wires) else {
+                // the components already fall apart into more than two groups
+                break;
+            };
+            let cut = wires
+                .iter()
+                .filter(|&&(a, b)| groups.find(a) != groups.find(b))
+                .count();
+            if cut == WIRES_TO_CUT {
+                let group = groups.size(0);
+                return Ok(group * (wiring.components - group));
+            }
+        }
+
+        Err(Error::NoCut)
+    }
+}
+
+pub fn part1(input: &str) -> Result<usize, Error> {
+    Part1::run(input)
+}
+
+/// Karger's contraction: merging the ends of the wires in random order until only two groups are
+/// left is the same as contracting a random wire at a time.
+fn contract(wiring: &Wiring, shuffled_wires: &[(usize, usize)]) -> Option<Groups> {
+    let mut groups = Groups::new(wiring.components);
+    let mut remaining = wiring.components;
+    for &(a, b) in shuffled_wires {
+        if remaining == 2 {
+            break;
+        }
+        if groups.union(a, b) {
+            remaining -= 1;
+        }
+    }
+
+    (remaining == 2).then_some(groups)
+}
+
+/// Union-find over the components.
+struct Groups {
+    parents: Vec<usize>,
+    sizes: Vec<usize>,
+}
+
+impl Groups {
+    fn new(len: usize) -> Self {
+        Self {
+            parents: (0..len).collect(),
+            sizes: vec![1; len],
+        }
+    }
+
+    fn find(&self, mut node: usize) -> usize {
+        while self.parents[node] != node {
+            node = self.parents[node];
+        }
+        node
+    }
+
+    /// Whether `a` and `b` were in different groups before.
+    fn union(&mut self, a: usize, b: usize) -> bool {
+        let (a, b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+        let (small, large) = if self.sizes[a] < self.sizes[b] {
+            (a, b)
+        } else {
+            (b, a)
+        };
+        self.parents[small] = large;
+        self.sizes[large] += self.sizes[small];
+        true
+    }
+
+    fn size(&self, node: usize) -> usize {
+        self.sizes[self.find(node)]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let input = "jqt: rhn xhk nvd
+rsh: frs pzl lsr
+xhk: hfx
+cmg: qnr nvd lhk bvb
+rhn: xhk bvb hfx
+bvb: xhk hfx
+pzl: lsr hfx nvd
+qnr: nvd
+ntq: jqt hfx bvb xhk
+nvd: lhk
+lsr: lhk
+rzs: qnr cmg lsr rsh
+frs: qnr lhk lsr";
+
+        assert_eq!(part1(input), Ok(54));
+    }
+
+    #[test]
+    fn test_part1_no_cut() {
+        let input = "jqt: rhn xhk\nrhn: xhk";
+
+        assert_eq!(part1(input), Err(Error::NoCut));
+    }
+}